@@ -0,0 +1,109 @@
+extern crate khronos_egl as egl;
+use gl::types::GLvoid;
+
+const WIDTH: egl::Int = 64;
+const HEIGHT: egl::Int = 64;
+
+fn main() {
+	let egl = unsafe { egl::DynamicInstance::<egl::EGL1_4>::load_required() }
+		.expect("unable to load libEGL.so.1");
+
+	// Get a display connection without any window system.
+	let display = egl
+		.get_surfaceless_display_ext()
+		.expect("unable to get the surfaceless display");
+	let (major, minor) = egl.initialize(display).unwrap();
+	println!(
+		"EGL {}.{} ({})",
+		major,
+		minor,
+		egl.query_string(Some(display), egl::VENDOR)
+			.unwrap()
+			.to_string_lossy()
+	);
+
+	egl.bind_api(egl::OPENGL_API)
+		.expect("unable to select OpenGL API");
+
+	// Choose a pbuffer configuration.
+	let attributes = [
+		egl::SURFACE_TYPE,
+		egl::PBUFFER_BIT,
+		egl::RENDERABLE_TYPE,
+		egl::OPENGL_BIT,
+		egl::RED_SIZE,
+		8,
+		egl::GREEN_SIZE,
+		8,
+		egl::BLUE_SIZE,
+		8,
+		egl::ALPHA_SIZE,
+		8,
+		egl::NONE,
+	];
+
+	let config = egl
+		.choose_first_config(display, &attributes)
+		.expect("unable to choose an EGL configuration")
+		.expect("no EGL configuration found");
+
+	let surface = egl
+		.create_pbuffer_surface(
+			display,
+			config,
+			&[egl::WIDTH, WIDTH, egl::HEIGHT, HEIGHT, egl::NONE],
+		)
+		.expect("unable to create the pbuffer");
+
+	let context = egl
+		.create_context(display, Some(config), None, &[egl::NONE])
+		.expect("unable to create an EGL context");
+
+	egl.make_current(display, Some(surface), Some(surface), Some(context))
+		.expect("unable to bind the context");
+
+	// Setup Open GL.
+	gl::load_with(|name| egl.get_proc_address(name).unwrap() as *const std::ffi::c_void);
+
+	// Render a red background with a green square in the bottom-left corner.
+	let mut pixels = vec![0u8; (WIDTH * HEIGHT * 4) as usize];
+	unsafe {
+		gl::ClearColor(1.0, 0.0, 0.0, 1.0);
+		gl::Clear(gl::COLOR_BUFFER_BIT);
+
+		gl::Enable(gl::SCISSOR_TEST);
+		gl::Scissor(0, 0, WIDTH / 2, HEIGHT / 2);
+		gl::ClearColor(0.0, 1.0, 0.0, 1.0);
+		gl::Clear(gl::COLOR_BUFFER_BIT);
+		gl::Disable(gl::SCISSOR_TEST);
+
+		gl::Finish();
+		gl::ReadPixels(
+			0,
+			0,
+			WIDTH,
+			HEIGHT,
+			gl::RGBA,
+			gl::UNSIGNED_BYTE,
+			pixels.as_mut_ptr() as *mut GLvoid,
+		);
+	}
+
+	// Read back the first pixel of each quadrant, bottom row first.
+	let pixel = |x: egl::Int, y: egl::Int| {
+		let i = ((y * WIDTH + x) * 4) as usize;
+		[pixels[i], pixels[i + 1], pixels[i + 2], pixels[i + 3]]
+	};
+
+	let bottom_left = pixel(0, 0);
+	let top_right = pixel(WIDTH - 1, HEIGHT - 1);
+	println!("bottom-left pixel: {:?}", bottom_left);
+	println!("top-right pixel: {:?}", top_right);
+	assert_eq!(bottom_left, [0, 255, 0, 255]);
+	assert_eq!(top_right, [255, 0, 0, 255]);
+
+	egl.make_current(display, None, None, None).unwrap();
+	egl.destroy_context(display, context).unwrap();
+	egl.destroy_surface(display, surface).unwrap();
+	egl.terminate(display).unwrap();
+}
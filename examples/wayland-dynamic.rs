@@ -99,7 +99,7 @@ fn create_context(
 	];
 
 	let context = egl
-		.create_context(display, config, None, &context_attributes)
+		.create_context(display, Some(config), None, &context_attributes)
 		.expect("unable to create an EGL context");
 
 	(context, config)
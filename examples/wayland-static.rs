@@ -98,7 +98,7 @@ fn create_context(display: egl::Display) -> (egl::Context, egl::Config) {
 	];
 
 	let context = egl
-		.create_context(display, config, None, &context_attributes)
+		.create_context(display, Some(config), None, &context_attributes)
 		.expect("unable to create an EGL context");
 
 	(context, config)
@@ -0,0 +1,700 @@
+//! Generates the EGL bindings of `khronos-egl` from the Khronos registry.
+//!
+//! This reads the vendored `registry/egl.xml` and writes the files of
+//! `src/generated`:
+//!  - `api.rs`: the `api!` invocation listing the functions of each core
+//!    EGL version;
+//!  - `enums.rs`: all the core and extension constants;
+//!  - `extensions.rs`: the `extensions!` invocation listing the function
+//!    prototypes of each extension;
+//!  - `enum_names.rs`: the symbolic names of the enumerants, displayed by
+//!    the `trace` feature.
+//!
+//! Run it with `cargo run -p khronos-egl-generator`, or with
+//! `cargo run -p khronos-egl-generator -- --check` to only check that the
+//! generated files are up to date.
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+
+/// Core EGL versions: registry feature name, `api!` trait name and Cargo
+/// feature.
+const VERSIONS: &[(&str, &str, &str)] = &[
+	("EGL_VERSION_1_0", "EGL1_0", "1_0"),
+	("EGL_VERSION_1_1", "EGL1_1", "1_1"),
+	("EGL_VERSION_1_2", "EGL1_2", "1_2"),
+	("EGL_VERSION_1_3", "EGL1_3", "1_3"),
+	("EGL_VERSION_1_4", "EGL1_4", "1_4"),
+	("EGL_VERSION_1_5", "EGL1_5", "1_5"),
+];
+
+/// Extensions advertised by `eglQueryString(EGL_NO_DISPLAY, EGL_EXTENSIONS)`.
+const CLIENT_EXTENSIONS: &[&str] = &[
+	"EGL_ANGLE_platform_angle",
+	"EGL_EXT_client_extensions",
+	"EGL_EXT_device_base",
+	"EGL_EXT_device_enumeration",
+	"EGL_EXT_device_query",
+	"EGL_EXT_platform_base",
+	"EGL_EXT_platform_device",
+	"EGL_EXT_platform_wayland",
+	"EGL_EXT_platform_x11",
+	"EGL_KHR_client_get_all_proc_addresses",
+	"EGL_KHR_debug",
+	"EGL_KHR_platform_android",
+	"EGL_KHR_platform_gbm",
+	"EGL_KHR_platform_wayland",
+	"EGL_KHR_platform_x11",
+	"EGL_MESA_platform_gbm",
+	"EGL_MESA_platform_surfaceless",
+];
+
+/// Constants that are not `EGLint` values.
+const CONSTANT_TYPES: &[(&str, &str)] = &[
+	("EGL_FALSE", "Boolean"),
+	("EGL_TRUE", "Boolean"),
+	("EGL_OPENGL_API", "Enum"),
+	("EGL_OPENGL_ES_API", "Enum"),
+	("EGL_OPENVG_API", "Enum"),
+	("EGL_PLATFORM_ANDROID_KHR", "Enum"),
+	("EGL_PLATFORM_DEVICE_EXT", "Enum"),
+	("EGL_PLATFORM_GBM_KHR", "Enum"),
+	("EGL_PLATFORM_GBM_MESA", "Enum"),
+	("EGL_PLATFORM_SURFACELESS_MESA", "Enum"),
+	("EGL_PLATFORM_WAYLAND_EXT", "Enum"),
+	("EGL_PLATFORM_WAYLAND_KHR", "Enum"),
+	("EGL_PLATFORM_X11_EXT", "Enum"),
+	("EGL_PLATFORM_X11_KHR", "Enum"),
+];
+
+/// Rust types of the registry types, with the Cargo feature defining them.
+const TYPES: &[(&str, &str, &str)] = &[
+	("EGLBoolean", "Boolean", "1_0"),
+	("EGLint", "Int", "1_0"),
+	("EGLAttrib", "Attrib", "1_0"),
+	("EGLAttribKHR", "Attrib", "1_0"),
+	("EGLDisplay", "EGLDisplay", "1_0"),
+	("EGLConfig", "EGLConfig", "1_0"),
+	("EGLContext", "EGLContext", "1_0"),
+	("EGLSurface", "EGLSurface", "1_0"),
+	("EGLNativeDisplayType", "NativeDisplayType", "1_0"),
+	("EGLNativePixmapType", "NativePixmapType", "1_0"),
+	("EGLNativeWindowType", "NativeWindowType", "1_0"),
+	("EGLNativeFileDescriptorKHR", "Int", "1_0"),
+	("EGLDeviceEXT", "EGLDeviceEXT", "1_0"),
+	("EGLenum", "Enum", "1_2"),
+	("EGLClientBuffer", "EGLClientBuffer", "1_2"),
+	("EGLSync", "EGLSync", "1_2"),
+	("EGLSyncKHR", "EGLSync", "1_2"),
+	("EGLImage", "EGLImage", "1_2"),
+	("EGLImageKHR", "EGLImage", "1_2"),
+	("EGLTime", "Time", "1_2"),
+	("EGLTimeKHR", "Time", "1_2"),
+	("EGLTimeNV", "u64", "1_0"),
+	("EGLuint64KHR", "u64", "1_0"),
+	("EGLuint64NV", "u64", "1_0"),
+	("EGLnsecsANDROID", "i64", "1_0"),
+	("EGLsizeiANDROID", "isize", "1_0"),
+	("EGLStreamKHR", "*mut c_void", "1_0"),
+	("EGLOutputLayerEXT", "*mut c_void", "1_0"),
+	("EGLOutputPortEXT", "*mut c_void", "1_0"),
+	("EGLSyncNV", "*mut c_void", "1_0"),
+	("EGLLabelKHR", "*mut c_void", "1_0"),
+	("EGLObjectKHR", "*mut c_void", "1_0"),
+	("EGLSetBlobFuncANDROID", "*mut c_void", "1_0"),
+	("EGLGetBlobFuncANDROID", "*mut c_void", "1_0"),
+	("EGLDEBUGPROCKHR", "*mut c_void", "1_0"),
+	("AHardwareBuffer", "c_void", "1_0"),
+	("wl_buffer", "c_void", "1_0"),
+	("wl_display", "c_void", "1_0"),
+	("wl_resource", "c_void", "1_0"),
+	("EGLClientPixmapHI", "c_void", "1_0"),
+	(
+		"__eglMustCastToProperFunctionPointerType",
+		"Option<extern \"system\" fn()>",
+		"1_0",
+	),
+	("void", "c_void", "1_0"),
+	("char", "c_char", "1_0"),
+	("int", "libc::c_int", "1_0"),
+];
+
+const HEADER: &str =
+	"// This file is generated from `registry/egl.xml` by `cargo run -p khronos-egl-generator`.
+// Do not edit it by hand.
+";
+
+/// Maximum width of a generated line, counting tabs as 4 columns.
+const MAX_WIDTH: usize = 100;
+
+type Error = Box<dyn std::error::Error>;
+
+fn main() -> ExitCode {
+	match run() {
+		Ok(true) => ExitCode::SUCCESS,
+		Ok(false) => ExitCode::FAILURE,
+		Err(e) => {
+			eprintln!("error: {}", e);
+			ExitCode::FAILURE
+		}
+	}
+}
+
+fn run() -> Result<bool, Error> {
+	let check = match env::args().nth(1).as_deref() {
+		None => false,
+		Some("--check") => true,
+		Some(arg) => return Err(format!("unexpected argument `{}`", arg).into()),
+	};
+
+	let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+	let xml = fs::read_to_string(root.join("registry/egl.xml"))?;
+	let registry = Registry::parse(&xml)?;
+
+	let outputs = [
+		("api.rs", registry.api()?),
+		("enums.rs", registry.enums()?),
+		("extensions.rs", registry.extensions()?),
+		("enum_names.rs", registry.enum_names()?),
+	];
+
+	let mut up_to_date = true;
+	for (name, content) in outputs {
+		let path: PathBuf = root.join("src/generated").join(name);
+		if check {
+			if fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+				eprintln!("`{}` is out of date", path.display());
+				up_to_date = false
+			}
+		} else {
+			fs::write(&path, content)?;
+		}
+	}
+
+	Ok(up_to_date)
+}
+
+/// Registry type, with its pointer indirections.
+struct Type {
+	/// Base registry type name.
+	name: String,
+
+	/// Whether the pointed data is `const`.
+	is_const: bool,
+
+	/// Pointer indirections.
+	pointers: usize,
+}
+
+impl Type {
+	/// Parses a `<proto>` or `<param>` element, returning the name and type.
+	fn parse(node: roxmltree::Node) -> Result<(String, Type), Error> {
+		let mut name = None;
+		let mut base = None;
+		let mut prefix = String::new();
+		let mut suffix = String::new();
+
+		for child in node.children() {
+			match child.tag_name().name() {
+				"name" => name = child.text().map(str::to_owned),
+				"ptype" => base = child.text().map(str::to_owned),
+				"" if name.is_none() => {
+					let text = child.text().unwrap_or_default();
+					if base.is_none() {
+						prefix.push_str(text)
+					} else {
+						suffix.push_str(text)
+					}
+				}
+				_ => (),
+			}
+		}
+
+		let name = name.ok_or("missing name")?;
+		let mut words: Vec<&str> = prefix
+			.split(|c: char| c.is_whitespace() || c == '*')
+			.filter(|w| !w.is_empty() && *w != "const" && *w != "struct")
+			.collect();
+		let base = match base {
+			Some(base) => base,
+			None => words.pop().ok_or("missing type")?.to_owned(),
+		};
+
+		let pointers = prefix.matches('*').count() + suffix.matches('*').count();
+		let is_const = prefix.contains("const")
+			|| suffix
+				.split('*')
+				.next()
+				.unwrap_or_default()
+				.contains("const");
+
+		Ok((
+			name,
+			Type {
+				name: base,
+				is_const,
+				pointers,
+			},
+		))
+	}
+
+	/// Returns the Rust type and the Cargo feature required to use it.
+	fn to_rust(&self) -> Result<(String, &'static str), Error> {
+		let (base, feature) = rust_type(&self.name)?;
+
+		if self.pointers == 0 && base == "c_void" {
+			return Ok(("()".to_owned(), feature));
+		}
+
+		let mut ty = base.to_owned();
+		for i in 0..self.pointers {
+			ty = if i == 0 && self.is_const {
+				format!("*const {}", ty)
+			} else {
+				format!("*mut {}", ty)
+			};
+		}
+
+		Ok((ty, feature))
+	}
+}
+
+fn rust_type(name: &str) -> Result<(&'static str, &'static str), Error> {
+	TYPES
+		.iter()
+		.find(|(n, _, _)| *n == name)
+		.map(|(_, ty, feature)| (*ty, *feature))
+		.ok_or_else(|| format!("unknown registry type `{}`", name).into())
+}
+
+/// Registry command.
+struct Command {
+	name: String,
+	params: Vec<(String, Type)>,
+	ret: Type,
+}
+
+impl Command {
+	/// Returns the Rust prototype of the command, and the Cargo feature
+	/// required to use it.
+	fn to_rust(&self, indent: usize) -> Result<(String, &'static str), Error> {
+		let mut feature = "1_0";
+		let mut params = Vec::new();
+		for (name, ty) in &self.params {
+			let (ty, f) = ty.to_rust()?;
+			feature = feature.max(f);
+			params.push(format!("{}: {}", param_name(name), ty));
+		}
+
+		let (ret, f) = self.ret.to_rust()?;
+		feature = feature.max(f);
+		let ret = if ret == "()" {
+			String::new()
+		} else {
+			format!(" -> {}", ret)
+		};
+
+		let tabs = "\t".repeat(indent);
+		let line = format!("{}fn {}({}){};", tabs, self.name, params.join(", "), ret);
+		if indent * 4 + line.len() - indent <= MAX_WIDTH {
+			return Ok((line, feature));
+		}
+
+		let mut proto = format!("{}fn {}(\n", tabs, self.name);
+		for (i, param) in params.iter().enumerate() {
+			let separator = if i + 1 < params.len() { "," } else { "" };
+			writeln!(proto, "{}\t{}{}", tabs, param, separator)?;
+		}
+		write!(proto, "{}){};", tabs, ret)?;
+		Ok((proto, feature))
+	}
+}
+
+fn param_name(name: &str) -> &str {
+	match name {
+		"dpy" => "display",
+		"type" => "type_",
+		name => name,
+	}
+}
+
+/// Registry constant.
+struct Constant {
+	value: String,
+	ty: Option<String>,
+}
+
+impl Constant {
+	/// Returns the Rust declaration of the constant, and the Cargo feature
+	/// required to use it.
+	fn to_rust(&self, name: &str) -> Result<(String, &'static str), Error> {
+		let rust_name = name.strip_prefix("EGL_").ok_or("missing `EGL_` prefix")?;
+
+		let (ty, value, feature) = if self.ty.as_deref() == Some("ull") {
+			("u64", self.value.clone(), "1_0")
+		} else if let Some(cast) = self
+			.value
+			.strip_prefix("EGL_CAST(")
+			.and_then(|v| v.strip_suffix(')'))
+		{
+			let (ty, value) = cast.split_once(',').ok_or("invalid cast")?;
+			let (ty, feature) = rust_type(ty.trim())?;
+			let value = value.trim();
+			if ty.starts_with('*') || ty.starts_with("EGL") || ty.starts_with("Native") {
+				if value != "0" {
+					return Err(format!("invalid pointer value `{}`", value).into());
+				}
+
+				(ty, "ptr::null_mut()".to_owned(), feature)
+			} else {
+				(ty, value.to_owned(), feature)
+			}
+		} else {
+			let ty = CONSTANT_TYPES
+				.iter()
+				.find(|(n, _)| *n == name)
+				.map(|(_, ty)| *ty)
+				.unwrap_or("Int");
+			let feature = if ty == "Enum" { "1_2" } else { "1_0" };
+			(ty, self.value.clone(), feature)
+		};
+
+		Ok((
+			format!("pub const {}: {} = {};", rust_name, ty, value),
+			feature,
+		))
+	}
+}
+
+/// EGL registry.
+struct Registry {
+	constants: HashMap<String, Constant>,
+	commands: HashMap<String, Command>,
+
+	/// Core versions, with their required constants and commands.
+	features: Vec<(String, Vec<String>, Vec<String>)>,
+
+	/// Extensions, with their required constants and commands.
+	extensions: Vec<(String, Vec<String>, Vec<String>)>,
+}
+
+impl Registry {
+	fn parse(xml: &str) -> Result<Self, Error> {
+		let doc = roxmltree::Document::parse(xml)?;
+		let mut registry = Registry {
+			constants: HashMap::new(),
+			commands: HashMap::new(),
+			features: Vec::new(),
+			extensions: Vec::new(),
+		};
+
+		for node in doc.descendants() {
+			match node.tag_name().name() {
+				"enum" if node.parent_element().map(|p| p.tag_name().name()) == Some("enums") => {
+					if let (Some(name), Some(value)) =
+						(node.attribute("name"), node.attribute("value"))
+					{
+						registry.constants.insert(
+							name.to_owned(),
+							Constant {
+								value: value.to_owned(),
+								ty: node.attribute("type").map(str::to_owned),
+							},
+						);
+					}
+				}
+				"command"
+					if node.parent_element().map(|p| p.tag_name().name()) == Some("commands") =>
+				{
+					let mut params = Vec::new();
+					let mut proto = None;
+					for child in node.children() {
+						match child.tag_name().name() {
+							"proto" => proto = Some(Type::parse(child)?),
+							"param" => params.push(Type::parse(child)?),
+							_ => (),
+						}
+					}
+
+					let (name, ret) = proto.ok_or("missing command prototype")?;
+					registry
+						.commands
+						.insert(name.clone(), Command { name, params, ret });
+				}
+				"feature" if node.attribute("api") == Some("egl") => {
+					let (constants, commands) = requirements(node);
+					let name = node.attribute("name").ok_or("missing feature name")?;
+					registry
+						.features
+						.push((name.to_owned(), constants, commands));
+				}
+				"extension" => {
+					let supported = node.attribute("supported").unwrap_or_default();
+					if supported.split('|').any(|api| api == "egl") {
+						let (constants, commands) = requirements(node);
+						let name = node.attribute("name").ok_or("missing extension name")?;
+						registry
+							.extensions
+							.push((name.to_owned(), constants, commands));
+					}
+				}
+				_ => (),
+			}
+		}
+
+		Ok(registry)
+	}
+
+	fn command(&self, name: &str) -> Result<&Command, Error> {
+		self.commands
+			.get(name)
+			.ok_or_else(|| format!("unknown command `{}`", name).into())
+	}
+
+	fn constant(&self, name: &str) -> Result<&Constant, Error> {
+		self.constants
+			.get(name)
+			.ok_or_else(|| format!("unknown constant `{}`", name).into())
+	}
+
+	/// Generates the `api!` invocation.
+	fn api(&self) -> Result<String, Error> {
+		let mut out = format!("{}\napi! {{\n", HEADER);
+
+		for (i, (feature, trait_name, version)) in VERSIONS.iter().enumerate() {
+			let (_, _, commands) = self
+				.features
+				.iter()
+				.find(|(name, _, _)| name == feature)
+				.ok_or_else(|| format!("missing feature `{}`", feature))?;
+
+			if i > 0 {
+				out.push('\n');
+			}
+
+			writeln!(out, "\t{} : \"{}\" {{", trait_name, version)?;
+			if commands.is_empty() {
+				out.push_str("\t\t// nothing.\n");
+			}
+
+			for name in commands {
+				let (proto, feature) = self.command(name)?.to_rust(2)?;
+				if feature > *version {
+					return Err(format!("`{}` requires the `{}` feature", name, feature).into());
+				}
+
+				writeln!(out, "{}", proto)?;
+			}
+
+			let separator = if i + 1 < VERSIONS.len() { "," } else { "" };
+			writeln!(out, "\t}}{}", separator)?;
+		}
+
+		out.push_str("}\n");
+		Ok(out)
+	}
+
+	/// Generates the constants.
+	fn enums(&self) -> Result<String, Error> {
+		let mut out = HEADER.to_owned();
+		let mut defined = HashSet::new();
+
+		for (feature, _, version) in VERSIONS {
+			let (_, constants, _) = self
+				.features
+				.iter()
+				.find(|(name, _, _)| name == feature)
+				.ok_or_else(|| format!("missing feature `{}`", feature))?;
+
+			let mut block = String::new();
+			for name in constants {
+				if defined.insert(name.as_str()) {
+					let (decl, type_feature) = self.constant(name)?.to_rust(name)?;
+					writeln!(
+						block,
+						"#[cfg(feature = \"{}\")]\n{}",
+						type_feature.max(version),
+						decl
+					)?;
+				}
+			}
+
+			if !block.is_empty() {
+				write!(out, "\n// {}\n{}", feature, block)?;
+			}
+		}
+
+		for (extension, constants, _) in &self.extensions {
+			let mut block = String::new();
+			for name in constants {
+				if defined.insert(name.as_str()) {
+					let (decl, feature) = self.constant(name)?.to_rust(name)?;
+					writeln!(block, "#[cfg(feature = \"{}\")]\n{}", feature, decl)?;
+				}
+			}
+
+			if !block.is_empty() {
+				write!(out, "\n// {}\n{}", extension, block)?;
+			}
+		}
+
+		Ok(out)
+	}
+
+	/// Generates the table of the symbolic names of the EGL enumerants, used
+	/// by the `trace` feature.
+	///
+	/// Only the `0x3000` to `0x3FFF` range reserved to EGL enumerants is
+	/// named. When several constants share the same value, the name of the
+	/// most recent core version is used, or else a `KHR` name, an `EXT` name,
+	/// and finally the name of the first extension defining it.
+	fn enum_names(&self) -> Result<String, Error> {
+		let core = self
+			.features
+			.iter()
+			.rev()
+			.flat_map(|(_, constants, _)| constants.iter().map(|name| (0, name)));
+		let extensions = self
+			.extensions
+			.iter()
+			.flat_map(|(extension, constants, _)| {
+				let rank = if extension.starts_with("EGL_KHR_") {
+					1
+				} else if extension.starts_with("EGL_EXT_") {
+					2
+				} else {
+					3
+				};
+				constants.iter().map(move |name| (rank, name))
+			});
+
+		let mut values = BTreeMap::new();
+		for (rank, name) in core.chain(extensions) {
+			let constant = self.constant(name)?;
+			if constant.ty.is_some() {
+				continue;
+			}
+
+			let value = match constant.value.strip_prefix("0x") {
+				Some(hex) => u32::from_str_radix(hex, 16).ok(),
+				None => constant.value.parse().ok(),
+			};
+
+			if let Some(value @ 0x3000..=0x3FFF) = value {
+				let entry = values.entry(value).or_insert((rank, name.as_str()));
+				if rank < entry.0 {
+					*entry = (rank, name.as_str())
+				}
+			}
+		}
+
+		let mut out = format!(
+			"{}\n/// Symbolic names of the EGL enumerants, sorted by value.\n{}",
+			HEADER, "const ENUM_NAMES: &[(Int, &str)] = &[\n"
+		);
+		for (value, (_, name)) in values {
+			writeln!(out, "\t(0x{:04X}, \"{}\"),", value, name)?;
+		}
+		out.push_str("];\n");
+
+		Ok(out)
+	}
+
+	/// Generates the `extensions!` invocation.
+	fn extensions(&self) -> Result<String, Error> {
+		let core: BTreeSet<&str> = self
+			.features
+			.iter()
+			.flat_map(|(_, _, commands)| commands.iter().map(String::as_str))
+			.collect();
+
+		let mut tables = Vec::new();
+		for (extension, _, commands) in &self.extensions {
+			let commands: Vec<&String> = commands
+				.iter()
+				.filter(|c| !core.contains(c.as_str()))
+				.collect();
+			if commands.is_empty() {
+				continue;
+			}
+
+			let kind = if CLIENT_EXTENSIONS.contains(&extension.as_str()) {
+				"Client"
+			} else {
+				"Display"
+			};
+
+			let mut feature = "1_0";
+			let mut protos = String::new();
+			for name in commands {
+				let (proto, f) = self.command(name)?.to_rust(2)?;
+				feature = feature.max(f);
+				writeln!(protos, "{}", proto)?;
+			}
+
+			tables.push(format!(
+				"\t{}: \"{}\" ({}, \"{}\") {{\n{}\t}}\n",
+				table_name(extension)?,
+				extension,
+				kind,
+				feature,
+				protos
+			));
+		}
+
+		Ok(format!(
+			"{}\nextensions! {{\n{}}}\n",
+			HEADER,
+			tables.join("\n")
+		))
+	}
+}
+
+/// Returns the constants and commands required by a feature or extension.
+fn requirements(node: roxmltree::Node) -> (Vec<String>, Vec<String>) {
+	let mut constants = Vec::new();
+	let mut commands = Vec::new();
+
+	for require in node.children().filter(|n| n.has_tag_name("require")) {
+		for item in require.children() {
+			if let Some(name) = item.attribute("name") {
+				let list = match item.tag_name().name() {
+					"enum" => &mut constants,
+					"command" => &mut commands,
+					_ => continue,
+				};
+
+				if !list.iter().any(|n| n == name) {
+					list.push(name.to_owned())
+				}
+			}
+		}
+	}
+
+	(constants, commands)
+}
+
+/// Returns the name of the function table of an extension.
+///
+/// For instance `EGL_KHR_image_base` gives `KhrImageBase`.
+fn table_name(extension: &str) -> Result<String, Error> {
+	let name = extension
+		.strip_prefix("EGL_")
+		.ok_or_else(|| format!("invalid extension name `{}`", extension))?;
+
+	Ok(name
+		.split('_')
+		.map(|word| {
+			let mut chars = word.chars();
+			match chars.next() {
+				Some(c) => c
+					.to_uppercase()
+					.chain(chars.flat_map(char::to_lowercase))
+					.collect(),
+				None => String::new(),
+			}
+		})
+		.collect())
+}
@@ -0,0 +1,738 @@
+//! Frame buffer configuration attributes.
+use super::*;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Deref};
+
+macro_rules! bitmask {
+	($(#[$meta:meta])* $id:ident { $($(#[$fmeta:meta])* const $name:ident = $value:expr;)* }) => {
+		$(#[$meta])*
+		#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+		pub struct $id(Int);
+
+		impl $id {
+			$(
+				$(#[$fmeta])*
+				pub const $name: $id = $id($value);
+			)*
+
+			/// Creates a mask from its raw bits.
+			#[inline]
+			pub const fn from_bits(bits: Int) -> $id {
+				$id(bits)
+			}
+
+			/// Returns the raw bits of the mask.
+			#[inline]
+			pub const fn bits(&self) -> Int {
+				self.0
+			}
+
+			/// Returns an empty mask.
+			#[inline]
+			pub const fn empty() -> $id {
+				$id(0)
+			}
+
+			/// Checks if the mask is empty.
+			#[inline]
+			pub const fn is_empty(&self) -> bool {
+				self.0 == 0
+			}
+
+			/// Checks if all the bits of `other` are set in this mask.
+			#[inline]
+			pub const fn contains(&self, other: $id) -> bool {
+				self.0 & other.0 == other.0
+			}
+		}
+
+		impl BitOr for $id {
+			type Output = $id;
+
+			#[inline]
+			fn bitor(self, other: $id) -> $id {
+				$id(self.0 | other.0)
+			}
+		}
+
+		impl BitOrAssign for $id {
+			#[inline]
+			fn bitor_assign(&mut self, other: $id) {
+				self.0 |= other.0
+			}
+		}
+
+		impl BitAnd for $id {
+			type Output = $id;
+
+			#[inline]
+			fn bitand(self, other: $id) -> $id {
+				$id(self.0 & other.0)
+			}
+		}
+
+		impl BitAndAssign for $id {
+			#[inline]
+			fn bitand_assign(&mut self, other: $id) {
+				self.0 &= other.0
+			}
+		}
+
+		impl From<$id> for Int {
+			#[inline]
+			fn from(mask: $id) -> Int {
+				mask.0
+			}
+		}
+	};
+}
+
+bitmask! {
+	/// Surface types supported by a frame buffer configuration (`SURFACE_TYPE`).
+	SurfaceType {
+		/// Window surfaces (`WINDOW_BIT`).
+		const WINDOW = WINDOW_BIT;
+
+		/// Pixel buffer surfaces (`PBUFFER_BIT`).
+		const PBUFFER = PBUFFER_BIT;
+
+		/// Pixmap surfaces (`PIXMAP_BIT`).
+		const PIXMAP = PIXMAP_BIT;
+
+		/// OpenVG rendering in linear colorspace (`VG_COLORSPACE_LINEAR_BIT`).
+		#[cfg(feature = "1_3")]
+		const VG_COLORSPACE_LINEAR = VG_COLORSPACE_LINEAR_BIT;
+
+		/// OpenVG rendering with premultiplied alpha (`VG_ALPHA_FORMAT_PRE_BIT`).
+		#[cfg(feature = "1_3")]
+		const VG_ALPHA_FORMAT_PRE = VG_ALPHA_FORMAT_PRE_BIT;
+
+		/// Box filtered multisample resolve (`MULTISAMPLE_RESOLVE_BOX_BIT`).
+		#[cfg(feature = "1_4")]
+		const MULTISAMPLE_RESOLVE_BOX = MULTISAMPLE_RESOLVE_BOX_BIT;
+
+		/// Preserved color buffer swap behavior (`SWAP_BEHAVIOR_PRESERVED_BIT`).
+		#[cfg(feature = "1_4")]
+		const SWAP_BEHAVIOR_PRESERVED = SWAP_BEHAVIOR_PRESERVED_BIT;
+	}
+}
+
+#[cfg(feature = "1_2")]
+bitmask! {
+	/// Client APIs supported by a frame buffer configuration
+	/// (`RENDERABLE_TYPE` and `CONFORMANT`).
+	RenderableType {
+		/// OpenGL ES 1.x (`OPENGL_ES_BIT`).
+		const OPENGL_ES = OPENGL_ES_BIT;
+
+		/// OpenVG (`OPENVG_BIT`).
+		const OPENVG = OPENVG_BIT;
+
+		/// OpenGL ES 2.x (`OPENGL_ES2_BIT`).
+		#[cfg(feature = "1_3")]
+		const OPENGL_ES2 = OPENGL_ES2_BIT;
+
+		/// OpenGL (`OPENGL_BIT`).
+		#[cfg(feature = "1_4")]
+		const OPENGL = OPENGL_BIT;
+
+		/// OpenGL ES 3.x (`OPENGL_ES3_BIT`).
+		#[cfg(feature = "1_5")]
+		const OPENGL_ES3 = OPENGL_ES3_BIT;
+	}
+}
+
+/// Caveat of a frame buffer configuration (`CONFIG_CAVEAT`).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ConfigCaveat {
+	/// No caveat (`NONE`).
+	None,
+
+	/// Rendering may be slow (`SLOW_CONFIG`).
+	Slow,
+
+	/// The configuration does not pass the conformance tests
+	/// (`NON_CONFORMANT_CONFIG`).
+	NonConformant,
+
+	/// Caveat unknown to this crate, such as a vendor specific value.
+	Other(Int),
+}
+
+impl From<ConfigCaveat> for Int {
+	fn from(caveat: ConfigCaveat) -> Int {
+		match caveat {
+			ConfigCaveat::None => NONE,
+			ConfigCaveat::Slow => SLOW_CONFIG,
+			ConfigCaveat::NonConformant => NON_CONFORMANT_CONFIG,
+			ConfigCaveat::Other(value) => value,
+		}
+	}
+}
+
+impl From<Int> for ConfigCaveat {
+	fn from(value: Int) -> ConfigCaveat {
+		match value {
+			NONE => ConfigCaveat::None,
+			SLOW_CONFIG => ConfigCaveat::Slow,
+			NON_CONFORMANT_CONFIG => ConfigCaveat::NonConformant,
+			_ => ConfigCaveat::Other(value),
+		}
+	}
+}
+
+/// Transparency type of a frame buffer configuration (`TRANSPARENT_TYPE`).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TransparentType {
+	/// Opaque windows (`NONE`).
+	None,
+
+	/// Transparent color given by the `TRANSPARENT_*_VALUE` attributes
+	/// (`TRANSPARENT_RGB`).
+	Rgb,
+
+	/// Transparency type unknown to this crate, such as a vendor specific
+	/// value.
+	Other(Int),
+}
+
+impl From<TransparentType> for Int {
+	fn from(ty: TransparentType) -> Int {
+		match ty {
+			TransparentType::None => NONE,
+			TransparentType::Rgb => TRANSPARENT_RGB,
+			TransparentType::Other(value) => value,
+		}
+	}
+}
+
+impl From<Int> for TransparentType {
+	fn from(value: Int) -> TransparentType {
+		match value {
+			NONE => TransparentType::None,
+			TRANSPARENT_RGB => TransparentType::Rgb,
+			_ => TransparentType::Other(value),
+		}
+	}
+}
+
+/// Color buffer type of a frame buffer configuration (`COLOR_BUFFER_TYPE`).
+#[cfg(feature = "1_2")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ColorBufferType {
+	/// RGB color buffer (`RGB_BUFFER`).
+	Rgb,
+
+	/// Luminance color buffer (`LUMINANCE_BUFFER`).
+	Luminance,
+
+	/// Color buffer type unknown to this crate, such as `YUV_BUFFER_EXT`
+	/// (`EGL_EXT_yuv_surface`).
+	Other(Int),
+}
+
+#[cfg(feature = "1_2")]
+impl From<ColorBufferType> for Int {
+	fn from(ty: ColorBufferType) -> Int {
+		match ty {
+			ColorBufferType::Rgb => RGB_BUFFER,
+			ColorBufferType::Luminance => LUMINANCE_BUFFER,
+			ColorBufferType::Other(value) => value,
+		}
+	}
+}
+
+#[cfg(feature = "1_2")]
+impl From<Int> for ColorBufferType {
+	fn from(value: Int) -> ColorBufferType {
+		match value {
+			RGB_BUFFER => ColorBufferType::Rgb,
+			LUMINANCE_BUFFER => ColorBufferType::Luminance,
+			_ => ColorBufferType::Other(value),
+		}
+	}
+}
+
+/// Frame buffer configuration attributes list builder.
+///
+/// This builds a valid `NONE` terminated attributes list that can be given
+/// to [`Instance::choose_config`], [`Instance::choose_first_config`] and
+/// [`Instance::matching_config_count`]. Setting the same attribute twice
+/// replaces the previous value.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate khronos_egl as egl;
+/// let attributes = egl::ConfigAttribs::new()
+///   .red_size(8)
+///   .green_size(8)
+///   .blue_size(8)
+///   .surface_type(egl::SurfaceType::WINDOW | egl::SurfaceType::PBUFFER);
+///
+/// assert_eq!(
+///   &*attributes,
+///   &[
+///     egl::RED_SIZE, 8,
+///     egl::GREEN_SIZE, 8,
+///     egl::BLUE_SIZE, 8,
+///     egl::SURFACE_TYPE, egl::WINDOW_BIT | egl::PBUFFER_BIT,
+///     egl::NONE
+///   ]
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ConfigAttribs {
+	list: Vec<Int>,
+}
+
+impl Default for ConfigAttribs {
+	fn default() -> Self {
+		ConfigAttribs::new()
+	}
+}
+
+impl ConfigAttribs {
+	/// Creates an empty attributes list.
+	pub fn new() -> Self {
+		ConfigAttribs { list: vec![NONE] }
+	}
+
+	/// Returns the `NONE` terminated attributes list.
+	#[inline]
+	pub fn as_slice(&self) -> &[Int] {
+		&self.list
+	}
+
+	/// Returns the value of the given attribute, if set.
+	pub fn get(&self, attribute: Int) -> Option<Int> {
+		self.list[..self.list.len() - 1]
+			.chunks_exact(2)
+			.find(|pair| pair[0] == attribute)
+			.map(|pair| pair[1])
+	}
+
+	/// Sets the value of an arbitrary attribute.
+	///
+	/// This can be used for attributes that have no dedicated setter,
+	/// such as extension attributes.
+	pub fn attrib(mut self, attribute: Int, value: Int) -> Self {
+		let len = self.list.len() - 1;
+		match self.list[..len]
+			.chunks_exact_mut(2)
+			.find(|pair| pair[0] == attribute)
+		{
+			Some(pair) => pair[1] = value,
+			None => {
+				self.list.insert(len, value);
+				self.list.insert(len, attribute);
+			}
+		}
+
+		self
+	}
+
+	/// Sets the total size of the color buffer in bits (`BUFFER_SIZE`).
+	pub fn buffer_size(self, size: Int) -> Self {
+		self.attrib(BUFFER_SIZE, size)
+	}
+
+	/// Sets the size of the red component of the color buffer in bits (`RED_SIZE`).
+	pub fn red_size(self, size: Int) -> Self {
+		self.attrib(RED_SIZE, size)
+	}
+
+	/// Sets the size of the green component of the color buffer in bits (`GREEN_SIZE`).
+	pub fn green_size(self, size: Int) -> Self {
+		self.attrib(GREEN_SIZE, size)
+	}
+
+	/// Sets the size of the blue component of the color buffer in bits (`BLUE_SIZE`).
+	pub fn blue_size(self, size: Int) -> Self {
+		self.attrib(BLUE_SIZE, size)
+	}
+
+	/// Sets the size of the alpha component of the color buffer in bits (`ALPHA_SIZE`).
+	pub fn alpha_size(self, size: Int) -> Self {
+		self.attrib(ALPHA_SIZE, size)
+	}
+
+	/// Sets the size of the depth buffer in bits (`DEPTH_SIZE`).
+	pub fn depth_size(self, size: Int) -> Self {
+		self.attrib(DEPTH_SIZE, size)
+	}
+
+	/// Sets the size of the stencil buffer in bits (`STENCIL_SIZE`).
+	pub fn stencil_size(self, size: Int) -> Self {
+		self.attrib(STENCIL_SIZE, size)
+	}
+
+	/// Sets the number of samples per pixel (`SAMPLES`).
+	pub fn samples(self, samples: Int) -> Self {
+		self.attrib(SAMPLES, samples)
+	}
+
+	/// Sets the number of multisample buffers (`SAMPLE_BUFFERS`).
+	pub fn sample_buffers(self, buffers: Int) -> Self {
+		self.attrib(SAMPLE_BUFFERS, buffers)
+	}
+
+	/// Selects the configuration with the given unique identifier (`CONFIG_ID`).
+	pub fn config_id(self, id: Int) -> Self {
+		self.attrib(CONFIG_ID, id)
+	}
+
+	/// Sets the frame buffer level (`LEVEL`).
+	pub fn level(self, level: Int) -> Self {
+		self.attrib(LEVEL, level)
+	}
+
+	/// Sets whether native rendering APIs can render into the surface
+	/// (`NATIVE_RENDERABLE`).
+	pub fn native_renderable(self, renderable: bool) -> Self {
+		self.attrib(NATIVE_RENDERABLE, bool_attrib(renderable))
+	}
+
+	/// Sets the native visual type (`NATIVE_VISUAL_TYPE`).
+	pub fn native_visual_type(self, ty: Int) -> Self {
+		self.attrib(NATIVE_VISUAL_TYPE, ty)
+	}
+
+	/// Sets the supported surface types (`SURFACE_TYPE`).
+	pub fn surface_type(self, ty: SurfaceType) -> Self {
+		self.attrib(SURFACE_TYPE, ty.bits())
+	}
+
+	/// Sets the configuration caveat (`CONFIG_CAVEAT`).
+	pub fn config_caveat(self, caveat: ConfigCaveat) -> Self {
+		self.attrib(CONFIG_CAVEAT, caveat.into())
+	}
+
+	/// Sets the transparency type (`TRANSPARENT_TYPE`).
+	pub fn transparent_type(self, ty: TransparentType) -> Self {
+		self.attrib(TRANSPARENT_TYPE, ty.into())
+	}
+
+	/// Sets the transparent color (`TRANSPARENT_RED_VALUE`,
+	/// `TRANSPARENT_GREEN_VALUE` and `TRANSPARENT_BLUE_VALUE`).
+	pub fn transparent_value(self, red: Int, green: Int, blue: Int) -> Self {
+		self.attrib(TRANSPARENT_RED_VALUE, red)
+			.attrib(TRANSPARENT_GREEN_VALUE, green)
+			.attrib(TRANSPARENT_BLUE_VALUE, blue)
+	}
+
+	/// Sets whether the color buffers can be bound to an RGB texture
+	/// (`BIND_TO_TEXTURE_RGB`).
+	#[cfg(feature = "1_1")]
+	pub fn bind_to_texture_rgb(self, bindable: bool) -> Self {
+		self.attrib(BIND_TO_TEXTURE_RGB, bool_attrib(bindable))
+	}
+
+	/// Sets whether the color buffers can be bound to an RGBA texture
+	/// (`BIND_TO_TEXTURE_RGBA`).
+	#[cfg(feature = "1_1")]
+	pub fn bind_to_texture_rgba(self, bindable: bool) -> Self {
+		self.attrib(BIND_TO_TEXTURE_RGBA, bool_attrib(bindable))
+	}
+
+	/// Sets the minimum swap interval (`MIN_SWAP_INTERVAL`).
+	#[cfg(feature = "1_1")]
+	pub fn min_swap_interval(self, interval: Int) -> Self {
+		self.attrib(MIN_SWAP_INTERVAL, interval)
+	}
+
+	/// Sets the maximum swap interval (`MAX_SWAP_INTERVAL`).
+	#[cfg(feature = "1_1")]
+	pub fn max_swap_interval(self, interval: Int) -> Self {
+		self.attrib(MAX_SWAP_INTERVAL, interval)
+	}
+
+	/// Sets the size of the alpha mask buffer in bits (`ALPHA_MASK_SIZE`).
+	#[cfg(feature = "1_2")]
+	pub fn alpha_mask_size(self, size: Int) -> Self {
+		self.attrib(ALPHA_MASK_SIZE, size)
+	}
+
+	/// Sets the size of the luminance component of the color buffer in bits
+	/// (`LUMINANCE_SIZE`).
+	#[cfg(feature = "1_2")]
+	pub fn luminance_size(self, size: Int) -> Self {
+		self.attrib(LUMINANCE_SIZE, size)
+	}
+
+	/// Sets the color buffer type (`COLOR_BUFFER_TYPE`).
+	#[cfg(feature = "1_2")]
+	pub fn color_buffer_type(self, ty: ColorBufferType) -> Self {
+		self.attrib(COLOR_BUFFER_TYPE, ty.into())
+	}
+
+	/// Sets the client APIs that must be supported (`RENDERABLE_TYPE`).
+	#[cfg(feature = "1_2")]
+	pub fn renderable_type(self, ty: RenderableType) -> Self {
+		self.attrib(RENDERABLE_TYPE, ty.bits())
+	}
+
+	/// Sets the client APIs for which contexts must be conformant (`CONFORMANT`).
+	#[cfg(feature = "1_3")]
+	pub fn conformant(self, ty: RenderableType) -> Self {
+		self.attrib(CONFORMANT, ty.bits())
+	}
+}
+
+impl Deref for ConfigAttribs {
+	type Target = [Int];
+
+	#[inline]
+	fn deref(&self) -> &[Int] {
+		self.as_slice()
+	}
+}
+
+impl AsRef<[Int]> for ConfigAttribs {
+	#[inline]
+	fn as_ref(&self) -> &[Int] {
+		self.as_slice()
+	}
+}
+
+/// Frame buffer configuration information.
+///
+/// This gathers all the attributes of a frame buffer configuration
+/// known for the EGL version provided by the API.
+/// It is returned by [`Instance::config_info`] and
+/// [`Instance::all_config_infos`].
+///
+/// Attributes introduced after EGL 1.0 are set to `None` when the provided
+/// EGL version does not support them.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ConfigInfo {
+	/// Frame buffer configuration.
+	pub config: Config,
+
+	/// Unique identifier of the configuration (`CONFIG_ID`).
+	pub config_id: Int,
+
+	/// Total size of the color buffer in bits (`BUFFER_SIZE`).
+	pub buffer_size: Int,
+
+	/// Size of the red component of the color buffer in bits (`RED_SIZE`).
+	pub red_size: Int,
+
+	/// Size of the green component of the color buffer in bits (`GREEN_SIZE`).
+	pub green_size: Int,
+
+	/// Size of the blue component of the color buffer in bits (`BLUE_SIZE`).
+	pub blue_size: Int,
+
+	/// Size of the alpha component of the color buffer in bits (`ALPHA_SIZE`).
+	pub alpha_size: Int,
+
+	/// Size of the depth buffer in bits (`DEPTH_SIZE`).
+	pub depth_size: Int,
+
+	/// Size of the stencil buffer in bits (`STENCIL_SIZE`).
+	pub stencil_size: Int,
+
+	/// Number of samples per pixel (`SAMPLES`).
+	pub samples: Int,
+
+	/// Number of multisample buffers (`SAMPLE_BUFFERS`).
+	pub sample_buffers: Int,
+
+	/// Frame buffer level (`LEVEL`).
+	pub level: Int,
+
+	/// Configuration caveat (`CONFIG_CAVEAT`).
+	pub config_caveat: ConfigCaveat,
+
+	/// Whether native rendering APIs can render into the surface
+	/// (`NATIVE_RENDERABLE`).
+	pub native_renderable: bool,
+
+	/// Identifier of the associated native visual (`NATIVE_VISUAL_ID`).
+	pub native_visual_id: Int,
+
+	/// Type of the associated native visual (`NATIVE_VISUAL_TYPE`).
+	pub native_visual_type: Int,
+
+	/// Supported surface types (`SURFACE_TYPE`).
+	pub surface_type: SurfaceType,
+
+	/// Transparency type (`TRANSPARENT_TYPE`).
+	pub transparent_type: TransparentType,
+
+	/// Transparent red value (`TRANSPARENT_RED_VALUE`).
+	pub transparent_red_value: Int,
+
+	/// Transparent green value (`TRANSPARENT_GREEN_VALUE`).
+	pub transparent_green_value: Int,
+
+	/// Transparent blue value (`TRANSPARENT_BLUE_VALUE`).
+	pub transparent_blue_value: Int,
+
+	/// Maximum width of a pixel buffer surface (`MAX_PBUFFER_WIDTH`).
+	pub max_pbuffer_width: Int,
+
+	/// Maximum height of a pixel buffer surface (`MAX_PBUFFER_HEIGHT`).
+	pub max_pbuffer_height: Int,
+
+	/// Maximum size of a pixel buffer surface in pixels (`MAX_PBUFFER_PIXELS`).
+	pub max_pbuffer_pixels: Int,
+
+	/// Whether the color buffers can be bound to an RGB texture
+	/// (`BIND_TO_TEXTURE_RGB`).
+	#[cfg(feature = "1_1")]
+	pub bind_to_texture_rgb: Option<bool>,
+
+	/// Whether the color buffers can be bound to an RGBA texture
+	/// (`BIND_TO_TEXTURE_RGBA`).
+	#[cfg(feature = "1_1")]
+	pub bind_to_texture_rgba: Option<bool>,
+
+	/// Minimum swap interval (`MIN_SWAP_INTERVAL`).
+	#[cfg(feature = "1_1")]
+	pub min_swap_interval: Option<Int>,
+
+	/// Maximum swap interval (`MAX_SWAP_INTERVAL`).
+	#[cfg(feature = "1_1")]
+	pub max_swap_interval: Option<Int>,
+
+	/// Size of the alpha mask buffer in bits (`ALPHA_MASK_SIZE`).
+	#[cfg(feature = "1_2")]
+	pub alpha_mask_size: Option<Int>,
+
+	/// Size of the luminance component of the color buffer in bits
+	/// (`LUMINANCE_SIZE`).
+	#[cfg(feature = "1_2")]
+	pub luminance_size: Option<Int>,
+
+	/// Color buffer type (`COLOR_BUFFER_TYPE`).
+	#[cfg(feature = "1_2")]
+	pub color_buffer_type: Option<ColorBufferType>,
+
+	/// Supported client APIs (`RENDERABLE_TYPE`).
+	#[cfg(feature = "1_2")]
+	pub renderable_type: Option<RenderableType>,
+
+	/// Client APIs for which contexts are conformant (`CONFORMANT`).
+	#[cfg(feature = "1_3")]
+	pub conformant: Option<RenderableType>,
+}
+
+impl<T: api::EGL1_0 + Api> Instance<T> {
+	/// Return all the attributes of an EGL frame buffer configuration known
+	/// for the provided EGL version.
+	///
+	/// Values of typed attributes unknown to this crate (for instance from
+	/// extensions) are returned as the `Other` variant of the attribute type.
+	pub fn config_info(&self, display: Display, config: Config) -> Result<ConfigInfo, Error> {
+		let get = |attribute| self.get_config_attrib(display, config, attribute);
+		#[cfg(feature = "1_1")]
+		let get_since = |since: Version, attribute| {
+			if self.version() >= since {
+				get(attribute).map(Some)
+			} else {
+				Ok(None)
+			}
+		};
+
+		Ok(ConfigInfo {
+			config,
+			config_id: get(CONFIG_ID)?,
+			buffer_size: get(BUFFER_SIZE)?,
+			red_size: get(RED_SIZE)?,
+			green_size: get(GREEN_SIZE)?,
+			blue_size: get(BLUE_SIZE)?,
+			alpha_size: get(ALPHA_SIZE)?,
+			depth_size: get(DEPTH_SIZE)?,
+			stencil_size: get(STENCIL_SIZE)?,
+			samples: get(SAMPLES)?,
+			sample_buffers: get(SAMPLE_BUFFERS)?,
+			level: get(LEVEL)?,
+			config_caveat: get(CONFIG_CAVEAT)?.into(),
+			native_renderable: get(NATIVE_RENDERABLE)? != FALSE as Int,
+			native_visual_id: get(NATIVE_VISUAL_ID)?,
+			native_visual_type: get(NATIVE_VISUAL_TYPE)?,
+			surface_type: SurfaceType::from_bits(get(SURFACE_TYPE)?),
+			transparent_type: get(TRANSPARENT_TYPE)?.into(),
+			transparent_red_value: get(TRANSPARENT_RED_VALUE)?,
+			transparent_green_value: get(TRANSPARENT_GREEN_VALUE)?,
+			transparent_blue_value: get(TRANSPARENT_BLUE_VALUE)?,
+			max_pbuffer_width: get(MAX_PBUFFER_WIDTH)?,
+			max_pbuffer_height: get(MAX_PBUFFER_HEIGHT)?,
+			max_pbuffer_pixels: get(MAX_PBUFFER_PIXELS)?,
+			#[cfg(feature = "1_1")]
+			bind_to_texture_rgb: get_since(Version::EGL1_1, BIND_TO_TEXTURE_RGB)?
+				.map(|value| value != FALSE as Int),
+			#[cfg(feature = "1_1")]
+			bind_to_texture_rgba: get_since(Version::EGL1_1, BIND_TO_TEXTURE_RGBA)?
+				.map(|value| value != FALSE as Int),
+			#[cfg(feature = "1_1")]
+			min_swap_interval: get_since(Version::EGL1_1, MIN_SWAP_INTERVAL)?,
+			#[cfg(feature = "1_1")]
+			max_swap_interval: get_since(Version::EGL1_1, MAX_SWAP_INTERVAL)?,
+			#[cfg(feature = "1_2")]
+			alpha_mask_size: get_since(Version::EGL1_2, ALPHA_MASK_SIZE)?,
+			#[cfg(feature = "1_2")]
+			luminance_size: get_since(Version::EGL1_2, LUMINANCE_SIZE)?,
+			#[cfg(feature = "1_2")]
+			color_buffer_type: get_since(Version::EGL1_2, COLOR_BUFFER_TYPE)?
+				.map(ColorBufferType::from),
+			#[cfg(feature = "1_2")]
+			renderable_type: get_since(Version::EGL1_2, RENDERABLE_TYPE)?
+				.map(RenderableType::from_bits),
+			#[cfg(feature = "1_3")]
+			conformant: get_since(Version::EGL1_3, CONFORMANT)?.map(RenderableType::from_bits),
+		})
+	}
+
+	/// Return the information about all the EGL frame buffer configurations
+	/// of a display.
+	///
+	/// See [`config_info`](Self::config_info).
+	pub fn all_config_infos(&self, display: Display) -> Result<Vec<ConfigInfo>, Error> {
+		let mut configs = Vec::with_capacity(self.get_config_count(display)?);
+		self.get_configs(display, &mut configs)?;
+		configs
+			.into_iter()
+			.map(|config| self.config_info(display, config))
+			.collect()
+	}
+}
+
+/// Converts a boolean into an `Int` attribute value.
+pub(crate) fn bool_attrib(value: bool) -> Int {
+	if value {
+		TRUE as Int
+	} else {
+		FALSE as Int
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn unknown_typed_attribute_values() {
+		let mock = Mock::new().with_configs(vec![
+			MockConfig::new(),
+			MockConfig::new()
+				.attrib(COLOR_BUFFER_TYPE, YUV_BUFFER_EXT)
+				.attrib(CONFIG_CAVEAT, 0x3456),
+		]);
+		let egl = Instance::new(mock);
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+
+		let infos = egl.all_config_infos(display).unwrap();
+		assert_eq!(infos.len(), 2);
+		assert_eq!(infos[0].color_buffer_type, Some(ColorBufferType::Rgb));
+		assert_eq!(infos[0].config_caveat, ConfigCaveat::None);
+		assert_eq!(
+			infos[1].color_buffer_type,
+			Some(ColorBufferType::Other(YUV_BUFFER_EXT))
+		);
+		assert_eq!(infos[1].config_caveat, ConfigCaveat::Other(0x3456));
+		assert_eq!(Int::from(infos[1].config_caveat), 0x3456);
+	}
+}
@@ -0,0 +1,342 @@
+//! Rendering context attributes.
+use super::*;
+
+/// Client rendering API.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ClientApi {
+	/// OpenGL ES (`OPENGL_ES_API`).
+	OpenGlEs,
+
+	/// OpenVG (`OPENVG_API`).
+	OpenVg,
+
+	/// OpenGL (`OPENGL_API`).
+	#[cfg(feature = "1_4")]
+	OpenGl,
+}
+
+impl From<ClientApi> for Enum {
+	fn from(api: ClientApi) -> Enum {
+		match api {
+			ClientApi::OpenGlEs => OPENGL_ES_API,
+			ClientApi::OpenVg => OPENVG_API,
+			#[cfg(feature = "1_4")]
+			ClientApi::OpenGl => OPENGL_API,
+		}
+	}
+}
+
+impl TryFrom<Enum> for ClientApi {
+	type Error = Enum;
+
+	fn try_from(api: Enum) -> Result<ClientApi, Enum> {
+		match api {
+			OPENGL_ES_API => Ok(ClientApi::OpenGlEs),
+			OPENVG_API => Ok(ClientApi::OpenVg),
+			#[cfg(feature = "1_4")]
+			OPENGL_API => Ok(ClientApi::OpenGl),
+			_ => Err(api),
+		}
+	}
+}
+
+/// OpenGL context profile (`CONTEXT_OPENGL_PROFILE_MASK`).
+#[cfg(feature = "1_5")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Profile {
+	/// Core profile (`CONTEXT_OPENGL_CORE_PROFILE_BIT`).
+	Core,
+
+	/// Compatibility profile (`CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT`).
+	Compatibility,
+}
+
+#[cfg(feature = "1_5")]
+impl From<Profile> for Int {
+	fn from(profile: Profile) -> Int {
+		match profile {
+			Profile::Core => CONTEXT_OPENGL_CORE_PROFILE_BIT,
+			Profile::Compatibility => CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT,
+		}
+	}
+}
+
+/// Reset notification strategy of a robust context
+/// (`CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY`).
+#[cfg(feature = "1_5")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ResetNotification {
+	/// Graphics resets are not reported (`NO_RESET_NOTIFICATION`).
+	None,
+
+	/// Graphics resets result in the loss of the context
+	/// (`LOSE_CONTEXT_ON_RESET`).
+	LoseContext,
+}
+
+#[cfg(feature = "1_5")]
+impl From<ResetNotification> for Int {
+	fn from(strategy: ResetNotification) -> Int {
+		match strategy {
+			ResetNotification::None => NO_RESET_NOTIFICATION,
+			ResetNotification::LoseContext => LOSE_CONTEXT_ON_RESET,
+		}
+	}
+}
+
+/// Rendering context attributes list builder.
+///
+/// This builds the attributes list given to [`Instance::create_context`]
+/// for a given client API. Since the set of valid attributes depends on the
+/// EGL version, the list is built for a specific [`Version`] using
+/// [`build`](Self::build), which fails with a `BadAttribute` error if an
+/// attribute is not supported by this version or client API.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate khronos_egl as egl;
+/// # fn main() -> Result<(), egl::Error> {
+/// let attributes = egl::ContextAttribs::new(egl::ClientApi::OpenGl)
+///   .version(4, 5)
+///   .profile(egl::Profile::Core)
+///   .build(egl::Version::EGL1_5)?;
+///
+/// assert_eq!(
+///   attributes,
+///   [
+///     egl::CONTEXT_MAJOR_VERSION, 4,
+///     egl::CONTEXT_MINOR_VERSION, 5,
+///     egl::CONTEXT_OPENGL_PROFILE_MASK, egl::CONTEXT_OPENGL_CORE_PROFILE_BIT,
+///     egl::NONE
+///   ]
+/// );
+///
+/// // Profiles are only meaningful to OpenGL.
+/// let attributes = egl::ContextAttribs::new(egl::ClientApi::OpenGlEs)
+///   .profile(egl::Profile::Core)
+///   .build(egl::Version::EGL1_5);
+///
+/// assert_eq!(attributes, Err(egl::Error::BadAttribute));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ContextAttribs {
+	api: ClientApi,
+	version: Option<(Int, Int)>,
+	#[cfg(feature = "1_5")]
+	profile: Option<Profile>,
+	#[cfg(feature = "1_5")]
+	debug: Option<bool>,
+	#[cfg(feature = "1_5")]
+	forward_compatible: Option<bool>,
+	#[cfg(feature = "1_5")]
+	robust_access: Option<bool>,
+	#[cfg(feature = "1_5")]
+	reset_notification: Option<ResetNotification>,
+}
+
+impl ContextAttribs {
+	/// Creates an empty attributes list for the given client API.
+	///
+	/// The client API must match the one bound with [`Instance::bind_api`]
+	/// when creating the context.
+	pub fn new(api: ClientApi) -> Self {
+		ContextAttribs {
+			api,
+			version: None,
+			#[cfg(feature = "1_5")]
+			profile: None,
+			#[cfg(feature = "1_5")]
+			debug: None,
+			#[cfg(feature = "1_5")]
+			forward_compatible: None,
+			#[cfg(feature = "1_5")]
+			robust_access: None,
+			#[cfg(feature = "1_5")]
+			reset_notification: None,
+		}
+	}
+
+	/// Returns the client API of the context.
+	#[inline]
+	pub fn api(&self) -> ClientApi {
+		self.api
+	}
+
+	/// Sets the requested client API version
+	/// (`CONTEXT_MAJOR_VERSION` and `CONTEXT_MINOR_VERSION`).
+	///
+	/// Before EGL 1.5, only the major version of OpenGL ES contexts can be
+	/// specified, using `CONTEXT_CLIENT_VERSION`.
+	pub fn version(mut self, major: Int, minor: Int) -> Self {
+		self.version = Some((major, minor));
+		self
+	}
+
+	/// Sets the requested OpenGL profile (`CONTEXT_OPENGL_PROFILE_MASK`).
+	///
+	/// Only valid for OpenGL contexts.
+	#[cfg(feature = "1_5")]
+	pub fn profile(mut self, profile: Profile) -> Self {
+		self.profile = Some(profile);
+		self
+	}
+
+	/// Requests a debug context (`CONTEXT_OPENGL_DEBUG`).
+	#[cfg(feature = "1_5")]
+	pub fn debug(mut self, debug: bool) -> Self {
+		self.debug = Some(debug);
+		self
+	}
+
+	/// Requests a forward-compatible context
+	/// (`CONTEXT_OPENGL_FORWARD_COMPATIBLE`).
+	///
+	/// Only valid for OpenGL contexts.
+	#[cfg(feature = "1_5")]
+	pub fn forward_compatible(mut self, forward_compatible: bool) -> Self {
+		self.forward_compatible = Some(forward_compatible);
+		self
+	}
+
+	/// Requests a context supporting robust buffer access
+	/// (`CONTEXT_OPENGL_ROBUST_ACCESS`).
+	#[cfg(feature = "1_5")]
+	pub fn robust_access(mut self, robust_access: bool) -> Self {
+		self.robust_access = Some(robust_access);
+		self
+	}
+
+	/// Sets the reset notification strategy
+	/// (`CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY`).
+	#[cfg(feature = "1_5")]
+	pub fn reset_notification(mut self, strategy: ResetNotification) -> Self {
+		self.reset_notification = Some(strategy);
+		self
+	}
+
+	/// Builds the `NONE` terminated attributes list for the given EGL version.
+	///
+	/// This will return a `BadAttribute` error if one of the attributes
+	/// is not supported by the given EGL version or client API.
+	pub fn build(&self, version: Version) -> Result<Vec<Int>, Error> {
+		let mut list = Vec::new();
+
+		match version {
+			#[cfg(feature = "1_5")]
+			v if v >= Version::EGL1_5 => self.build_1_5(&mut list),
+			#[cfg(feature = "1_3")]
+			v if v >= Version::EGL1_3 => self.build_1_3(&mut list),
+			_ => self.build_1_0(),
+		}
+		.map_err(Error::without_context)?;
+
+		list.push(NONE);
+		Ok(list)
+	}
+
+	/// Checks if attributes introduced by EGL 1.5 are set.
+	#[cfg(feature = "1_5")]
+	fn has_1_5_attribs(&self) -> bool {
+		self.profile.is_some()
+			|| self.debug.is_some()
+			|| self.forward_compatible.is_some()
+			|| self.robust_access.is_some()
+			|| self.reset_notification.is_some()
+	}
+
+	/// No attribute is supported before EGL 1.3.
+	fn build_1_0(&self) -> Result<(), Error> {
+		#[cfg(feature = "1_5")]
+		if self.has_1_5_attribs() {
+			return Err(Error::BadAttribute);
+		}
+
+		if self.version.is_some() {
+			return Err(Error::BadAttribute);
+		}
+
+		Ok(())
+	}
+
+	/// Only the major OpenGL ES version is supported before EGL 1.5
+	/// (`CONTEXT_CLIENT_VERSION`).
+	#[cfg(feature = "1_3")]
+	fn build_1_3(&self, list: &mut Vec<Int>) -> Result<(), Error> {
+		#[cfg(feature = "1_5")]
+		if self.has_1_5_attribs() {
+			return Err(Error::BadAttribute);
+		}
+
+		if let Some((major, minor)) = self.version {
+			if self.api != ClientApi::OpenGlEs || minor != 0 {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_CLIENT_VERSION, major]);
+		}
+
+		Ok(())
+	}
+
+	#[cfg(feature = "1_5")]
+	fn build_1_5(&self, list: &mut Vec<Int>) -> Result<(), Error> {
+		let is_opengl = self.api == ClientApi::OpenGl;
+		let is_vg = self.api == ClientApi::OpenVg;
+
+		if let Some((major, minor)) = self.version {
+			if is_vg {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_MAJOR_VERSION, major, CONTEXT_MINOR_VERSION, minor]);
+		}
+
+		if let Some(profile) = self.profile {
+			if !is_opengl {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_OPENGL_PROFILE_MASK, profile.into()]);
+		}
+
+		if let Some(debug) = self.debug {
+			if is_vg {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_OPENGL_DEBUG, bool_attrib(debug)]);
+		}
+
+		if let Some(forward_compatible) = self.forward_compatible {
+			if !is_opengl {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([
+				CONTEXT_OPENGL_FORWARD_COMPATIBLE,
+				bool_attrib(forward_compatible),
+			]);
+		}
+
+		if let Some(robust_access) = self.robust_access {
+			if is_vg {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_OPENGL_ROBUST_ACCESS, bool_attrib(robust_access)]);
+		}
+
+		if let Some(strategy) = self.reset_notification {
+			if is_vg {
+				return Err(Error::BadAttribute);
+			}
+
+			list.extend([CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY, strategy.into()]);
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,115 @@
+//! Scoped current context bindings.
+use super::*;
+use std::marker::PhantomData;
+
+/// Rendering context binding saved by a [`CurrentGuard`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CurrentBinding {
+	/// Display of the current rendering context.
+	pub display: Display,
+
+	/// Current draw surface.
+	pub draw: Option<Surface>,
+
+	/// Current read surface.
+	pub read: Option<Surface>,
+
+	/// Current rendering context.
+	pub context: Option<Context>,
+}
+
+/// Scoped rendering context binding.
+///
+/// This is returned by [`Instance::make_current_scoped`].
+/// When dropped, the rendering context binding that was current when the
+/// guard was created is restored. If no context was current at that time,
+/// the context bound by the guard is released instead.
+///
+/// Since EGL bindings are per-thread, this type is neither [`Send`] nor
+/// [`std::marker::Sync`].
+pub struct CurrentGuard<'a, T: api::EGL1_4> {
+	instance: &'a Instance<T>,
+	display: Display,
+	previous: Option<CurrentBinding>,
+	_thread_bound: PhantomData<*const ()>,
+}
+
+impl<'a, T: api::EGL1_4> CurrentGuard<'a, T> {
+	/// Returns the display on which the context is bound.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Returns the binding that will be restored when the guard is dropped,
+	/// or `None` if the context will simply be released.
+	#[inline]
+	pub fn previous(&self) -> Option<CurrentBinding> {
+		self.previous
+	}
+}
+
+impl<'a, T: api::EGL1_4> Drop for CurrentGuard<'a, T> {
+	fn drop(&mut self) {
+		match self.previous {
+			Some(previous) => self.instance.make_current(
+				previous.display,
+				previous.draw,
+				previous.read,
+				previous.context,
+			),
+			None => self.instance.make_current(self.display, None, None, None),
+		}
+		.ok();
+	}
+}
+
+impl<'a, T: api::EGL1_4> fmt::Debug for CurrentGuard<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("CurrentGuard")
+			.field("display", &self.display)
+			.field("previous", &self.previous)
+			.finish()
+	}
+}
+
+impl<T: api::EGL1_4> Instance<T> {
+	/// Return the current rendering context binding of the calling thread,
+	/// or `None` if no context is current.
+	///
+	/// Note that the binding is relative to the current rendering API
+	/// (see [`bind_api`](Self::bind_api)).
+	pub fn get_current_binding(&self) -> Option<CurrentBinding> {
+		self.get_current_display().map(|display| CurrentBinding {
+			display,
+			draw: self.get_current_surface(DRAW),
+			read: self.get_current_surface(READ),
+			context: self.get_current_context(),
+		})
+	}
+
+	/// Attach an EGL rendering context to EGL surfaces until the returned guard
+	/// is dropped.
+	///
+	/// The previous binding is restored when the guard is dropped.
+	/// Note that the binding is relative to the current rendering API
+	/// (see [`bind_api`](Self::bind_api)) which must not change during the
+	/// lifetime of the guard.
+	pub fn make_current_scoped(
+		&self,
+		display: Display,
+		draw: Option<Surface>,
+		read: Option<Surface>,
+		ctx: Option<Context>,
+	) -> Result<CurrentGuard<'_, T>, Error> {
+		let previous = self.get_current_binding();
+		self.make_current(display, draw, read, ctx)?;
+
+		Ok(CurrentGuard {
+			instance: self,
+			display,
+			previous,
+			_thread_bound: PhantomData,
+		})
+	}
+}
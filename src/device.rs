@@ -0,0 +1,382 @@
+//! EGL devices.
+use super::*;
+
+/// EGL device (`EGL_EXT_device_base`).
+///
+/// Devices are enumerated with [`Instance::query_devices`], and allow
+/// selecting a GPU without any window system.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Device(EGLDeviceEXT);
+
+/// Device string holding the path of the DRM render node of a device
+/// (`EGL_EXT_device_drm_render_node`).
+///
+/// This extension is more recent than the vendored registry, so this
+/// constant is not generated.
+pub const DRM_RENDER_NODE_FILE_EXT: Int = 0x3377;
+
+impl Device {
+	/// Creates a new device from its EGL pointer.
+	///
+	/// # Safety
+	///
+	/// `ptr` must be a valid `EGLDeviceEXT` pointer.
+	#[inline]
+	pub unsafe fn from_ptr(ptr: EGLDeviceEXT) -> Device {
+		Device(ptr)
+	}
+
+	#[inline]
+	pub fn as_ptr(&self) -> EGLDeviceEXT {
+		self.0
+	}
+}
+
+impl<T: api::EGL1_0> Instance<T> {
+	/// Loads a device extension table, falling back to `EGL_EXT_device_base`
+	/// which includes the functions of all the device extensions.
+	fn load_device_extension<E: Extension + From<ExtDeviceBase>>(&self) -> Result<E, Error> {
+		match self.load_extension::<E>(None) {
+			Err(Error::ExtensionNotSupported(name)) => self
+				.load_extension::<ExtDeviceBase>(None)
+				.map(E::from)
+				.map_err(|_| Error::ExtensionNotSupported(name).without_context()),
+			result => result,
+		}
+	}
+
+	/// Return the list of available EGL devices (`EGL_EXT_device_enumeration`).
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// for device in egl.query_devices()? {
+	///   if let Ok(name) = egl.query_device_string(device, egl::EXTENSIONS) {
+	///     println!("{:?}: {:?}", device, name);
+	///   }
+	/// }
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn query_devices(&self) -> Result<Vec<Device>, Error> {
+		let ext = self.load_device_extension::<ExtDeviceEnumeration>()?;
+		unsafe {
+			let mut count = 0;
+
+			if ext.eglQueryDevicesEXT(0, ptr::null_mut(), &mut count) != TRUE {
+				return Err(self.error_in(ErrorContext::new("eglQueryDevicesEXT")));
+			}
+
+			if count == 0 {
+				return Ok(Vec::new());
+			}
+
+			let mut devices = Vec::with_capacity(count as usize);
+
+			if ext.eglQueryDevicesEXT(count, devices.as_mut_ptr() as *mut EGLDeviceEXT, &mut count)
+				== TRUE
+			{
+				devices.set_len(count as usize);
+				Ok(devices)
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDevicesEXT").with_arg("max_devices", &count),
+				))
+			}
+		}
+	}
+
+	/// Return a string describing a property of an EGL device
+	/// (`EGL_EXT_device_query`).
+	pub fn query_device_string(&self, device: Device, name: Int) -> Result<&'static CStr, Error> {
+		let ext = self.load_device_extension::<ExtDeviceQuery>()?;
+		unsafe {
+			let c_str = ext.eglQueryDeviceStringEXT(device.as_ptr(), name);
+
+			if !c_str.is_null() {
+				Ok(CStr::from_ptr(c_str))
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDeviceStringEXT")
+						.with_arg("device", &device)
+						.with_arg("name", &name),
+				))
+			}
+		}
+	}
+
+	/// Returns the extensions supported by the given device
+	/// (`EGL_EXT_device_query`).
+	pub fn device_extensions(&self, device: Device) -> Result<Extensions, Error> {
+		let list = self.query_device_string(device, EXTENSIONS)?;
+		Ok(Extensions::parse(&list.to_string_lossy()))
+	}
+
+	/// Return the value of an EGL device attribute (`EGL_EXT_device_query`).
+	pub fn query_device_attrib(&self, device: Device, attribute: Int) -> Result<Attrib, Error> {
+		let ext = self.load_device_extension::<ExtDeviceQuery>()?;
+		unsafe {
+			let mut value = 0;
+
+			if ext.eglQueryDeviceAttribEXT(device.as_ptr(), attribute, &mut value) == TRUE {
+				Ok(value)
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDeviceAttribEXT")
+						.with_arg("device", &device)
+						.with_arg("attribute", &attribute),
+				))
+			}
+		}
+	}
+
+	/// Return the value of an EGL display attribute (`EGL_EXT_device_query`).
+	///
+	/// The device of the display connection is returned by the `DEVICE_EXT`
+	/// attribute:
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// # let display = unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }.unwrap();
+	/// let ptr = egl.query_display_attrib(display, egl::DEVICE_EXT)?;
+	/// let device = unsafe { egl::Device::from_ptr(ptr as egl::EGLDeviceEXT) };
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn query_display_attrib(&self, display: Display, attribute: Int) -> Result<Attrib, Error> {
+		let ext = self.load_device_extension::<ExtDeviceQuery>()?;
+		unsafe {
+			let mut value = 0;
+
+			if ext.eglQueryDisplayAttribEXT(display.as_ptr(), attribute, &mut value) == TRUE {
+				Ok(value)
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDisplayAttribEXT")
+						.with_display(display)
+						.with_arg("attribute", &attribute),
+				))
+			}
+		}
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<T: api::EGL1_5> Instance<T> {
+	/// Get an EGL display connection for the given device
+	/// (`EGL_EXT_platform_device`).
+	///
+	/// This uses `eglGetPlatformDisplay` with `PLATFORM_DEVICE_EXT`.
+	/// Use [`get_device_display_ext`](Self::get_device_display_ext) when the
+	/// API does not provide EGL 1.5.
+	///
+	/// Note that the constant `ATTRIB_NONE` which has the type `Attrib` can be used
+	/// instead of `NONE` to terminate the attribute list.
+	///
+	/// This will return a `BadParameter` error if `attrib_list` is not a valid
+	/// attributes list (if it does not terminate with `ATTRIB_NONE`).
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// let device = egl.query_devices()?[0];
+	/// let display = egl.get_device_display(device, &[egl::ATTRIB_NONE])?;
+	/// egl.initialize(display)?;
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn get_device_display(
+		&self,
+		device: Device,
+		attrib_list: &[Attrib],
+	) -> Result<Display, Error> {
+		if !self.client_extensions()?.has("EGL_EXT_platform_device") {
+			return Err(Error::ExtensionNotSupported("EGL_EXT_platform_device").without_context());
+		}
+
+		unsafe { self.get_platform_display(PLATFORM_DEVICE_EXT, device.as_ptr(), attrib_list) }
+	}
+}
+
+#[cfg(feature = "1_2")]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Get an EGL display connection for the given device
+	/// (`EGL_EXT_platform_device`) using `eglGetPlatformDisplayEXT`
+	/// (`EGL_EXT_platform_base`).
+	///
+	/// This is meant for APIs that do not provide EGL 1.5. Since the display
+	/// is created by an extension function, it is not seen by API wrappers
+	/// such as `Traced` or `Validated`. `Validated` tracks it once it is
+	/// initialized.
+	///
+	/// This will return a `BadParameter` error if `attrib_list` is not a valid
+	/// attributes list (if it does not terminate with `ATTRIB_NONE`).
+	pub fn get_device_display_ext(
+		&self,
+		device: Device,
+		attrib_list: &[Attrib],
+	) -> Result<Display, Error> {
+		if !self.client_extensions()?.has("EGL_EXT_platform_device") {
+			return Err(Error::ExtensionNotSupported("EGL_EXT_platform_device").without_context());
+		}
+
+		unsafe { self.platform_display_ext(PLATFORM_DEVICE_EXT, device.as_ptr(), attrib_list) }
+	}
+}
+
+#[cfg(unix)]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Query a device string holding a path, if the device supports the given
+	/// extension.
+	fn query_device_path(
+		&self,
+		device: Device,
+		extension: &'static str,
+		name: Int,
+	) -> Result<std::path::PathBuf, Error> {
+		use std::os::unix::ffi::OsStrExt;
+
+		if !self.device_extensions(device)?.has(extension) {
+			return Err(Error::ExtensionNotSupported(extension).without_context());
+		}
+
+		let path = self.query_device_string(device, name)?;
+		Ok(std::ffi::OsStr::from_bytes(path.to_bytes()).into())
+	}
+
+	/// Return the path of the DRM primary node of the given device
+	/// (`EGL_EXT_device_drm`), such as `/dev/dri/card0`.
+	pub fn query_device_drm_file(&self, device: Device) -> Result<std::path::PathBuf, Error> {
+		self.query_device_path(device, "EGL_EXT_device_drm", DRM_DEVICE_FILE_EXT)
+	}
+
+	/// Return the path of the DRM render node of the given device
+	/// (`EGL_EXT_device_drm_render_node`), such as `/dev/dri/renderD128`.
+	///
+	/// Returns `None` if the device has no render node: the implementation
+	/// then returns `NULL` without any error (e.g. Mesa's software device).
+	pub fn query_device_drm_render_node_file(
+		&self,
+		device: Device,
+	) -> Result<Option<std::path::PathBuf>, Error> {
+		match self.query_device_path(
+			device,
+			"EGL_EXT_device_drm_render_node",
+			DRM_RENDER_NODE_FILE_EXT,
+		) {
+			Ok(path) => Ok(Some(path)),
+			Err(Error::Other(SUCCESS)) => Ok(None),
+			Err(e) => Err(e),
+		}
+	}
+}
+
+#[cfg(all(unix, feature = "1_2"))]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Build the attribute list passing the given DRM master file descriptor,
+	/// if the device supports `EGL_EXT_device_drm`.
+	fn drm_master_attribs(
+		&self,
+		device: Device,
+		master_fd: std::os::fd::BorrowedFd,
+	) -> Result<[Attrib; 3], Error> {
+		use std::os::fd::AsRawFd;
+
+		if !self.device_extensions(device)?.has("EGL_EXT_device_drm") {
+			return Err(Error::ExtensionNotSupported("EGL_EXT_device_drm").without_context());
+		}
+
+		Ok([
+			DRM_MASTER_FD_EXT as Attrib,
+			master_fd.as_raw_fd() as Attrib,
+			ATTRIB_NONE,
+		])
+	}
+
+	/// Get an EGL display connection for the given DRM device, using the given
+	/// DRM master file descriptor (`EGL_EXT_device_drm`), with
+	/// `eglGetPlatformDisplayEXT`.
+	///
+	/// This is meant for APIs that do not provide EGL 1.5
+	/// (see [`get_device_display_ext`](Self::get_device_display_ext)).
+	pub fn get_drm_device_display_ext(
+		&self,
+		device: Device,
+		master_fd: std::os::fd::BorrowedFd,
+	) -> Result<Display, Error> {
+		let attrib_list = self.drm_master_attribs(device, master_fd)?;
+		self.get_device_display_ext(device, &attrib_list)
+	}
+}
+
+#[cfg(all(unix, feature = "1_5"))]
+impl<T: api::EGL1_5> Instance<T> {
+	/// Get an EGL display connection for the given DRM device, using the given
+	/// DRM master file descriptor (`EGL_EXT_device_drm`).
+	///
+	/// The file descriptor is passed with the `DRM_MASTER_FD_EXT` attribute
+	/// and must be opened on the primary node of the device (see
+	/// [`Instance::query_device_drm_file`]). The caller keeps ownership of
+	/// the file descriptor.
+	/// Use [`get_drm_device_display_ext`](Self::get_drm_device_display_ext)
+	/// when the API does not provide EGL 1.5.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # use std::os::fd::AsFd;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// let device = egl.query_devices()?[0];
+	/// let card = std::fs::File::open(egl.query_device_drm_file(device)?).unwrap();
+	/// let display = egl.get_drm_device_display(device, card.as_fd())?;
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn get_drm_device_display(
+		&self,
+		device: Device,
+		master_fd: std::os::fd::BorrowedFd,
+	) -> Result<Display, Error> {
+		let attrib_list = self.drm_master_attribs(device, master_fd)?;
+		self.get_device_display(device, &attrib_list)
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn device_extensions_required() {
+		let egl = Instance::new(Mock::new());
+		let device = unsafe { Device::from_ptr(1 as EGLDeviceEXT) };
+
+		assert_eq!(
+			egl.query_devices(),
+			Err(Error::ExtensionNotSupported("EGL_EXT_device_enumeration"))
+		);
+		assert_eq!(
+			egl.query_device_string(device, EXTENSIONS),
+			Err(Error::ExtensionNotSupported("EGL_EXT_device_query"))
+		);
+		assert_eq!(
+			egl.get_device_display(device, &[ATTRIB_NONE]),
+			Err(Error::ExtensionNotSupported("EGL_EXT_platform_device"))
+		);
+	}
+
+	#[test]
+	fn device_display() {
+		let egl = Instance::new(Mock::new().with_client_extensions(Some(
+			"EGL_EXT_client_extensions EGL_EXT_platform_base EGL_EXT_platform_device",
+		)));
+		let device = unsafe { Device::from_ptr(1 as EGLDeviceEXT) };
+
+		let display = egl.get_device_display(device, &[ATTRIB_NONE]).unwrap();
+		assert_eq!(egl.initialize(display), Ok((1, 5)));
+		assert_eq!(egl.get_device_display(device, &[ATTRIB_NONE]), Ok(display));
+		assert_ne!(unsafe { egl.get_display(DEFAULT_DISPLAY) }, Some(display));
+	}
+}
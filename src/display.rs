@@ -0,0 +1,317 @@
+//! Initialized display connections.
+use super::*;
+
+/// Initialized EGL display connection.
+///
+/// This is returned by [`Instance::initialize_owned`] and guarantees that the
+/// underlying display connection is initialized for as long as it lives.
+/// The display connection is terminated when dropped.
+///
+/// Objects created through this type (for instance with
+/// [`create_context`](Self::create_context)) borrow it: they must be dropped,
+/// and thus destroyed, before the display connection can be terminated.
+/// This does not apply to objects created by the `Instance::create_*_owned`
+/// functions from a raw [`Display`], which only borrow the instance.
+///
+/// ```compile_fail,E0505
+/// # extern crate khronos_egl as egl;
+/// fn terminate_early<T: egl::api::EGL1_0>(
+///   egl: &egl::Instance<T>,
+///   display: egl::Display,
+/// ) -> Result<(), egl::Error> {
+///   let display = egl.initialize_owned(display)?;
+///   let context = display.create_context(None, None, &[egl::NONE])?;
+///   drop(display); // error: `context` still borrows `display`.
+///   drop(context);
+///   Ok(())
+/// }
+/// ```
+///
+/// Note that EGL display connections are shared: every call to
+/// `eglGetDisplay` (or `eglGetPlatformDisplay`) with the same native display
+/// returns the same `EGLDisplay` handle, and initializing it again does not
+/// create a new connection. Dropping this guard calls `eglTerminate`, which
+/// terminates the connection for every [`Display`] (and every other
+/// `InitializedDisplay`) using the same handle in the process, and releases
+/// all of their objects.
+pub struct InitializedDisplay<'a, T: api::EGL1_0> {
+	display: OwnedDisplay<'a, T>,
+	version: (Int, Int),
+}
+
+impl<'a, T: api::EGL1_0> InitializedDisplay<'a, T> {
+	/// Takes ownership of the given initialized display connection.
+	///
+	/// # Safety
+	///
+	/// `display` must be a valid display connection obtained from `instance`,
+	/// initialized with the given `version`, and must not be terminated by
+	/// anyone else.
+	#[inline]
+	pub unsafe fn from_raw(
+		instance: &'a Instance<T>,
+		display: Display,
+		version: (Int, Int),
+	) -> Self {
+		InitializedDisplay {
+			display: OwnedDisplay::from_raw(instance, display),
+			version,
+		}
+	}
+
+	/// Releases ownership of the display connection without terminating it.
+	#[inline]
+	pub fn into_raw(self) -> Display {
+		self.display.into_raw()
+	}
+
+	/// Returns the EGL instance used to initialize the display.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.display.instance()
+	}
+
+	/// Returns the underlying display connection.
+	#[inline]
+	pub fn as_raw(&self) -> Display {
+		self.display.as_raw()
+	}
+
+	/// Returns the `(major, minor)` EGL version reported by `eglInitialize`
+	/// for this display connection.
+	#[inline]
+	pub fn version(&self) -> (Int, Int) {
+		self.version
+	}
+
+	/// Return the number of EGL frame buffer configurations that match specified
+	/// attributes.
+	///
+	/// See [`Instance::matching_config_count`].
+	pub fn matching_config_count(&self, attrib_list: &[Int]) -> Result<usize, Error> {
+		self.instance()
+			.matching_config_count(self.as_raw(), attrib_list)
+	}
+
+	/// Return a list of EGL frame buffer configurations that match specified
+	/// attributes.
+	///
+	/// See [`Instance::choose_config`].
+	pub fn choose_config(
+		&self,
+		attrib_list: &[Int],
+		configs: &mut Vec<Config>,
+	) -> Result<(), Error> {
+		self.instance()
+			.choose_config(self.as_raw(), attrib_list, configs)
+	}
+
+	/// Return the first EGL frame buffer configuration that match specified
+	/// attributes.
+	///
+	/// See [`Instance::choose_first_config`].
+	pub fn choose_first_config(&self, attrib_list: &[Int]) -> Result<Option<Config>, Error> {
+		self.instance()
+			.choose_first_config(self.as_raw(), attrib_list)
+	}
+
+	/// Return information about an EGL frame buffer configuration.
+	///
+	/// See [`Instance::get_config_attrib`].
+	pub fn get_config_attrib(&self, config: Config, attribute: Int) -> Result<Int, Error> {
+		self.instance()
+			.get_config_attrib(self.as_raw(), config, attribute)
+	}
+
+	/// Return the number of all frame buffer configurations.
+	///
+	/// See [`Instance::get_config_count`].
+	pub fn get_config_count(&self) -> Result<usize, Error> {
+		self.instance().get_config_count(self.as_raw())
+	}
+
+	/// Get the list of all EGL frame buffer configurations.
+	///
+	/// See [`Instance::get_configs`].
+	pub fn get_configs(&self, configs: &mut Vec<Config>) -> Result<(), Error> {
+		self.instance().get_configs(self.as_raw(), configs)
+	}
+
+	/// Create a new EGL rendering context.
+	///
+	/// See [`Instance::create_context`].
+	pub fn create_context(
+		&self,
+		config: Option<Config>,
+		share_context: Option<Context>,
+		attrib_list: &[Int],
+	) -> Result<OwnedContext<'_, T>, Error> {
+		self.instance()
+			.create_context_owned(self.as_raw(), config, share_context, attrib_list)
+	}
+
+	/// Create a new EGL pixel buffer surface.
+	///
+	/// See [`Instance::create_pbuffer_surface`].
+	pub fn create_pbuffer_surface(
+		&self,
+		config: Config,
+		attrib_list: &[Int],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		self.instance()
+			.create_pbuffer_surface_owned(self.as_raw(), config, attrib_list)
+	}
+
+	/// Create a new EGL offscreen surface.
+	///
+	/// See [`Instance::create_pixmap_surface`].
+	///
+	/// # Safety
+	///
+	/// This function may raise undefined behavior if the display and native
+	/// pixmap do not belong to the same platform.
+	pub unsafe fn create_pixmap_surface(
+		&self,
+		config: Config,
+		pixmap: NativePixmapType,
+		attrib_list: &[Int],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		self.instance()
+			.create_pixmap_surface_owned(self.as_raw(), config, pixmap, attrib_list)
+	}
+
+	/// Create a new EGL window surface.
+	///
+	/// See [`Instance::create_window_surface`].
+	///
+	/// # Safety
+	///
+	/// This function may raise undefined behavior if the display and native
+	/// window do not belong to the same platform.
+	pub unsafe fn create_window_surface(
+		&self,
+		config: Config,
+		window: NativeWindowType,
+		attrib_list: Option<&[Int]>,
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		self.instance()
+			.create_window_surface_owned(self.as_raw(), config, window, attrib_list)
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> InitializedDisplay<'a, T> {
+	/// Create a new EGL sync object.
+	///
+	/// See [`Instance::create_sync`].
+	///
+	/// # Safety
+	///
+	/// When creating an OpenCL Event Sync Object, passing an invalid event
+	/// handle in `attrib_list` may result in undefined behavior up to and including program
+	/// termination.
+	pub unsafe fn create_sync(
+		&self,
+		ty: Enum,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedSync<'_, T>, Error> {
+		self.instance()
+			.create_sync_owned(self.as_raw(), ty, attrib_list)
+	}
+
+	/// Create a new Image object.
+	///
+	/// See [`Instance::create_image`].
+	pub fn create_image(
+		&self,
+		ctx: Context,
+		target: Enum,
+		buffer: ClientBuffer,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedImage<'_, T>, Error> {
+		self.instance()
+			.create_image_owned(self.as_raw(), ctx, target, buffer, attrib_list)
+	}
+
+	/// Create a new EGL on-screen rendering surface.
+	///
+	/// See [`Instance::create_platform_window_surface`].
+	///
+	/// # Safety
+	///
+	/// The `native_window` must be a valid pointer to the native window
+	/// and must belong to the same platform as the display.
+	pub unsafe fn create_platform_window_surface(
+		&self,
+		config: Config,
+		native_window: NativeWindowType,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		let surface = self.instance().create_platform_window_surface(
+			self.as_raw(),
+			config,
+			native_window,
+			attrib_list,
+		)?;
+		Ok(OwnedSurface::from_raw(
+			self.instance(),
+			self.as_raw(),
+			surface,
+		))
+	}
+
+	/// Create a new EGL offscreen surface.
+	///
+	/// See [`Instance::create_platform_pixmap_surface`].
+	///
+	/// # Safety
+	///
+	/// The `native_pixmap` must be a valid pointer to a native pixmap
+	/// and must belong to the same platform as the display.
+	pub unsafe fn create_platform_pixmap_surface(
+		&self,
+		config: Config,
+		native_pixmap: NativePixmapType,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		let surface = self.instance().create_platform_pixmap_surface(
+			self.as_raw(),
+			config,
+			native_pixmap,
+			attrib_list,
+		)?;
+		Ok(OwnedSurface::from_raw(
+			self.instance(),
+			self.as_raw(),
+			surface,
+		))
+	}
+}
+
+impl<'a, T: api::EGL1_0> fmt::Debug for InitializedDisplay<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"InitializedDisplay({:?}, {}.{})",
+			self.as_raw(),
+			self.version.0,
+			self.version.1
+		)
+	}
+}
+
+impl<T: api::EGL1_0> Instance<T> {
+	/// Initialize an EGL display connection, returning a guard that terminates
+	/// it when dropped.
+	///
+	/// The display connection is shared with any other user of the same
+	/// `EGLDisplay` handle in the process, which is also terminated when the
+	/// guard is dropped. Only use this if the display connection is not used
+	/// elsewhere (see [`InitializedDisplay`]).
+	///
+	/// See [`initialize`](Self::initialize).
+	pub fn initialize_owned(&self, display: Display) -> Result<InitializedDisplay<'_, T>, Error> {
+		let version = self.initialize(display)?;
+		unsafe { Ok(InitializedDisplay::from_raw(self, display, version)) }
+	}
+}
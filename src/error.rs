@@ -0,0 +1,240 @@
+//! Failed EGL calls context.
+use super::*;
+use std::cell::RefCell;
+
+thread_local! {
+	/// Context of the last failed EGL call on this thread.
+	static LAST_CONTEXT: RefCell<Option<ErrorContext>> = const { RefCell::new(None) };
+}
+
+/// Context of a failed EGL function call.
+///
+/// This records the EGL function that failed, the display connection and the
+/// relevant arguments of the call. The context of the last failure on the
+/// calling thread can be retrieved from the returned [`Error`] using
+/// [`Error::context`], or captured along with the error in a
+/// [`DetailedError`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ErrorContext {
+	function: &'static str,
+	error: Error,
+	display: Option<usize>,
+	arguments: Vec<(&'static str, String)>,
+	attrib_list: Option<Vec<Attrib>>,
+}
+
+impl ErrorContext {
+	pub(crate) fn new(function: &'static str) -> Self {
+		ErrorContext {
+			function,
+			error: Error::Other(SUCCESS),
+			display: None,
+			arguments: Vec::new(),
+			attrib_list: None,
+		}
+	}
+
+	pub(crate) fn with_display(mut self, display: Display) -> Self {
+		self.display = Some(display.as_ptr() as usize);
+		self
+	}
+
+	pub(crate) fn with_arg(mut self, name: &'static str, value: &dyn fmt::Debug) -> Self {
+		self.arguments.push((name, format!("{:?}", value)));
+		self
+	}
+
+	pub(crate) fn with_int_list(mut self, attrib_list: &[Int]) -> Self {
+		self.attrib_list = Some(attrib_list.iter().map(|a| *a as Attrib).collect());
+		self
+	}
+
+	#[cfg(feature = "1_5")]
+	pub(crate) fn with_attrib_list(mut self, attrib_list: &[Attrib]) -> Self {
+		self.attrib_list = Some(attrib_list.to_vec());
+		self
+	}
+
+	/// Returns the name of the EGL function that failed (e.g. `eglCreateContext`).
+	#[inline]
+	pub fn function(&self) -> &'static str {
+		self.function
+	}
+
+	/// Returns the error returned by the function.
+	#[inline]
+	pub fn error(&self) -> Error {
+		self.error
+	}
+
+	/// Returns the display connection given to the function, if any.
+	pub fn display(&self) -> Option<Display> {
+		self.display
+			.map(|display| unsafe { Display::from_ptr(display as EGLDisplay) })
+	}
+
+	/// Returns the other arguments given to the function, with their name,
+	/// formatted using their `Debug` implementation.
+	#[inline]
+	pub fn arguments(&self) -> &[(&'static str, String)] {
+		&self.arguments
+	}
+
+	/// Returns the attribute list given to the function, if any.
+	///
+	/// `Int` attribute lists are converted into `Attrib` lists.
+	#[inline]
+	pub fn attribs(&self) -> Option<&[Attrib]> {
+		self.attrib_list.as_deref()
+	}
+}
+
+impl fmt::Display for ErrorContext {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}(", self.function)?;
+
+		let mut first = true;
+		let mut separator = |f: &mut fmt::Formatter| {
+			if first {
+				first = false;
+				Ok(())
+			} else {
+				f.write_str(", ")
+			}
+		};
+
+		if let Some(display) = self.display {
+			separator(f)?;
+			write!(f, "display = {:#x}", display)?;
+		}
+
+		for (name, value) in &self.arguments {
+			separator(f)?;
+			write!(f, "{} = {}", name, value)?;
+		}
+
+		if let Some(attrib_list) = &self.attrib_list {
+			separator(f)?;
+			write!(f, "attrib_list = [")?;
+			for (i, a) in attrib_list.iter().enumerate() {
+				if i > 0 {
+					f.write_str(", ")?;
+				}
+
+				write!(f, "{:#x}", a)?;
+			}
+			f.write_str("]")?;
+		}
+
+		write!(f, ") failed: {}", self.error)
+	}
+}
+
+impl Error {
+	/// Returns the context of the EGL function call that returned this error.
+	///
+	/// This is the context of the last failed EGL call on the calling thread,
+	/// if it returned the same error. It is overwritten by the next failure
+	/// on the same thread, so it should be retrieved right after the error
+	/// is returned (for instance by converting it into a [`DetailedError`]).
+	///
+	/// Errors detected by this crate without calling EGL (such as an invalid
+	/// attribute list) have no context.
+	pub fn context(&self) -> Option<ErrorContext> {
+		LAST_CONTEXT.with(|c| {
+			c.borrow()
+				.as_ref()
+				.filter(|context| context.error == *self)
+				.cloned()
+		})
+	}
+
+	/// Marks this error as detected by this crate without calling EGL.
+	///
+	/// This forgets the context of the last failed EGL call on the calling
+	/// thread so that it is not returned by [`context`](Self::context).
+	pub(crate) fn without_context(self) -> Error {
+		LAST_CONTEXT.with(|c| *c.borrow_mut() = None);
+		self
+	}
+}
+
+/// EGL error with the context of the failed call.
+///
+/// This can be used in place of [`Error`] as error type: the `?` operator
+/// will capture the context of the failed call when converting the returned
+/// [`Error`].
+///
+/// ## Example
+///
+/// ```no_run
+/// # extern crate khronos_egl as egl;
+/// fn create_context(
+///   egl: &egl::Instance<egl::Static>,
+///   display: egl::Display,
+///   config: egl::Config,
+/// ) -> Result<egl::Context, egl::DetailedError> {
+///   let context = egl.create_context(display, Some(config), None, &[egl::NONE])?;
+///   Ok(context)
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DetailedError {
+	error: Error,
+	context: Option<ErrorContext>,
+}
+
+impl DetailedError {
+	/// Returns the EGL error.
+	#[inline]
+	pub fn error(&self) -> Error {
+		self.error
+	}
+
+	/// Returns the context of the failed call, if known.
+	#[inline]
+	pub fn context(&self) -> Option<&ErrorContext> {
+		self.context.as_ref()
+	}
+}
+
+impl From<Error> for DetailedError {
+	fn from(error: Error) -> Self {
+		DetailedError {
+			error,
+			context: error.context(),
+		}
+	}
+}
+
+impl From<DetailedError> for Error {
+	fn from(e: DetailedError) -> Self {
+		e.error
+	}
+}
+
+impl fmt::Display for DetailedError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match &self.context {
+			Some(context) => context.fmt(f),
+			None => self.error.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for DetailedError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+#[cfg(feature = "1_0")]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Return the error of a failed EGL function call, recording its context.
+	pub(crate) fn error_in(&self, mut context: ErrorContext) -> Error {
+		context.error = self.last_error();
+		let error = context.error;
+		LAST_CONTEXT.with(|c| *c.borrow_mut() = Some(context));
+		error
+	}
+}
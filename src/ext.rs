@@ -0,0 +1,423 @@
+//! Extension functions.
+use super::*;
+
+/// EGL device type (`EGL_EXT_device_base`).
+pub type EGLDeviceEXT = *mut c_void;
+
+/// Where an extension is advertised.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ExtensionKind {
+	/// Client extension, advertised by [`Instance::client_extensions`].
+	Client,
+
+	/// Display extension, advertised by [`Instance::display_extensions`].
+	Display,
+}
+
+/// Function table of an EGL extension.
+///
+/// Tables are loaded with [`Instance::load_extension`], through
+/// `eglGetProcAddress`, only if the extension is advertised.
+///
+/// # Safety
+///
+/// [`Extension::load`] must cast every function pointer returned by the
+/// loader to the prototype given by the extension specification.
+pub unsafe trait Extension: Copy + Send + std::marker::Sync + 'static {
+	/// Name of the extension (e.g. `EGL_KHR_image_base`).
+	const NAME: &'static str;
+
+	/// Where the extension is advertised.
+	const KIND: ExtensionKind;
+
+	/// Loads the extension functions using the given `eglGetProcAddress`
+	/// loader.
+	///
+	/// Returns `None` if any function is missing.
+	///
+	/// # Safety
+	///
+	/// The loader must return the function with the given name, if any.
+	unsafe fn load(loader: &mut dyn FnMut(&str) -> Option<extern "system" fn()>) -> Option<Self>;
+}
+
+macro_rules! extensions {
+	($($id:ident : $name:literal ($kind:ident, $version:literal) { $(fn $fname:ident ($($arg:ident : $atype:ty ),* ) $(-> $rtype:ty)? ;)* })*) => {
+		$(
+			#[cfg(feature=$version)]
+			#[doc = concat!("`", $name, "` function table.")]
+			#[derive(Clone, Copy)]
+			#[allow(non_snake_case)]
+			pub struct $id {
+				$(
+					$fname: unsafe extern "system" fn($($atype),*) $(-> $rtype)?,
+				)*
+			}
+
+			#[cfg(feature=$version)]
+			#[allow(non_snake_case)]
+			impl $id {
+				$(
+					#[doc = concat!("Calls `", stringify!($fname), "`.")]
+					///
+					/// # Safety
+					///
+					/// The arguments must be valid according to the extension specification.
+					#[inline(always)]
+					#[allow(clippy::too_many_arguments)]
+					pub unsafe fn $fname(&self, $($arg: $atype),*) $(-> $rtype)? {
+						(self.$fname)($($arg),*)
+					}
+				)*
+			}
+
+			#[cfg(feature=$version)]
+			unsafe impl Extension for $id {
+				const NAME: &'static str = $name;
+				const KIND: ExtensionKind = ExtensionKind::$kind;
+
+				unsafe fn load(loader: &mut dyn FnMut(&str) -> Option<extern "system" fn()>) -> Option<Self> {
+					Some($id {
+						$(
+							$fname: std::mem::transmute::<
+								extern "system" fn(),
+								unsafe extern "system" fn($($atype),*) $(-> $rtype)?,
+							>(loader(stringify!($fname))?),
+						)*
+					})
+				}
+			}
+
+			#[cfg(feature=$version)]
+			impl fmt::Debug for $id {
+				fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					f.debug_struct(stringify!($id))
+						$(
+							.field(stringify!($fname), &(self.$fname as *const c_void))
+						)*
+						.finish()
+				}
+			}
+		)*
+	};
+}
+
+include!("generated/extensions.rs");
+
+// `EGL_EXT_device_base` is the union of `EGL_EXT_device_enumeration` and
+// `EGL_EXT_device_query`, and some implementations only advertise it.
+
+#[cfg(feature = "1_0")]
+impl From<ExtDeviceBase> for ExtDeviceEnumeration {
+	fn from(base: ExtDeviceBase) -> Self {
+		ExtDeviceEnumeration {
+			eglQueryDevicesEXT: base.eglQueryDevicesEXT,
+		}
+	}
+}
+
+#[cfg(feature = "1_0")]
+impl From<ExtDeviceBase> for ExtDeviceQuery {
+	fn from(base: ExtDeviceBase) -> Self {
+		ExtDeviceQuery {
+			eglQueryDeviceAttribEXT: base.eglQueryDeviceAttribEXT,
+			eglQueryDeviceStringEXT: base.eglQueryDeviceStringEXT,
+			eglQueryDisplayAttribEXT: base.eglQueryDisplayAttribEXT,
+		}
+	}
+}
+
+// `EGL_KHR_reusable_sync` includes the functions of `EGL_KHR_fence_sync`.
+
+#[cfg(feature = "1_2")]
+impl From<KhrReusableSync> for KhrFenceSync {
+	fn from(reusable: KhrReusableSync) -> Self {
+		KhrFenceSync {
+			eglCreateSyncKHR: reusable.eglCreateSyncKHR,
+			eglDestroySyncKHR: reusable.eglDestroySyncKHR,
+			eglClientWaitSyncKHR: reusable.eglClientWaitSyncKHR,
+			eglGetSyncAttribKHR: reusable.eglGetSyncAttribKHR,
+		}
+	}
+}
+
+#[cfg(feature = "1_0")]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Loads the function table of the given extension.
+	///
+	/// Display extensions are looked up in the extensions of the given
+	/// (initialized) display connection, and fail with [`Error::BadDisplay`]
+	/// if no display is given. Client extensions ignore the display.
+	///
+	/// Returns [`Error::ExtensionNotSupported`] if the extension is not
+	/// advertised, or if any of its functions cannot be found.
+	/// Loaded tables are cached by the instance.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// let devices = egl.load_extension::<egl::ExtDeviceEnumeration>(None)?;
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn load_extension<E: Extension>(&self, display: Option<Display>) -> Result<E, Error> {
+		let supported = match (E::KIND, display) {
+			(ExtensionKind::Client, _) => self.client_extensions()?.has(E::NAME),
+			(ExtensionKind::Display, Some(display)) => {
+				self.display_extensions(display)?.has(E::NAME)
+			}
+			(ExtensionKind::Display, None) => return Err(Error::BadDisplay.without_context()),
+		};
+
+		if !supported {
+			return Err(Error::ExtensionNotSupported(E::NAME).without_context());
+		}
+
+		if let Some(table) = self.extensions.functions::<E>() {
+			return Ok(table);
+		}
+
+		let table = unsafe { E::load(&mut |name| self.get_proc_address(name)) }
+			.ok_or_else(|| Error::ExtensionNotSupported(E::NAME).without_context())?;
+		self.extensions.insert_functions(table);
+		Ok(table)
+	}
+
+	/// Post EGL surface color buffer to a native window, with the given damage
+	/// rectangles (`EGL_KHR_swap_buffers_with_damage`).
+	///
+	/// Each rectangle is given as `[x, y, width, height]`, relative to the
+	/// bottom-left corner of the surface.
+	pub fn swap_buffers_with_damage(
+		&self,
+		display: Display,
+		surface: Surface,
+		rects: &[[Int; 4]],
+	) -> Result<(), Error> {
+		let ext = self.load_extension::<KhrSwapBuffersWithDamage>(Some(display))?;
+		unsafe {
+			if ext.eglSwapBuffersWithDamageKHR(
+				display.as_ptr(),
+				surface.as_ptr(),
+				rects.as_ptr() as *mut Int,
+				rects.len() as Int,
+			) == TRUE
+			{
+				Ok(())
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglSwapBuffersWithDamageKHR")
+						.with_display(display)
+						.with_arg("surface", &surface)
+						.with_arg("rects", &rects),
+				))
+			}
+		}
+	}
+
+	/// Bind a context to the current rendering thread without any draw and
+	/// read surface (`EGL_KHR_surfaceless_context`).
+	///
+	/// Rendering then goes to framebuffer objects of the client API.
+	/// Returns [`Error::ExtensionNotSupported`] if the display does not
+	/// support the extension.
+	pub fn make_current_surfaceless(&self, display: Display, ctx: Context) -> Result<(), Error> {
+		if !self
+			.display_extensions(display)?
+			.has("EGL_KHR_surfaceless_context")
+		{
+			return Err(
+				Error::ExtensionNotSupported("EGL_KHR_surfaceless_context").without_context()
+			);
+		}
+
+		self.make_current(display, None, None, Some(ctx))
+	}
+}
+
+/// Parses the `(major, minor)` version at the start of a `VERSION` string
+/// (e.g. `1.5 Mesa 24.0.0`).
+#[cfg(feature = "1_5")]
+pub(crate) fn parse_version(version: &CStr) -> (Int, Int) {
+	let version = version.to_string_lossy();
+	let mut numbers = version
+		.split(|c: char| !c.is_ascii_digit())
+		.map(|n| n.parse::<Int>().unwrap_or(0));
+	let major = numbers.next().unwrap_or(0);
+	let minor = numbers.next().unwrap_or(0);
+	(major, minor)
+}
+
+#[cfg(feature = "1_2")]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Get an EGL display connection for the given platform using
+	/// `eglGetPlatformDisplayEXT` (`EGL_EXT_platform_base`).
+	///
+	/// The `Attrib` list is converted into the `Int` list expected by the
+	/// extension.
+	///
+	/// # Safety
+	///
+	/// `native_display` must be valid for the given platform.
+	pub(crate) unsafe fn platform_display_ext(
+		&self,
+		platform: Enum,
+		native_display: *mut c_void,
+		attrib_list: &[Attrib],
+	) -> Result<Display, Error> {
+		check_attrib_list(attrib_list)?;
+
+		let ext = self.load_extension::<ExtPlatformBase>(None)?;
+		let int_list: Vec<Int> = attrib_list.iter().map(|a| *a as Int).collect();
+		let display = ext.eglGetPlatformDisplayEXT(platform, native_display, int_list.as_ptr());
+		if display != NO_DISPLAY {
+			Ok(Display::from_ptr(display))
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglGetPlatformDisplayEXT")
+					.with_arg("platform", &platform)
+					.with_arg("native_display", &native_display)
+					.with_int_list(&int_list),
+			))
+		}
+	}
+
+	/// Get an EGL display connection on the surfaceless platform
+	/// (`EGL_MESA_platform_surfaceless`) using `eglGetPlatformDisplayEXT`.
+	///
+	/// This is meant for APIs that do not provide EGL 1.5. Since the display
+	/// is created by an extension function, it is not seen by API wrappers
+	/// such as `Traced` or `Validated`. `Validated` tracks it once it is
+	/// initialized.
+	pub fn get_surfaceless_display_ext(&self) -> Result<Display, Error> {
+		if !self
+			.client_extensions()?
+			.has("EGL_MESA_platform_surfaceless")
+		{
+			return Err(
+				Error::ExtensionNotSupported("EGL_MESA_platform_surfaceless").without_context(),
+			);
+		}
+
+		unsafe {
+			self.platform_display_ext(PLATFORM_SURFACELESS_MESA, ptr::null_mut(), &[ATTRIB_NONE])
+		}
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<T: api::EGL1_5> Instance<T> {
+	/// Get an EGL display connection on the surfaceless platform
+	/// (`EGL_MESA_platform_surfaceless`).
+	///
+	/// This platform does not need any window system nor GPU, and only
+	/// supports off-screen rendering (pbuffers and surfaceless contexts).
+	/// This uses `eglGetPlatformDisplay`. Use
+	/// [`get_surfaceless_display_ext`](Self::get_surfaceless_display_ext)
+	/// when the API does not provide EGL 1.5.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// let display = egl.get_surfaceless_display()?;
+	/// egl.initialize(display)?;
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn get_surfaceless_display(&self) -> Result<Display, Error> {
+		if !self
+			.client_extensions()?
+			.has("EGL_MESA_platform_surfaceless")
+		{
+			return Err(
+				Error::ExtensionNotSupported("EGL_MESA_platform_surfaceless").without_context(),
+			);
+		}
+
+		unsafe {
+			self.get_platform_display(PLATFORM_SURFACELESS_MESA, ptr::null_mut(), &[ATTRIB_NONE])
+		}
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn crate_errors_have_no_context() {
+		let egl = Instance::new(Mock::new());
+		let unknown = unsafe { Display::from_ptr(usize::MAX as EGLDisplay) };
+
+		// A failed EGL call leaves a `BadDisplay` context behind.
+		let e = egl.query_string(Some(unknown), VENDOR).unwrap_err();
+		assert_eq!(e, Error::BadDisplay);
+		assert_eq!(e.context().unwrap().function(), "eglQueryString");
+
+		// Which must not be attached to the same error detected by the crate.
+		let e = egl.load_extension::<KhrFenceSync>(None).unwrap_err();
+		assert_eq!(e, Error::BadDisplay);
+		assert!(e.context().is_none());
+
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let e = egl
+			.load_extension::<KhrFenceSync>(Some(display))
+			.unwrap_err();
+		assert_eq!(e, Error::ExtensionNotSupported("EGL_KHR_fence_sync"));
+		assert!(e.context().is_none());
+	}
+
+	#[test]
+	fn surfaceless_context() {
+		let egl = Instance::new(Mock::new());
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let ctx = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+
+		assert_eq!(
+			egl.make_current_surfaceless(display, ctx),
+			Err(Error::ExtensionNotSupported("EGL_KHR_surfaceless_context"))
+		);
+		assert_eq!(egl.get_current_context(), None);
+
+		let egl = Instance::new(Mock::new().with_display_extensions("EGL_KHR_surfaceless_context"));
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let ctx = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+
+		assert_eq!(egl.make_current_surfaceless(display, ctx), Ok(()));
+		assert_eq!(egl.get_current_context(), Some(ctx));
+		egl.make_current(display, None, None, None).unwrap();
+	}
+
+	#[test]
+	fn no_config_context() {
+		let egl = Instance::new(Mock::new());
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+
+		let e = egl
+			.create_context(display, None, None, &[NONE])
+			.unwrap_err();
+		assert_eq!(e, Error::ExtensionNotSupported("EGL_KHR_no_config_context"));
+		assert!(e.context().is_none());
+
+		for extension in ["EGL_KHR_no_config_context", "EGL_MESA_configless_context"] {
+			let egl = Instance::new(Mock::new().with_display_extensions(extension));
+			let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+			egl.initialize(display).unwrap();
+
+			let ctx = egl.create_context(display, None, None, &[NONE]).unwrap();
+			assert_eq!(egl.query_context(display, ctx, CONFIG_ID), Ok(0));
+		}
+	}
+}
@@ -0,0 +1,207 @@
+//! Extension queries.
+use super::*;
+use std::any::{Any, TypeId};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Set of EGL extensions.
+///
+/// This is returned by [`Instance::client_extensions`] and
+/// [`Instance::display_extensions`], and is cheap to clone.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Extensions {
+	names: Arc<BTreeSet<String>>,
+}
+
+impl Extensions {
+	/// Parses a space-separated list of extension names, as returned by
+	/// `eglQueryString` with `EXTENSIONS`.
+	pub fn parse(list: &str) -> Self {
+		Extensions {
+			names: Arc::new(list.split_ascii_whitespace().map(String::from).collect()),
+		}
+	}
+
+	/// Checks if the given extension (e.g. `EGL_KHR_image_base`) is in the set.
+	#[inline]
+	pub fn has(&self, name: &str) -> bool {
+		self.names.contains(name)
+	}
+
+	/// Returns the number of extensions in the set.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.names.len()
+	}
+
+	/// Checks if the set is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.names.is_empty()
+	}
+
+	/// Returns an iterator over the extension names, in lexicographic order.
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = &str> {
+		self.names.iter().map(String::as_str)
+	}
+}
+
+impl fmt::Display for Extensions {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for (i, name) in self.iter().enumerate() {
+			if i > 0 {
+				f.write_str(" ")?;
+			}
+
+			f.write_str(name)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Extension sets and function tables cached by an [`Instance`].
+pub(crate) struct ExtensionCache {
+	client: OnceLock<Extensions>,
+	displays: Mutex<Vec<(usize, Extensions)>>,
+	functions: Mutex<Vec<(TypeId, Box<dyn Any + Send + std::marker::Sync>)>>,
+}
+
+impl ExtensionCache {
+	pub(crate) const fn new() -> Self {
+		ExtensionCache {
+			client: OnceLock::new(),
+			displays: Mutex::new(Vec::new()),
+			functions: Mutex::new(Vec::new()),
+		}
+	}
+
+	fn display(&self, display: Display) -> Option<Extensions> {
+		let key = display.as_ptr() as usize;
+		let displays = self.displays.lock().unwrap();
+		displays
+			.iter()
+			.find(|(d, _)| *d == key)
+			.map(|(_, extensions)| extensions.clone())
+	}
+
+	fn insert_display(&self, display: Display, extensions: Extensions) {
+		let key = display.as_ptr() as usize;
+		let mut displays = self.displays.lock().unwrap();
+		if !displays.iter().any(|(d, _)| *d == key) {
+			displays.push((key, extensions))
+		}
+	}
+
+	/// Forgets the extensions of the given display, after it is terminated.
+	pub(crate) fn forget_display(&self, display: Display) {
+		let key = display.as_ptr() as usize;
+		self.displays.lock().unwrap().retain(|(d, _)| *d != key)
+	}
+
+	/// Returns the loaded function table of the given extension, if any.
+	pub(crate) fn functions<E: Extension>(&self) -> Option<E> {
+		let functions = self.functions.lock().unwrap();
+		functions
+			.iter()
+			.find(|(id, _)| *id == TypeId::of::<E>())
+			.and_then(|(_, table)| table.downcast_ref::<E>().copied())
+	}
+
+	pub(crate) fn insert_functions<E: Extension>(&self, table: E) {
+		let mut functions = self.functions.lock().unwrap();
+		if !functions.iter().any(|(id, _)| *id == TypeId::of::<E>()) {
+			functions.push((TypeId::of::<E>(), Box::new(table)))
+		}
+	}
+}
+
+#[cfg(feature = "1_0")]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Returns the client extensions.
+	///
+	/// The result is cached after the first successful query.
+	/// If the implementation does not support `EGL_EXT_client_extensions`, in
+	/// which case querying the extensions with `NO_DISPLAY` fails with
+	/// [`Error::BadDisplay`], an empty set is returned.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// if egl.client_extensions()?.has("EGL_EXT_platform_base") {
+	///   // ...
+	/// }
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn client_extensions(&self) -> Result<Extensions, Error> {
+		if let Some(extensions) = self.extensions.client.get() {
+			return Ok(extensions.clone());
+		}
+
+		let extensions = match self.query_string(None, EXTENSIONS) {
+			Ok(list) => Extensions::parse(&list.to_string_lossy()),
+			Err(Error::BadDisplay) => Extensions::default(),
+			Err(e) => return Err(e),
+		};
+
+		Ok(self.extensions.client.get_or_init(|| extensions).clone())
+	}
+
+	/// Returns the extensions supported by the given display connection.
+	///
+	/// The display must be initialized. The result is cached per display
+	/// connection until it is terminated with [`Instance::terminate`].
+	pub fn display_extensions(&self, display: Display) -> Result<Extensions, Error> {
+		if let Some(extensions) = self.extensions.display(display) {
+			return Ok(extensions);
+		}
+
+		let list = self.query_string(Some(display), EXTENSIONS)?;
+		let extensions = Extensions::parse(&list.to_string_lossy());
+		self.extensions.insert_display(display, extensions.clone());
+		Ok(extensions)
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_extensions_cache() {
+		let egl = Instance::new(
+			Mock::new().with_display_extensions("EGL_KHR_image_base EGL_KHR_surfaceless_context"),
+		);
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+
+		assert_eq!(
+			egl.display_extensions(display).unwrap_err(),
+			Error::NotInitialized
+		);
+
+		egl.initialize(display).unwrap();
+		let extensions = egl.display_extensions(display).unwrap();
+		assert_eq!(extensions.len(), 2);
+		assert!(extensions.has("EGL_KHR_surfaceless_context"));
+		assert!(!extensions.has("EGL_KHR_fence_sync"));
+		assert_eq!(
+			egl.load_extension::<KhrFenceSync>(Some(display))
+				.unwrap_err(),
+			Error::ExtensionNotSupported("EGL_KHR_fence_sync")
+		);
+
+		// The cached set is forgotten when the display is terminated, and
+		// queried again once it is initialized.
+		egl.terminate(display).unwrap();
+		assert_eq!(
+			egl.display_extensions(display).unwrap_err(),
+			Error::NotInitialized
+		);
+
+		egl.initialize(display).unwrap();
+		assert_eq!(egl.display_extensions(display).unwrap(), extensions);
+	}
+}
@@ -0,0 +1,157 @@
+// This file is generated from `registry/egl.xml` by `cargo run -p khronos-egl-generator`.
+// Do not edit it by hand.
+
+api! {
+	EGL1_0 : "1_0" {
+		fn eglChooseConfig(
+			display: EGLDisplay,
+			attrib_list: *const Int,
+			configs: *mut EGLConfig,
+			config_size: Int,
+			num_config: *mut Int
+		) -> Boolean;
+		fn eglCopyBuffers(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			target: NativePixmapType
+		) -> Boolean;
+		fn eglCreateContext(
+			display: EGLDisplay,
+			config: EGLConfig,
+			share_context: EGLContext,
+			attrib_list: *const Int
+		) -> EGLContext;
+		fn eglCreatePbufferSurface(
+			display: EGLDisplay,
+			config: EGLConfig,
+			attrib_list: *const Int
+		) -> EGLSurface;
+		fn eglCreatePixmapSurface(
+			display: EGLDisplay,
+			config: EGLConfig,
+			pixmap: NativePixmapType,
+			attrib_list: *const Int
+		) -> EGLSurface;
+		fn eglCreateWindowSurface(
+			display: EGLDisplay,
+			config: EGLConfig,
+			win: NativeWindowType,
+			attrib_list: *const Int
+		) -> EGLSurface;
+		fn eglDestroyContext(display: EGLDisplay, ctx: EGLContext) -> Boolean;
+		fn eglDestroySurface(display: EGLDisplay, surface: EGLSurface) -> Boolean;
+		fn eglGetConfigAttrib(
+			display: EGLDisplay,
+			config: EGLConfig,
+			attribute: Int,
+			value: *mut Int
+		) -> Boolean;
+		fn eglGetConfigs(
+			display: EGLDisplay,
+			configs: *mut EGLConfig,
+			config_size: Int,
+			num_config: *mut Int
+		) -> Boolean;
+		fn eglGetCurrentDisplay() -> EGLDisplay;
+		fn eglGetCurrentSurface(readdraw: Int) -> EGLSurface;
+		fn eglGetDisplay(display_id: NativeDisplayType) -> EGLDisplay;
+		fn eglGetError() -> Int;
+		fn eglGetProcAddress(procname: *const c_char) -> Option<extern "system" fn()>;
+		fn eglInitialize(display: EGLDisplay, major: *mut Int, minor: *mut Int) -> Boolean;
+		fn eglMakeCurrent(
+			display: EGLDisplay,
+			draw: EGLSurface,
+			read: EGLSurface,
+			ctx: EGLContext
+		) -> Boolean;
+		fn eglQueryContext(
+			display: EGLDisplay,
+			ctx: EGLContext,
+			attribute: Int,
+			value: *mut Int
+		) -> Boolean;
+		fn eglQueryString(display: EGLDisplay, name: Int) -> *const c_char;
+		fn eglQuerySurface(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attribute: Int,
+			value: *mut Int
+		) -> Boolean;
+		fn eglSwapBuffers(display: EGLDisplay, surface: EGLSurface) -> Boolean;
+		fn eglTerminate(display: EGLDisplay) -> Boolean;
+		fn eglWaitGL() -> Boolean;
+		fn eglWaitNative(engine: Int) -> Boolean;
+	},
+
+	EGL1_1 : "1_1" {
+		fn eglBindTexImage(display: EGLDisplay, surface: EGLSurface, buffer: Int) -> Boolean;
+		fn eglReleaseTexImage(display: EGLDisplay, surface: EGLSurface, buffer: Int) -> Boolean;
+		fn eglSurfaceAttrib(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attribute: Int,
+			value: Int
+		) -> Boolean;
+		fn eglSwapInterval(display: EGLDisplay, interval: Int) -> Boolean;
+	},
+
+	EGL1_2 : "1_2" {
+		fn eglBindAPI(api: Enum) -> Boolean;
+		fn eglQueryAPI() -> Enum;
+		fn eglCreatePbufferFromClientBuffer(
+			display: EGLDisplay,
+			buftype: Enum,
+			buffer: EGLClientBuffer,
+			config: EGLConfig,
+			attrib_list: *const Int
+		) -> EGLSurface;
+		fn eglReleaseThread() -> Boolean;
+		fn eglWaitClient() -> Boolean;
+	},
+
+	EGL1_3 : "1_3" {
+		// nothing.
+	},
+
+	EGL1_4 : "1_4" {
+		fn eglGetCurrentContext() -> EGLContext;
+	},
+
+	EGL1_5 : "1_5" {
+		fn eglCreateSync(display: EGLDisplay, type_: Enum, attrib_list: *const Attrib) -> EGLSync;
+		fn eglDestroySync(display: EGLDisplay, sync: EGLSync) -> Boolean;
+		fn eglClientWaitSync(display: EGLDisplay, sync: EGLSync, flags: Int, timeout: Time) -> Int;
+		fn eglGetSyncAttrib(
+			display: EGLDisplay,
+			sync: EGLSync,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglCreateImage(
+			display: EGLDisplay,
+			ctx: EGLContext,
+			target: Enum,
+			buffer: EGLClientBuffer,
+			attrib_list: *const Attrib
+		) -> EGLImage;
+		fn eglDestroyImage(display: EGLDisplay, image: EGLImage) -> Boolean;
+		fn eglGetPlatformDisplay(
+			platform: Enum,
+			native_display: *mut c_void,
+			attrib_list: *const Attrib
+		) -> EGLDisplay;
+		fn eglCreatePlatformWindowSurface(
+			display: EGLDisplay,
+			config: EGLConfig,
+			native_window: *mut c_void,
+			attrib_list: *const Attrib
+		) -> EGLSurface;
+		fn eglCreatePlatformPixmapSurface(
+			display: EGLDisplay,
+			config: EGLConfig,
+			native_pixmap: *mut c_void,
+			attrib_list: *const Attrib
+		) -> EGLSurface;
+		fn eglWaitSync(display: EGLDisplay, sync: EGLSync, flags: Int) -> Boolean;
+	}
+}
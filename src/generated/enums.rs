@@ -0,0 +1,1290 @@
+// This file is generated from `registry/egl.xml` by `cargo run -p khronos-egl-generator`.
+// Do not edit it by hand.
+
+// EGL_VERSION_1_0
+#[cfg(feature = "1_0")]
+pub const ALPHA_SIZE: Int = 0x3021;
+#[cfg(feature = "1_0")]
+pub const BAD_ACCESS: Int = 0x3002;
+#[cfg(feature = "1_0")]
+pub const BAD_ALLOC: Int = 0x3003;
+#[cfg(feature = "1_0")]
+pub const BAD_ATTRIBUTE: Int = 0x3004;
+#[cfg(feature = "1_0")]
+pub const BAD_CONFIG: Int = 0x3005;
+#[cfg(feature = "1_0")]
+pub const BAD_CONTEXT: Int = 0x3006;
+#[cfg(feature = "1_0")]
+pub const BAD_CURRENT_SURFACE: Int = 0x3007;
+#[cfg(feature = "1_0")]
+pub const BAD_DISPLAY: Int = 0x3008;
+#[cfg(feature = "1_0")]
+pub const BAD_MATCH: Int = 0x3009;
+#[cfg(feature = "1_0")]
+pub const BAD_NATIVE_PIXMAP: Int = 0x300A;
+#[cfg(feature = "1_0")]
+pub const BAD_NATIVE_WINDOW: Int = 0x300B;
+#[cfg(feature = "1_0")]
+pub const BAD_PARAMETER: Int = 0x300C;
+#[cfg(feature = "1_0")]
+pub const BAD_SURFACE: Int = 0x300D;
+#[cfg(feature = "1_0")]
+pub const BLUE_SIZE: Int = 0x3022;
+#[cfg(feature = "1_0")]
+pub const BUFFER_SIZE: Int = 0x3020;
+#[cfg(feature = "1_0")]
+pub const CONFIG_CAVEAT: Int = 0x3027;
+#[cfg(feature = "1_0")]
+pub const CONFIG_ID: Int = 0x3028;
+#[cfg(feature = "1_0")]
+pub const CORE_NATIVE_ENGINE: Int = 0x305B;
+#[cfg(feature = "1_0")]
+pub const DEPTH_SIZE: Int = 0x3025;
+#[cfg(feature = "1_0")]
+pub const DONT_CARE: Int = -1;
+#[cfg(feature = "1_0")]
+pub const DRAW: Int = 0x3059;
+#[cfg(feature = "1_0")]
+pub const EXTENSIONS: Int = 0x3055;
+#[cfg(feature = "1_0")]
+pub const FALSE: Boolean = 0;
+#[cfg(feature = "1_0")]
+pub const GREEN_SIZE: Int = 0x3023;
+#[cfg(feature = "1_0")]
+pub const HEIGHT: Int = 0x3056;
+#[cfg(feature = "1_0")]
+pub const LARGEST_PBUFFER: Int = 0x3058;
+#[cfg(feature = "1_0")]
+pub const LEVEL: Int = 0x3029;
+#[cfg(feature = "1_0")]
+pub const MAX_PBUFFER_HEIGHT: Int = 0x302A;
+#[cfg(feature = "1_0")]
+pub const MAX_PBUFFER_PIXELS: Int = 0x302B;
+#[cfg(feature = "1_0")]
+pub const MAX_PBUFFER_WIDTH: Int = 0x302C;
+#[cfg(feature = "1_0")]
+pub const NATIVE_RENDERABLE: Int = 0x302D;
+#[cfg(feature = "1_0")]
+pub const NATIVE_VISUAL_ID: Int = 0x302E;
+#[cfg(feature = "1_0")]
+pub const NATIVE_VISUAL_TYPE: Int = 0x302F;
+#[cfg(feature = "1_0")]
+pub const NONE: Int = 0x3038;
+#[cfg(feature = "1_0")]
+pub const NON_CONFORMANT_CONFIG: Int = 0x3051;
+#[cfg(feature = "1_0")]
+pub const NOT_INITIALIZED: Int = 0x3001;
+#[cfg(feature = "1_0")]
+pub const NO_CONTEXT: EGLContext = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const NO_DISPLAY: EGLDisplay = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const NO_SURFACE: EGLSurface = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const PBUFFER_BIT: Int = 0x0001;
+#[cfg(feature = "1_0")]
+pub const PIXMAP_BIT: Int = 0x0002;
+#[cfg(feature = "1_0")]
+pub const READ: Int = 0x305A;
+#[cfg(feature = "1_0")]
+pub const RED_SIZE: Int = 0x3024;
+#[cfg(feature = "1_0")]
+pub const SAMPLES: Int = 0x3031;
+#[cfg(feature = "1_0")]
+pub const SAMPLE_BUFFERS: Int = 0x3032;
+#[cfg(feature = "1_0")]
+pub const SLOW_CONFIG: Int = 0x3050;
+#[cfg(feature = "1_0")]
+pub const STENCIL_SIZE: Int = 0x3026;
+#[cfg(feature = "1_0")]
+pub const SUCCESS: Int = 0x3000;
+#[cfg(feature = "1_0")]
+pub const SURFACE_TYPE: Int = 0x3033;
+#[cfg(feature = "1_0")]
+pub const TRANSPARENT_BLUE_VALUE: Int = 0x3035;
+#[cfg(feature = "1_0")]
+pub const TRANSPARENT_GREEN_VALUE: Int = 0x3036;
+#[cfg(feature = "1_0")]
+pub const TRANSPARENT_RED_VALUE: Int = 0x3037;
+#[cfg(feature = "1_0")]
+pub const TRANSPARENT_RGB: Int = 0x3052;
+#[cfg(feature = "1_0")]
+pub const TRANSPARENT_TYPE: Int = 0x3034;
+#[cfg(feature = "1_0")]
+pub const TRUE: Boolean = 1;
+#[cfg(feature = "1_0")]
+pub const VENDOR: Int = 0x3053;
+#[cfg(feature = "1_0")]
+pub const VERSION: Int = 0x3054;
+#[cfg(feature = "1_0")]
+pub const WIDTH: Int = 0x3057;
+#[cfg(feature = "1_0")]
+pub const WINDOW_BIT: Int = 0x0004;
+
+// EGL_VERSION_1_1
+#[cfg(feature = "1_1")]
+pub const BACK_BUFFER: Int = 0x3084;
+#[cfg(feature = "1_1")]
+pub const BIND_TO_TEXTURE_RGB: Int = 0x3039;
+#[cfg(feature = "1_1")]
+pub const BIND_TO_TEXTURE_RGBA: Int = 0x303A;
+#[cfg(feature = "1_1")]
+pub const CONTEXT_LOST: Int = 0x300E;
+#[cfg(feature = "1_1")]
+pub const MIN_SWAP_INTERVAL: Int = 0x303B;
+#[cfg(feature = "1_1")]
+pub const MAX_SWAP_INTERVAL: Int = 0x303C;
+#[cfg(feature = "1_1")]
+pub const MIPMAP_TEXTURE: Int = 0x3082;
+#[cfg(feature = "1_1")]
+pub const MIPMAP_LEVEL: Int = 0x3083;
+#[cfg(feature = "1_1")]
+pub const NO_TEXTURE: Int = 0x305C;
+#[cfg(feature = "1_1")]
+pub const TEXTURE_2D: Int = 0x305F;
+#[cfg(feature = "1_1")]
+pub const TEXTURE_FORMAT: Int = 0x3080;
+#[cfg(feature = "1_1")]
+pub const TEXTURE_RGB: Int = 0x305D;
+#[cfg(feature = "1_1")]
+pub const TEXTURE_RGBA: Int = 0x305E;
+#[cfg(feature = "1_1")]
+pub const TEXTURE_TARGET: Int = 0x3081;
+
+// EGL_VERSION_1_2
+#[cfg(feature = "1_2")]
+pub const ALPHA_FORMAT: Int = 0x3088;
+#[cfg(feature = "1_2")]
+pub const ALPHA_FORMAT_NONPRE: Int = 0x308B;
+#[cfg(feature = "1_2")]
+pub const ALPHA_FORMAT_PRE: Int = 0x308C;
+#[cfg(feature = "1_2")]
+pub const ALPHA_MASK_SIZE: Int = 0x303E;
+#[cfg(feature = "1_2")]
+pub const BUFFER_PRESERVED: Int = 0x3094;
+#[cfg(feature = "1_2")]
+pub const BUFFER_DESTROYED: Int = 0x3095;
+#[cfg(feature = "1_2")]
+pub const CLIENT_APIS: Int = 0x308D;
+#[cfg(feature = "1_2")]
+pub const COLORSPACE: Int = 0x3087;
+#[cfg(feature = "1_2")]
+pub const COLORSPACE_sRGB: Int = 0x3089;
+#[cfg(feature = "1_2")]
+pub const COLORSPACE_LINEAR: Int = 0x308A;
+#[cfg(feature = "1_2")]
+pub const COLOR_BUFFER_TYPE: Int = 0x303F;
+#[cfg(feature = "1_2")]
+pub const CONTEXT_CLIENT_TYPE: Int = 0x3097;
+#[cfg(feature = "1_2")]
+pub const DISPLAY_SCALING: Int = 10000;
+#[cfg(feature = "1_2")]
+pub const HORIZONTAL_RESOLUTION: Int = 0x3090;
+#[cfg(feature = "1_2")]
+pub const LUMINANCE_BUFFER: Int = 0x308F;
+#[cfg(feature = "1_2")]
+pub const LUMINANCE_SIZE: Int = 0x303D;
+#[cfg(feature = "1_2")]
+pub const OPENGL_ES_BIT: Int = 0x0001;
+#[cfg(feature = "1_2")]
+pub const OPENVG_BIT: Int = 0x0002;
+#[cfg(feature = "1_2")]
+pub const OPENGL_ES_API: Enum = 0x30A0;
+#[cfg(feature = "1_2")]
+pub const OPENVG_API: Enum = 0x30A1;
+#[cfg(feature = "1_2")]
+pub const OPENVG_IMAGE: Int = 0x3096;
+#[cfg(feature = "1_2")]
+pub const PIXEL_ASPECT_RATIO: Int = 0x3092;
+#[cfg(feature = "1_2")]
+pub const RENDERABLE_TYPE: Int = 0x3040;
+#[cfg(feature = "1_2")]
+pub const RENDER_BUFFER: Int = 0x3086;
+#[cfg(feature = "1_2")]
+pub const RGB_BUFFER: Int = 0x308E;
+#[cfg(feature = "1_2")]
+pub const SINGLE_BUFFER: Int = 0x3085;
+#[cfg(feature = "1_2")]
+pub const SWAP_BEHAVIOR: Int = 0x3093;
+#[cfg(feature = "1_2")]
+pub const UNKNOWN: Int = -1;
+#[cfg(feature = "1_2")]
+pub const VERTICAL_RESOLUTION: Int = 0x3091;
+
+// EGL_VERSION_1_3
+#[cfg(feature = "1_3")]
+pub const CONFORMANT: Int = 0x3042;
+#[cfg(feature = "1_3")]
+pub const CONTEXT_CLIENT_VERSION: Int = 0x3098;
+#[cfg(feature = "1_3")]
+pub const MATCH_NATIVE_PIXMAP: Int = 0x3041;
+#[cfg(feature = "1_3")]
+pub const OPENGL_ES2_BIT: Int = 0x0004;
+#[cfg(feature = "1_3")]
+pub const VG_ALPHA_FORMAT: Int = 0x3088;
+#[cfg(feature = "1_3")]
+pub const VG_ALPHA_FORMAT_NONPRE: Int = 0x308B;
+#[cfg(feature = "1_3")]
+pub const VG_ALPHA_FORMAT_PRE: Int = 0x308C;
+#[cfg(feature = "1_3")]
+pub const VG_ALPHA_FORMAT_PRE_BIT: Int = 0x0040;
+#[cfg(feature = "1_3")]
+pub const VG_COLORSPACE: Int = 0x3087;
+#[cfg(feature = "1_3")]
+pub const VG_COLORSPACE_sRGB: Int = 0x3089;
+#[cfg(feature = "1_3")]
+pub const VG_COLORSPACE_LINEAR: Int = 0x308A;
+#[cfg(feature = "1_3")]
+pub const VG_COLORSPACE_LINEAR_BIT: Int = 0x0020;
+
+// EGL_VERSION_1_4
+#[cfg(feature = "1_4")]
+pub const DEFAULT_DISPLAY: NativeDisplayType = ptr::null_mut();
+#[cfg(feature = "1_4")]
+pub const MULTISAMPLE_RESOLVE_BOX_BIT: Int = 0x0200;
+#[cfg(feature = "1_4")]
+pub const MULTISAMPLE_RESOLVE: Int = 0x3099;
+#[cfg(feature = "1_4")]
+pub const MULTISAMPLE_RESOLVE_DEFAULT: Int = 0x309A;
+#[cfg(feature = "1_4")]
+pub const MULTISAMPLE_RESOLVE_BOX: Int = 0x309B;
+#[cfg(feature = "1_4")]
+pub const OPENGL_API: Enum = 0x30A2;
+#[cfg(feature = "1_4")]
+pub const OPENGL_BIT: Int = 0x0008;
+#[cfg(feature = "1_4")]
+pub const SWAP_BEHAVIOR_PRESERVED_BIT: Int = 0x0400;
+
+// EGL_VERSION_1_5
+#[cfg(feature = "1_5")]
+pub const CONTEXT_MAJOR_VERSION: Int = 0x3098;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_MINOR_VERSION: Int = 0x30FB;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_PROFILE_MASK: Int = 0x30FD;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: Int = 0x31BD;
+#[cfg(feature = "1_5")]
+pub const NO_RESET_NOTIFICATION: Int = 0x31BE;
+#[cfg(feature = "1_5")]
+pub const LOSE_CONTEXT_ON_RESET: Int = 0x31BF;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_CORE_PROFILE_BIT: Int = 0x00000001;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: Int = 0x00000002;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_DEBUG: Int = 0x31B0;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_FORWARD_COMPATIBLE: Int = 0x31B1;
+#[cfg(feature = "1_5")]
+pub const CONTEXT_OPENGL_ROBUST_ACCESS: Int = 0x31B2;
+#[cfg(feature = "1_5")]
+pub const OPENGL_ES3_BIT: Int = 0x00000040;
+#[cfg(feature = "1_5")]
+pub const CL_EVENT_HANDLE: Int = 0x309C;
+#[cfg(feature = "1_5")]
+pub const SYNC_CL_EVENT: Int = 0x30FE;
+#[cfg(feature = "1_5")]
+pub const SYNC_CL_EVENT_COMPLETE: Int = 0x30FF;
+#[cfg(feature = "1_5")]
+pub const SYNC_PRIOR_COMMANDS_COMPLETE: Int = 0x30F0;
+#[cfg(feature = "1_5")]
+pub const SYNC_TYPE: Int = 0x30F7;
+#[cfg(feature = "1_5")]
+pub const SYNC_STATUS: Int = 0x30F1;
+#[cfg(feature = "1_5")]
+pub const SYNC_CONDITION: Int = 0x30F8;
+#[cfg(feature = "1_5")]
+pub const SIGNALED: Int = 0x30F2;
+#[cfg(feature = "1_5")]
+pub const UNSIGNALED: Int = 0x30F3;
+#[cfg(feature = "1_5")]
+pub const SYNC_FLUSH_COMMANDS_BIT: Int = 0x0001;
+#[cfg(feature = "1_5")]
+pub const FOREVER: u64 = 0xFFFFFFFFFFFFFFFF;
+#[cfg(feature = "1_5")]
+pub const TIMEOUT_EXPIRED: Int = 0x30F5;
+#[cfg(feature = "1_5")]
+pub const CONDITION_SATISFIED: Int = 0x30F6;
+#[cfg(feature = "1_5")]
+pub const NO_SYNC: EGLSync = ptr::null_mut();
+#[cfg(feature = "1_5")]
+pub const SYNC_FENCE: Int = 0x30F9;
+#[cfg(feature = "1_5")]
+pub const GL_COLORSPACE: Int = 0x309D;
+#[cfg(feature = "1_5")]
+pub const GL_COLORSPACE_SRGB: Int = 0x3089;
+#[cfg(feature = "1_5")]
+pub const GL_COLORSPACE_LINEAR: Int = 0x308A;
+#[cfg(feature = "1_5")]
+pub const GL_RENDERBUFFER: Int = 0x30B9;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_2D: Int = 0x30B1;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_LEVEL: Int = 0x30BC;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_3D: Int = 0x30B2;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_ZOFFSET: Int = 0x30BD;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_X: Int = 0x30B3;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_X: Int = 0x30B4;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Y: Int = 0x30B5;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: Int = 0x30B6;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Z: Int = 0x30B7;
+#[cfg(feature = "1_5")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: Int = 0x30B8;
+#[cfg(feature = "1_5")]
+pub const IMAGE_PRESERVED: Int = 0x30D2;
+#[cfg(feature = "1_5")]
+pub const NO_IMAGE: EGLImage = ptr::null_mut();
+
+// EGL_ANDROID_create_native_client_buffer
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_USAGE_ANDROID: Int = 0x3143;
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_USAGE_PROTECTED_BIT_ANDROID: Int = 0x00000001;
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_USAGE_RENDERBUFFER_BIT_ANDROID: Int = 0x00000002;
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_USAGE_TEXTURE_BIT_ANDROID: Int = 0x00000004;
+
+// EGL_ANDROID_framebuffer_target
+#[cfg(feature = "1_0")]
+pub const FRAMEBUFFER_TARGET_ANDROID: Int = 0x3147;
+
+// EGL_ANDROID_front_buffer_auto_refresh
+#[cfg(feature = "1_0")]
+pub const FRONT_BUFFER_AUTO_REFRESH_ANDROID: Int = 0x314C;
+
+// EGL_ANDROID_image_native_buffer
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_ANDROID: Int = 0x3140;
+
+// EGL_ANDROID_native_fence_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_NATIVE_FENCE_ANDROID: Int = 0x3144;
+#[cfg(feature = "1_0")]
+pub const SYNC_NATIVE_FENCE_FD_ANDROID: Int = 0x3145;
+#[cfg(feature = "1_0")]
+pub const SYNC_NATIVE_FENCE_SIGNALED_ANDROID: Int = 0x3146;
+#[cfg(feature = "1_0")]
+pub const NO_NATIVE_FENCE_FD_ANDROID: Int = -1;
+
+// EGL_ANDROID_get_frame_timestamps
+#[cfg(feature = "1_0")]
+pub const TIMESTAMP_PENDING_ANDROID: i64 = -2;
+#[cfg(feature = "1_0")]
+pub const TIMESTAMP_INVALID_ANDROID: i64 = -1;
+#[cfg(feature = "1_0")]
+pub const TIMESTAMPS_ANDROID: Int = 0x3430;
+#[cfg(feature = "1_0")]
+pub const COMPOSITE_DEADLINE_ANDROID: Int = 0x3431;
+#[cfg(feature = "1_0")]
+pub const COMPOSITE_INTERVAL_ANDROID: Int = 0x3432;
+#[cfg(feature = "1_0")]
+pub const COMPOSITE_TO_PRESENT_LATENCY_ANDROID: Int = 0x3433;
+#[cfg(feature = "1_0")]
+pub const REQUESTED_PRESENT_TIME_ANDROID: Int = 0x3434;
+#[cfg(feature = "1_0")]
+pub const RENDERING_COMPLETE_TIME_ANDROID: Int = 0x3435;
+#[cfg(feature = "1_0")]
+pub const COMPOSITION_LATCH_TIME_ANDROID: Int = 0x3436;
+#[cfg(feature = "1_0")]
+pub const FIRST_COMPOSITION_START_TIME_ANDROID: Int = 0x3437;
+#[cfg(feature = "1_0")]
+pub const LAST_COMPOSITION_START_TIME_ANDROID: Int = 0x3438;
+#[cfg(feature = "1_0")]
+pub const FIRST_COMPOSITION_GPU_FINISHED_TIME_ANDROID: Int = 0x3439;
+#[cfg(feature = "1_0")]
+pub const DISPLAY_PRESENT_TIME_ANDROID: Int = 0x343A;
+#[cfg(feature = "1_0")]
+pub const DEQUEUE_READY_TIME_ANDROID: Int = 0x343B;
+#[cfg(feature = "1_0")]
+pub const READS_DONE_TIME_ANDROID: Int = 0x343C;
+
+// EGL_ANDROID_recordable
+#[cfg(feature = "1_0")]
+pub const RECORDABLE_ANDROID: Int = 0x3142;
+
+// EGL_ANGLE_d3d_share_handle_client_buffer
+#[cfg(feature = "1_0")]
+pub const D3D_TEXTURE_2D_SHARE_HANDLE_ANGLE: Int = 0x3200;
+
+// EGL_ANGLE_device_d3d
+#[cfg(feature = "1_0")]
+pub const D3D9_DEVICE_ANGLE: Int = 0x33A0;
+#[cfg(feature = "1_0")]
+pub const D3D11_DEVICE_ANGLE: Int = 0x33A1;
+
+// EGL_ANGLE_window_fixed_size
+#[cfg(feature = "1_0")]
+pub const FIXED_SIZE_ANGLE: Int = 0x3201;
+
+// EGL_ARM_implicit_external_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_PRIOR_COMMANDS_IMPLICIT_EXTERNAL_ARM: Int = 0x328A;
+
+// EGL_ARM_pixmap_multisample_discard
+#[cfg(feature = "1_0")]
+pub const DISCARD_SAMPLES_ARM: Int = 0x3286;
+
+// EGL_EXT_buffer_age
+#[cfg(feature = "1_0")]
+pub const BUFFER_AGE_EXT: Int = 0x313D;
+
+// EGL_EXT_client_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_CLIENT_EXT: Int = 0x3364;
+#[cfg(feature = "1_0")]
+pub const SYNC_CLIENT_SIGNAL_EXT: Int = 0x3365;
+
+// EGL_EXT_create_context_robustness
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_ROBUST_ACCESS_EXT: Int = 0x30BF;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_EXT: Int = 0x3138;
+#[cfg(feature = "1_0")]
+pub const NO_RESET_NOTIFICATION_EXT: Int = 0x31BE;
+#[cfg(feature = "1_0")]
+pub const LOSE_CONTEXT_ON_RESET_EXT: Int = 0x31BF;
+
+// EGL_EXT_device_base
+#[cfg(feature = "1_0")]
+pub const NO_DEVICE_EXT: EGLDeviceEXT = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const BAD_DEVICE_EXT: Int = 0x322B;
+#[cfg(feature = "1_0")]
+pub const DEVICE_EXT: Int = 0x322C;
+
+// EGL_EXT_device_drm
+#[cfg(feature = "1_0")]
+pub const DRM_DEVICE_FILE_EXT: Int = 0x3233;
+#[cfg(feature = "1_0")]
+pub const DRM_MASTER_FD_EXT: Int = 0x333C;
+
+// EGL_EXT_device_openwf
+#[cfg(feature = "1_0")]
+pub const OPENWF_DEVICE_ID_EXT: Int = 0x3237;
+
+// EGL_EXT_gl_colorspace_bt2020_linear
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_BT2020_LINEAR_EXT: Int = 0x333F;
+
+// EGL_EXT_gl_colorspace_bt2020_pq
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_BT2020_PQ_EXT: Int = 0x3340;
+
+// EGL_EXT_gl_colorspace_scrgb
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_SCRGB_EXT: Int = 0x3351;
+
+// EGL_EXT_gl_colorspace_scrgb_linear
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_SCRGB_LINEAR_EXT: Int = 0x3350;
+
+// EGL_EXT_gl_colorspace_display_p3_linear
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_DISPLAY_P3_LINEAR_EXT: Int = 0x3362;
+
+// EGL_EXT_gl_colorspace_display_p3
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_DISPLAY_P3_EXT: Int = 0x3363;
+
+// EGL_EXT_image_dma_buf_import
+#[cfg(feature = "1_0")]
+pub const LINUX_DMA_BUF_EXT: Int = 0x3270;
+#[cfg(feature = "1_0")]
+pub const LINUX_DRM_FOURCC_EXT: Int = 0x3271;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE0_FD_EXT: Int = 0x3272;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE0_OFFSET_EXT: Int = 0x3273;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE0_PITCH_EXT: Int = 0x3274;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE1_FD_EXT: Int = 0x3275;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE1_OFFSET_EXT: Int = 0x3276;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE1_PITCH_EXT: Int = 0x3277;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE2_FD_EXT: Int = 0x3278;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE2_OFFSET_EXT: Int = 0x3279;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE2_PITCH_EXT: Int = 0x327A;
+#[cfg(feature = "1_0")]
+pub const YUV_COLOR_SPACE_HINT_EXT: Int = 0x327B;
+#[cfg(feature = "1_0")]
+pub const SAMPLE_RANGE_HINT_EXT: Int = 0x327C;
+#[cfg(feature = "1_0")]
+pub const YUV_CHROMA_HORIZONTAL_SITING_HINT_EXT: Int = 0x327D;
+#[cfg(feature = "1_0")]
+pub const YUV_CHROMA_VERTICAL_SITING_HINT_EXT: Int = 0x327E;
+#[cfg(feature = "1_0")]
+pub const ITU_REC601_EXT: Int = 0x327F;
+#[cfg(feature = "1_0")]
+pub const ITU_REC709_EXT: Int = 0x3280;
+#[cfg(feature = "1_0")]
+pub const ITU_REC2020_EXT: Int = 0x3281;
+#[cfg(feature = "1_0")]
+pub const YUV_FULL_RANGE_EXT: Int = 0x3282;
+#[cfg(feature = "1_0")]
+pub const YUV_NARROW_RANGE_EXT: Int = 0x3283;
+#[cfg(feature = "1_0")]
+pub const YUV_CHROMA_SITING_0_EXT: Int = 0x3284;
+#[cfg(feature = "1_0")]
+pub const YUV_CHROMA_SITING_0_5_EXT: Int = 0x3285;
+
+// EGL_EXT_image_dma_buf_import_modifiers
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE3_FD_EXT: Int = 0x3440;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE3_OFFSET_EXT: Int = 0x3441;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE3_PITCH_EXT: Int = 0x3442;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE0_MODIFIER_LO_EXT: Int = 0x3443;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE0_MODIFIER_HI_EXT: Int = 0x3444;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE1_MODIFIER_LO_EXT: Int = 0x3445;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE1_MODIFIER_HI_EXT: Int = 0x3446;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE2_MODIFIER_LO_EXT: Int = 0x3447;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE2_MODIFIER_HI_EXT: Int = 0x3448;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE3_MODIFIER_LO_EXT: Int = 0x3449;
+#[cfg(feature = "1_0")]
+pub const DMA_BUF_PLANE3_MODIFIER_HI_EXT: Int = 0x344A;
+
+// EGL_EXT_image_gl_colorspace
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_DEFAULT_EXT: Int = 0x314D;
+
+// EGL_EXT_multiview_window
+#[cfg(feature = "1_0")]
+pub const MULTIVIEW_VIEW_COUNT_EXT: Int = 0x3134;
+
+// EGL_EXT_output_base
+#[cfg(feature = "1_0")]
+pub const NO_OUTPUT_LAYER_EXT: *mut c_void = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const NO_OUTPUT_PORT_EXT: *mut c_void = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const BAD_OUTPUT_LAYER_EXT: Int = 0x322D;
+#[cfg(feature = "1_0")]
+pub const BAD_OUTPUT_PORT_EXT: Int = 0x322E;
+#[cfg(feature = "1_0")]
+pub const SWAP_INTERVAL_EXT: Int = 0x322F;
+
+// EGL_EXT_output_drm
+#[cfg(feature = "1_0")]
+pub const DRM_CRTC_EXT: Int = 0x3234;
+#[cfg(feature = "1_0")]
+pub const DRM_PLANE_EXT: Int = 0x3235;
+#[cfg(feature = "1_0")]
+pub const DRM_CONNECTOR_EXT: Int = 0x3236;
+
+// EGL_EXT_output_openwf
+#[cfg(feature = "1_0")]
+pub const OPENWF_PIPELINE_ID_EXT: Int = 0x3238;
+#[cfg(feature = "1_0")]
+pub const OPENWF_PORT_ID_EXT: Int = 0x3239;
+
+// EGL_EXT_pixel_format_float
+#[cfg(feature = "1_0")]
+pub const COLOR_COMPONENT_TYPE_EXT: Int = 0x3339;
+#[cfg(feature = "1_0")]
+pub const COLOR_COMPONENT_TYPE_FIXED_EXT: Int = 0x333A;
+#[cfg(feature = "1_0")]
+pub const COLOR_COMPONENT_TYPE_FLOAT_EXT: Int = 0x333B;
+
+// EGL_EXT_platform_device
+#[cfg(feature = "1_2")]
+pub const PLATFORM_DEVICE_EXT: Enum = 0x313F;
+
+// EGL_EXT_platform_wayland
+#[cfg(feature = "1_2")]
+pub const PLATFORM_WAYLAND_EXT: Enum = 0x31D8;
+
+// EGL_EXT_platform_x11
+#[cfg(feature = "1_2")]
+pub const PLATFORM_X11_EXT: Enum = 0x31D5;
+#[cfg(feature = "1_0")]
+pub const PLATFORM_X11_SCREEN_EXT: Int = 0x31D6;
+
+// EGL_EXT_protected_content
+#[cfg(feature = "1_0")]
+pub const PROTECTED_CONTENT_EXT: Int = 0x32C0;
+
+// EGL_EXT_surface_SMPTE2086_metadata
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_RX_EXT: Int = 0x3341;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_RY_EXT: Int = 0x3342;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_GX_EXT: Int = 0x3343;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_GY_EXT: Int = 0x3344;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_BX_EXT: Int = 0x3345;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_DISPLAY_PRIMARY_BY_EXT: Int = 0x3346;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_WHITE_POINT_X_EXT: Int = 0x3347;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_WHITE_POINT_Y_EXT: Int = 0x3348;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_MAX_LUMINANCE_EXT: Int = 0x3349;
+#[cfg(feature = "1_0")]
+pub const SMPTE2086_MIN_LUMINANCE_EXT: Int = 0x334A;
+#[cfg(feature = "1_0")]
+pub const METADATA_SCALING_EXT: Int = 50000;
+
+// EGL_EXT_yuv_surface
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_EXT: Int = 0x3301;
+#[cfg(feature = "1_0")]
+pub const YUV_NUMBER_OF_PLANES_EXT: Int = 0x3311;
+#[cfg(feature = "1_0")]
+pub const YUV_SUBSAMPLE_EXT: Int = 0x3312;
+#[cfg(feature = "1_0")]
+pub const YUV_DEPTH_RANGE_EXT: Int = 0x3317;
+#[cfg(feature = "1_0")]
+pub const YUV_CSC_STANDARD_EXT: Int = 0x330A;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE_BPP_EXT: Int = 0x331A;
+#[cfg(feature = "1_0")]
+pub const YUV_BUFFER_EXT: Int = 0x3300;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_YUV_EXT: Int = 0x3302;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_YVU_EXT: Int = 0x3303;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_YUYV_EXT: Int = 0x3304;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_UYVY_EXT: Int = 0x3305;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_YVYU_EXT: Int = 0x3306;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_VYUY_EXT: Int = 0x3307;
+#[cfg(feature = "1_0")]
+pub const YUV_ORDER_AYUV_EXT: Int = 0x3308;
+#[cfg(feature = "1_0")]
+pub const YUV_SUBSAMPLE_4_2_0_EXT: Int = 0x3313;
+#[cfg(feature = "1_0")]
+pub const YUV_SUBSAMPLE_4_2_2_EXT: Int = 0x3314;
+#[cfg(feature = "1_0")]
+pub const YUV_SUBSAMPLE_4_4_4_EXT: Int = 0x3315;
+#[cfg(feature = "1_0")]
+pub const YUV_DEPTH_RANGE_LIMITED_EXT: Int = 0x3318;
+#[cfg(feature = "1_0")]
+pub const YUV_DEPTH_RANGE_FULL_EXT: Int = 0x3319;
+#[cfg(feature = "1_0")]
+pub const YUV_CSC_STANDARD_601_EXT: Int = 0x330B;
+#[cfg(feature = "1_0")]
+pub const YUV_CSC_STANDARD_709_EXT: Int = 0x330C;
+#[cfg(feature = "1_0")]
+pub const YUV_CSC_STANDARD_2020_EXT: Int = 0x330D;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE_BPP_0_EXT: Int = 0x331B;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE_BPP_8_EXT: Int = 0x331C;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE_BPP_10_EXT: Int = 0x331D;
+
+// EGL_HI_clientpixmap
+#[cfg(feature = "1_0")]
+pub const CLIENT_PIXMAP_POINTER_HI: Int = 0x8F74;
+
+// EGL_HI_colorformats
+#[cfg(feature = "1_0")]
+pub const COLOR_FORMAT_HI: Int = 0x8F70;
+#[cfg(feature = "1_0")]
+pub const COLOR_RGB_HI: Int = 0x8F71;
+#[cfg(feature = "1_0")]
+pub const COLOR_RGBA_HI: Int = 0x8F72;
+#[cfg(feature = "1_0")]
+pub const COLOR_ARGB_HI: Int = 0x8F73;
+
+// EGL_IMG_context_priority
+#[cfg(feature = "1_0")]
+pub const CONTEXT_PRIORITY_LEVEL_IMG: Int = 0x3100;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_PRIORITY_HIGH_IMG: Int = 0x3101;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_PRIORITY_MEDIUM_IMG: Int = 0x3102;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_PRIORITY_LOW_IMG: Int = 0x3103;
+
+// EGL_IMG_image_plane_attribs
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_MULTIPLANE_SEPARATE_IMG: Int = 0x3105;
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_PLANE_OFFSET_IMG: Int = 0x3106;
+
+// EGL_KHR_cl_event
+#[cfg(feature = "1_0")]
+pub const CL_EVENT_HANDLE_KHR: Int = 0x309C;
+#[cfg(feature = "1_0")]
+pub const SYNC_CL_EVENT_KHR: Int = 0x30FE;
+#[cfg(feature = "1_0")]
+pub const SYNC_CL_EVENT_COMPLETE_KHR: Int = 0x30FF;
+
+// EGL_KHR_config_attribs
+#[cfg(feature = "1_0")]
+pub const CONFORMANT_KHR: Int = 0x3042;
+#[cfg(feature = "1_0")]
+pub const VG_COLORSPACE_LINEAR_BIT_KHR: Int = 0x0020;
+#[cfg(feature = "1_0")]
+pub const VG_ALPHA_FORMAT_PRE_BIT_KHR: Int = 0x0040;
+
+// EGL_KHR_context_flush_control
+#[cfg(feature = "1_0")]
+pub const CONTEXT_RELEASE_BEHAVIOR_NONE_KHR: Int = 0;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_RELEASE_BEHAVIOR_KHR: Int = 0x2097;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_RELEASE_BEHAVIOR_FLUSH_KHR: Int = 0x2098;
+
+// EGL_KHR_create_context
+#[cfg(feature = "1_0")]
+pub const CONTEXT_MAJOR_VERSION_KHR: Int = 0x3098;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_MINOR_VERSION_KHR: Int = 0x30FB;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_FLAGS_KHR: Int = 0x30FC;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_PROFILE_MASK_KHR: Int = 0x30FD;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY_KHR: Int = 0x31BD;
+#[cfg(feature = "1_0")]
+pub const NO_RESET_NOTIFICATION_KHR: Int = 0x31BE;
+#[cfg(feature = "1_0")]
+pub const LOSE_CONTEXT_ON_RESET_KHR: Int = 0x31BF;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_DEBUG_BIT_KHR: Int = 0x00000001;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_FORWARD_COMPATIBLE_BIT_KHR: Int = 0x00000002;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_ROBUST_ACCESS_BIT_KHR: Int = 0x00000004;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_CORE_PROFILE_BIT_KHR: Int = 0x00000001;
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT_KHR: Int = 0x00000002;
+#[cfg(feature = "1_0")]
+pub const OPENGL_ES3_BIT_KHR: Int = 0x00000040;
+
+// EGL_KHR_create_context_no_error
+#[cfg(feature = "1_0")]
+pub const CONTEXT_OPENGL_NO_ERROR_KHR: Int = 0x31B3;
+
+// EGL_KHR_debug
+#[cfg(feature = "1_0")]
+pub const OBJECT_THREAD_KHR: Int = 0x33B0;
+#[cfg(feature = "1_0")]
+pub const OBJECT_DISPLAY_KHR: Int = 0x33B1;
+#[cfg(feature = "1_0")]
+pub const OBJECT_CONTEXT_KHR: Int = 0x33B2;
+#[cfg(feature = "1_0")]
+pub const OBJECT_SURFACE_KHR: Int = 0x33B3;
+#[cfg(feature = "1_0")]
+pub const OBJECT_IMAGE_KHR: Int = 0x33B4;
+#[cfg(feature = "1_0")]
+pub const OBJECT_SYNC_KHR: Int = 0x33B5;
+#[cfg(feature = "1_0")]
+pub const OBJECT_STREAM_KHR: Int = 0x33B6;
+#[cfg(feature = "1_0")]
+pub const DEBUG_MSG_CRITICAL_KHR: Int = 0x33B9;
+#[cfg(feature = "1_0")]
+pub const DEBUG_MSG_ERROR_KHR: Int = 0x33BA;
+#[cfg(feature = "1_0")]
+pub const DEBUG_MSG_WARN_KHR: Int = 0x33BB;
+#[cfg(feature = "1_0")]
+pub const DEBUG_MSG_INFO_KHR: Int = 0x33BC;
+#[cfg(feature = "1_0")]
+pub const DEBUG_CALLBACK_KHR: Int = 0x33B8;
+
+// EGL_KHR_display_reference
+#[cfg(feature = "1_0")]
+pub const TRACK_REFERENCES_KHR: Int = 0x3352;
+
+// EGL_KHR_fence_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_PRIOR_COMMANDS_COMPLETE_KHR: Int = 0x30F0;
+#[cfg(feature = "1_0")]
+pub const SYNC_CONDITION_KHR: Int = 0x30F8;
+#[cfg(feature = "1_0")]
+pub const SYNC_FENCE_KHR: Int = 0x30F9;
+
+// EGL_KHR_gl_colorspace
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_KHR: Int = 0x309D;
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_SRGB_KHR: Int = 0x3089;
+#[cfg(feature = "1_0")]
+pub const GL_COLORSPACE_LINEAR_KHR: Int = 0x308A;
+
+// EGL_KHR_gl_renderbuffer_image
+#[cfg(feature = "1_0")]
+pub const GL_RENDERBUFFER_KHR: Int = 0x30B9;
+
+// EGL_KHR_gl_texture_2D_image
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_2D_KHR: Int = 0x30B1;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_LEVEL_KHR: Int = 0x30BC;
+
+// EGL_KHR_gl_texture_3D_image
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_3D_KHR: Int = 0x30B2;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_ZOFFSET_KHR: Int = 0x30BD;
+
+// EGL_KHR_gl_texture_cubemap_image
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_X_KHR: Int = 0x30B3;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_X_KHR: Int = 0x30B4;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Y_KHR: Int = 0x30B5;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Y_KHR: Int = 0x30B6;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Z_KHR: Int = 0x30B7;
+#[cfg(feature = "1_0")]
+pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Z_KHR: Int = 0x30B8;
+
+// EGL_KHR_image
+#[cfg(feature = "1_0")]
+pub const NATIVE_PIXMAP_KHR: Int = 0x30B0;
+#[cfg(feature = "1_2")]
+pub const NO_IMAGE_KHR: EGLImage = ptr::null_mut();
+
+// EGL_KHR_image_base
+#[cfg(feature = "1_0")]
+pub const IMAGE_PRESERVED_KHR: Int = 0x30D2;
+
+// EGL_KHR_lock_surface
+#[cfg(feature = "1_0")]
+pub const READ_SURFACE_BIT_KHR: Int = 0x0001;
+#[cfg(feature = "1_0")]
+pub const WRITE_SURFACE_BIT_KHR: Int = 0x0002;
+#[cfg(feature = "1_0")]
+pub const LOCK_SURFACE_BIT_KHR: Int = 0x0080;
+#[cfg(feature = "1_0")]
+pub const OPTIMAL_FORMAT_BIT_KHR: Int = 0x0100;
+#[cfg(feature = "1_0")]
+pub const MATCH_FORMAT_KHR: Int = 0x3043;
+#[cfg(feature = "1_0")]
+pub const FORMAT_RGB_565_EXACT_KHR: Int = 0x30C0;
+#[cfg(feature = "1_0")]
+pub const FORMAT_RGB_565_KHR: Int = 0x30C1;
+#[cfg(feature = "1_0")]
+pub const FORMAT_RGBA_8888_EXACT_KHR: Int = 0x30C2;
+#[cfg(feature = "1_0")]
+pub const FORMAT_RGBA_8888_KHR: Int = 0x30C3;
+#[cfg(feature = "1_0")]
+pub const MAP_PRESERVE_PIXELS_KHR: Int = 0x30C4;
+#[cfg(feature = "1_0")]
+pub const LOCK_USAGE_HINT_KHR: Int = 0x30C5;
+#[cfg(feature = "1_0")]
+pub const BITMAP_POINTER_KHR: Int = 0x30C6;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PITCH_KHR: Int = 0x30C7;
+#[cfg(feature = "1_0")]
+pub const BITMAP_ORIGIN_KHR: Int = 0x30C8;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_RED_OFFSET_KHR: Int = 0x30C9;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_GREEN_OFFSET_KHR: Int = 0x30CA;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_BLUE_OFFSET_KHR: Int = 0x30CB;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_ALPHA_OFFSET_KHR: Int = 0x30CC;
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_LUMINANCE_OFFSET_KHR: Int = 0x30CD;
+#[cfg(feature = "1_0")]
+pub const LOWER_LEFT_KHR: Int = 0x30CE;
+#[cfg(feature = "1_0")]
+pub const UPPER_LEFT_KHR: Int = 0x30CF;
+
+// EGL_KHR_lock_surface2
+#[cfg(feature = "1_0")]
+pub const BITMAP_PIXEL_SIZE_KHR: Int = 0x3110;
+
+// EGL_KHR_mutable_render_buffer
+#[cfg(feature = "1_0")]
+pub const MUTABLE_RENDER_BUFFER_BIT_KHR: Int = 0x1000;
+
+// EGL_KHR_no_config_context
+#[cfg(feature = "1_0")]
+pub const NO_CONFIG_KHR: EGLConfig = ptr::null_mut();
+
+// EGL_KHR_partial_update
+#[cfg(feature = "1_0")]
+pub const BUFFER_AGE_KHR: Int = 0x313D;
+
+// EGL_KHR_platform_android
+#[cfg(feature = "1_2")]
+pub const PLATFORM_ANDROID_KHR: Enum = 0x3141;
+
+// EGL_KHR_platform_gbm
+#[cfg(feature = "1_2")]
+pub const PLATFORM_GBM_KHR: Enum = 0x31D7;
+
+// EGL_KHR_platform_wayland
+#[cfg(feature = "1_2")]
+pub const PLATFORM_WAYLAND_KHR: Enum = 0x31D8;
+
+// EGL_KHR_platform_x11
+#[cfg(feature = "1_2")]
+pub const PLATFORM_X11_KHR: Enum = 0x31D5;
+#[cfg(feature = "1_0")]
+pub const PLATFORM_X11_SCREEN_KHR: Int = 0x31D6;
+
+// EGL_KHR_reusable_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_STATUS_KHR: Int = 0x30F1;
+#[cfg(feature = "1_0")]
+pub const SIGNALED_KHR: Int = 0x30F2;
+#[cfg(feature = "1_0")]
+pub const UNSIGNALED_KHR: Int = 0x30F3;
+#[cfg(feature = "1_0")]
+pub const TIMEOUT_EXPIRED_KHR: Int = 0x30F5;
+#[cfg(feature = "1_0")]
+pub const CONDITION_SATISFIED_KHR: Int = 0x30F6;
+#[cfg(feature = "1_0")]
+pub const SYNC_TYPE_KHR: Int = 0x30F7;
+#[cfg(feature = "1_0")]
+pub const SYNC_REUSABLE_KHR: Int = 0x30FA;
+#[cfg(feature = "1_0")]
+pub const SYNC_FLUSH_COMMANDS_BIT_KHR: Int = 0x0001;
+#[cfg(feature = "1_0")]
+pub const FOREVER_KHR: u64 = 0xFFFFFFFFFFFFFFFF;
+#[cfg(feature = "1_2")]
+pub const NO_SYNC_KHR: EGLSync = ptr::null_mut();
+
+// EGL_KHR_stream
+#[cfg(feature = "1_0")]
+pub const NO_STREAM_KHR: *mut c_void = ptr::null_mut();
+#[cfg(feature = "1_0")]
+pub const CONSUMER_LATENCY_USEC_KHR: Int = 0x3210;
+#[cfg(feature = "1_0")]
+pub const PRODUCER_FRAME_KHR: Int = 0x3212;
+#[cfg(feature = "1_0")]
+pub const CONSUMER_FRAME_KHR: Int = 0x3213;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_KHR: Int = 0x3214;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_CREATED_KHR: Int = 0x3215;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_CONNECTING_KHR: Int = 0x3216;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_EMPTY_KHR: Int = 0x3217;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_NEW_FRAME_AVAILABLE_KHR: Int = 0x3218;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_OLD_FRAME_AVAILABLE_KHR: Int = 0x3219;
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_DISCONNECTED_KHR: Int = 0x321A;
+#[cfg(feature = "1_0")]
+pub const BAD_STREAM_KHR: Int = 0x321B;
+#[cfg(feature = "1_0")]
+pub const BAD_STATE_KHR: Int = 0x321C;
+
+// EGL_KHR_stream_consumer_gltexture
+#[cfg(feature = "1_0")]
+pub const CONSUMER_ACQUIRE_TIMEOUT_USEC_KHR: Int = 0x321E;
+
+// EGL_KHR_stream_cross_process_fd
+#[cfg(feature = "1_0")]
+pub const NO_FILE_DESCRIPTOR_KHR: Int = -1;
+
+// EGL_KHR_stream_fifo
+#[cfg(feature = "1_0")]
+pub const STREAM_FIFO_LENGTH_KHR: Int = 0x31FC;
+#[cfg(feature = "1_0")]
+pub const STREAM_TIME_NOW_KHR: Int = 0x31FD;
+#[cfg(feature = "1_0")]
+pub const STREAM_TIME_CONSUMER_KHR: Int = 0x31FE;
+#[cfg(feature = "1_0")]
+pub const STREAM_TIME_PRODUCER_KHR: Int = 0x31FF;
+
+// EGL_KHR_stream_producer_eglsurface
+#[cfg(feature = "1_0")]
+pub const STREAM_BIT_KHR: Int = 0x0800;
+
+// EGL_KHR_vg_parent_image
+#[cfg(feature = "1_0")]
+pub const VG_PARENT_IMAGE_KHR: Int = 0x30BA;
+
+// EGL_MESA_drm_image
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_FORMAT_MESA: Int = 0x31D0;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_USE_MESA: Int = 0x31D1;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_FORMAT_ARGB32_MESA: Int = 0x31D2;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_MESA: Int = 0x31D3;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_STRIDE_MESA: Int = 0x31D4;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_USE_SCANOUT_MESA: Int = 0x00000001;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_USE_SHARE_MESA: Int = 0x00000002;
+#[cfg(feature = "1_0")]
+pub const DRM_BUFFER_USE_CURSOR_MESA: Int = 0x00000004;
+
+// EGL_MESA_platform_gbm
+#[cfg(feature = "1_2")]
+pub const PLATFORM_GBM_MESA: Enum = 0x31D7;
+
+// EGL_MESA_platform_surfaceless
+#[cfg(feature = "1_2")]
+pub const PLATFORM_SURFACELESS_MESA: Enum = 0x31DD;
+
+// EGL_NOK_texture_from_pixmap
+#[cfg(feature = "1_0")]
+pub const Y_INVERTED_NOK: Int = 0x307F;
+
+// EGL_NV_3dvision_surface
+#[cfg(feature = "1_0")]
+pub const AUTO_STEREO_NV: Int = 0x3136;
+
+// EGL_NV_coverage_sample
+#[cfg(feature = "1_0")]
+pub const COVERAGE_BUFFERS_NV: Int = 0x30E0;
+#[cfg(feature = "1_0")]
+pub const COVERAGE_SAMPLES_NV: Int = 0x30E1;
+
+// EGL_NV_context_priority_realtime
+#[cfg(feature = "1_0")]
+pub const CONTEXT_PRIORITY_REALTIME_NV: Int = 0x3357;
+
+// EGL_NV_coverage_sample_resolve
+#[cfg(feature = "1_0")]
+pub const COVERAGE_SAMPLE_RESOLVE_NV: Int = 0x3131;
+#[cfg(feature = "1_0")]
+pub const COVERAGE_SAMPLE_RESOLVE_DEFAULT_NV: Int = 0x3132;
+#[cfg(feature = "1_0")]
+pub const COVERAGE_SAMPLE_RESOLVE_NONE_NV: Int = 0x3133;
+
+// EGL_NV_cuda_event
+#[cfg(feature = "1_0")]
+pub const CUDA_EVENT_HANDLE_NV: Int = 0x323B;
+#[cfg(feature = "1_0")]
+pub const SYNC_CUDA_EVENT_NV: Int = 0x323C;
+#[cfg(feature = "1_0")]
+pub const SYNC_CUDA_EVENT_COMPLETE_NV: Int = 0x323D;
+
+// EGL_NV_depth_nonlinear
+#[cfg(feature = "1_0")]
+pub const DEPTH_ENCODING_NV: Int = 0x30E2;
+#[cfg(feature = "1_0")]
+pub const DEPTH_ENCODING_NONE_NV: Int = 0;
+#[cfg(feature = "1_0")]
+pub const DEPTH_ENCODING_NONLINEAR_NV: Int = 0x30E3;
+
+// EGL_NV_device_cuda
+#[cfg(feature = "1_0")]
+pub const CUDA_DEVICE_NV: Int = 0x323A;
+
+// EGL_NV_post_sub_buffer
+#[cfg(feature = "1_0")]
+pub const POST_SUB_BUFFER_SUPPORTED_NV: Int = 0x30BE;
+
+// EGL_NV_robustness_video_memory_purge
+#[cfg(feature = "1_0")]
+pub const GENERATE_RESET_ON_VIDEO_MEMORY_PURGE_NV: Int = 0x334C;
+
+// EGL_NV_stream_consumer_gltexture_yuv
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE0_TEXTURE_UNIT_NV: Int = 0x332C;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE1_TEXTURE_UNIT_NV: Int = 0x332D;
+#[cfg(feature = "1_0")]
+pub const YUV_PLANE2_TEXTURE_UNIT_NV: Int = 0x332E;
+
+// EGL_NV_stream_cross_object
+#[cfg(feature = "1_0")]
+pub const STREAM_CROSS_OBJECT_NV: Int = 0x334D;
+
+// EGL_NV_stream_cross_display
+#[cfg(feature = "1_0")]
+pub const STREAM_CROSS_DISPLAY_NV: Int = 0x334E;
+
+// EGL_NV_stream_cross_partition
+#[cfg(feature = "1_0")]
+pub const STREAM_CROSS_PARTITION_NV: Int = 0x323F;
+
+// EGL_NV_stream_cross_process
+#[cfg(feature = "1_0")]
+pub const STREAM_CROSS_PROCESS_NV: Int = 0x3245;
+
+// EGL_NV_stream_cross_system
+#[cfg(feature = "1_0")]
+pub const STREAM_CROSS_SYSTEM_NV: Int = 0x334F;
+
+// EGL_NV_stream_fifo_next
+#[cfg(feature = "1_0")]
+pub const PENDING_FRAME_NV: Int = 0x3329;
+#[cfg(feature = "1_0")]
+pub const STREAM_TIME_PENDING_NV: Int = 0x332A;
+
+// EGL_NV_stream_fifo_synchronous
+#[cfg(feature = "1_0")]
+pub const STREAM_FIFO_SYNCHRONOUS_NV: Int = 0x3336;
+
+// EGL_NV_stream_frame_limits
+#[cfg(feature = "1_0")]
+pub const PRODUCER_MAX_FRAME_HINT_NV: Int = 0x3337;
+#[cfg(feature = "1_0")]
+pub const CONSUMER_MAX_FRAME_HINT_NV: Int = 0x3338;
+
+// EGL_NV_stream_metadata
+#[cfg(feature = "1_0")]
+pub const MAX_STREAM_METADATA_BLOCKS_NV: Int = 0x3250;
+#[cfg(feature = "1_0")]
+pub const MAX_STREAM_METADATA_BLOCK_SIZE_NV: Int = 0x3251;
+#[cfg(feature = "1_0")]
+pub const MAX_STREAM_METADATA_TOTAL_SIZE_NV: Int = 0x3252;
+#[cfg(feature = "1_0")]
+pub const PRODUCER_METADATA_NV: Int = 0x3253;
+#[cfg(feature = "1_0")]
+pub const CONSUMER_METADATA_NV: Int = 0x3254;
+#[cfg(feature = "1_0")]
+pub const PENDING_METADATA_NV: Int = 0x3328;
+#[cfg(feature = "1_0")]
+pub const METADATA0_SIZE_NV: Int = 0x3255;
+#[cfg(feature = "1_0")]
+pub const METADATA1_SIZE_NV: Int = 0x3256;
+#[cfg(feature = "1_0")]
+pub const METADATA2_SIZE_NV: Int = 0x3257;
+#[cfg(feature = "1_0")]
+pub const METADATA3_SIZE_NV: Int = 0x3258;
+#[cfg(feature = "1_0")]
+pub const METADATA0_TYPE_NV: Int = 0x3259;
+#[cfg(feature = "1_0")]
+pub const METADATA1_TYPE_NV: Int = 0x325A;
+#[cfg(feature = "1_0")]
+pub const METADATA2_TYPE_NV: Int = 0x325B;
+#[cfg(feature = "1_0")]
+pub const METADATA3_TYPE_NV: Int = 0x325C;
+
+// EGL_NV_stream_reset
+#[cfg(feature = "1_0")]
+pub const SUPPORT_RESET_NV: Int = 0x3334;
+#[cfg(feature = "1_0")]
+pub const SUPPORT_REUSE_NV: Int = 0x3335;
+
+// EGL_NV_stream_remote
+#[cfg(feature = "1_0")]
+pub const STREAM_STATE_INITIALIZING_NV: Int = 0x3240;
+#[cfg(feature = "1_0")]
+pub const STREAM_TYPE_NV: Int = 0x3241;
+#[cfg(feature = "1_0")]
+pub const STREAM_PROTOCOL_NV: Int = 0x3242;
+#[cfg(feature = "1_0")]
+pub const STREAM_ENDPOINT_NV: Int = 0x3243;
+#[cfg(feature = "1_0")]
+pub const STREAM_LOCAL_NV: Int = 0x3244;
+#[cfg(feature = "1_0")]
+pub const STREAM_PRODUCER_NV: Int = 0x3247;
+#[cfg(feature = "1_0")]
+pub const STREAM_CONSUMER_NV: Int = 0x3248;
+#[cfg(feature = "1_0")]
+pub const STREAM_PROTOCOL_FD_NV: Int = 0x3246;
+
+// EGL_NV_stream_socket
+#[cfg(feature = "1_0")]
+pub const STREAM_PROTOCOL_SOCKET_NV: Int = 0x324B;
+#[cfg(feature = "1_0")]
+pub const SOCKET_HANDLE_NV: Int = 0x324C;
+#[cfg(feature = "1_0")]
+pub const SOCKET_TYPE_NV: Int = 0x324D;
+
+// EGL_NV_stream_socket_inet
+#[cfg(feature = "1_0")]
+pub const SOCKET_TYPE_INET_NV: Int = 0x324F;
+
+// EGL_NV_stream_socket_unix
+#[cfg(feature = "1_0")]
+pub const SOCKET_TYPE_UNIX_NV: Int = 0x324E;
+
+// EGL_NV_stream_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_NEW_FRAME_NV: Int = 0x321F;
+
+// EGL_NV_sync
+#[cfg(feature = "1_0")]
+pub const SYNC_PRIOR_COMMANDS_COMPLETE_NV: Int = 0x30E6;
+#[cfg(feature = "1_0")]
+pub const SYNC_STATUS_NV: Int = 0x30E7;
+#[cfg(feature = "1_0")]
+pub const SIGNALED_NV: Int = 0x30E8;
+#[cfg(feature = "1_0")]
+pub const UNSIGNALED_NV: Int = 0x30E9;
+#[cfg(feature = "1_0")]
+pub const SYNC_FLUSH_COMMANDS_BIT_NV: Int = 0x0001;
+#[cfg(feature = "1_0")]
+pub const FOREVER_NV: u64 = 0xFFFFFFFFFFFFFFFF;
+#[cfg(feature = "1_0")]
+pub const ALREADY_SIGNALED_NV: Int = 0x30EA;
+#[cfg(feature = "1_0")]
+pub const TIMEOUT_EXPIRED_NV: Int = 0x30EB;
+#[cfg(feature = "1_0")]
+pub const CONDITION_SATISFIED_NV: Int = 0x30EC;
+#[cfg(feature = "1_0")]
+pub const SYNC_TYPE_NV: Int = 0x30ED;
+#[cfg(feature = "1_0")]
+pub const SYNC_CONDITION_NV: Int = 0x30EE;
+#[cfg(feature = "1_0")]
+pub const SYNC_FENCE_NV: Int = 0x30EF;
+#[cfg(feature = "1_0")]
+pub const NO_SYNC_NV: *mut c_void = ptr::null_mut();
+
+// EGL_TIZEN_image_native_buffer
+#[cfg(feature = "1_0")]
+pub const NATIVE_BUFFER_TIZEN: Int = 0x32A0;
+
+// EGL_TIZEN_image_native_surface
+#[cfg(feature = "1_0")]
+pub const NATIVE_SURFACE_TIZEN: Int = 0x32A1;
+
+// EGL_EXT_compositor
+#[cfg(feature = "1_0")]
+pub const PRIMARY_COMPOSITOR_CONTEXT_EXT: Int = 0x3460;
+#[cfg(feature = "1_0")]
+pub const EXTERNAL_REF_ID_EXT: Int = 0x3461;
+#[cfg(feature = "1_0")]
+pub const COMPOSITOR_DROP_NEWEST_FRAME_EXT: Int = 0x3462;
+#[cfg(feature = "1_0")]
+pub const COMPOSITOR_KEEP_NEWEST_FRAME_EXT: Int = 0x3463;
+
+// EGL_EXT_surface_CTA861_3_metadata
+#[cfg(feature = "1_0")]
+pub const CTA861_3_MAX_CONTENT_LIGHT_LEVEL_EXT: Int = 0x3360;
+#[cfg(feature = "1_0")]
+pub const CTA861_3_MAX_FRAME_AVERAGE_LEVEL_EXT: Int = 0x3361;
+
+// EGL_EXT_image_implicit_sync_control
+#[cfg(feature = "1_0")]
+pub const IMPORT_SYNC_TYPE_EXT: Int = 0x3470;
+#[cfg(feature = "1_0")]
+pub const IMPORT_IMPLICIT_SYNC_EXT: Int = 0x3471;
+#[cfg(feature = "1_0")]
+pub const IMPORT_EXPLICIT_SYNC_EXT: Int = 0x3472;
+
+// EGL_EXT_bind_to_front
+#[cfg(feature = "1_0")]
+pub const FRONT_BUFFER_EXT: Int = 0x3464;
@@ -0,0 +1,617 @@
+// This file is generated from `registry/egl.xml` by `cargo run -p khronos-egl-generator`.
+// Do not edit it by hand.
+
+extensions! {
+	AndroidBlobCache: "EGL_ANDROID_blob_cache" (Display, "1_0") {
+		fn eglSetBlobCacheFuncsANDROID(display: EGLDisplay, set: *mut c_void, get: *mut c_void);
+	}
+
+	AndroidCreateNativeClientBuffer: "EGL_ANDROID_create_native_client_buffer" (Display, "1_2") {
+		fn eglCreateNativeClientBufferANDROID(attrib_list: *const Int) -> EGLClientBuffer;
+	}
+
+	AndroidGetNativeClientBuffer: "EGL_ANDROID_get_native_client_buffer" (Display, "1_2") {
+		fn eglGetNativeClientBufferANDROID(buffer: *const c_void) -> EGLClientBuffer;
+	}
+
+	AndroidNativeFenceSync: "EGL_ANDROID_native_fence_sync" (Display, "1_2") {
+		fn eglDupNativeFenceFDANDROID(display: EGLDisplay, sync: EGLSync) -> Int;
+	}
+
+	AndroidPresentationTime: "EGL_ANDROID_presentation_time" (Display, "1_0") {
+		fn eglPresentationTimeANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			time: i64
+		) -> Boolean;
+	}
+
+	AndroidGetFrameTimestamps: "EGL_ANDROID_get_frame_timestamps" (Display, "1_0") {
+		fn eglGetCompositorTimingSupportedANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			name: Int
+		) -> Boolean;
+		fn eglGetCompositorTimingANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			numTimestamps: Int,
+			names: *const Int,
+			values: *mut i64
+		) -> Boolean;
+		fn eglGetNextFrameIdANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			frameId: *mut u64
+		) -> Boolean;
+		fn eglGetFrameTimestampSupportedANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			timestamp: Int
+		) -> Boolean;
+		fn eglGetFrameTimestampsANDROID(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			frameId: u64,
+			numTimestamps: Int,
+			timestamps: *const Int,
+			values: *mut i64
+		) -> Boolean;
+	}
+
+	AngleQuerySurfacePointer: "EGL_ANGLE_query_surface_pointer" (Display, "1_0") {
+		fn eglQuerySurfacePointerANGLE(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attribute: Int,
+			value: *mut *mut c_void
+		) -> Boolean;
+	}
+
+	ExtClientSync: "EGL_EXT_client_sync" (Display, "1_2") {
+		fn eglClientSignalSyncEXT(
+			display: EGLDisplay,
+			sync: EGLSync,
+			attrib_list: *const Attrib
+		) -> Boolean;
+	}
+
+	ExtDeviceBase: "EGL_EXT_device_base" (Client, "1_0") {
+		fn eglQueryDeviceAttribEXT(
+			device: EGLDeviceEXT,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglQueryDeviceStringEXT(device: EGLDeviceEXT, name: Int) -> *const c_char;
+		fn eglQueryDevicesEXT(
+			max_devices: Int,
+			devices: *mut EGLDeviceEXT,
+			num_devices: *mut Int
+		) -> Boolean;
+		fn eglQueryDisplayAttribEXT(
+			display: EGLDisplay,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+	}
+
+	ExtDeviceEnumeration: "EGL_EXT_device_enumeration" (Client, "1_0") {
+		fn eglQueryDevicesEXT(
+			max_devices: Int,
+			devices: *mut EGLDeviceEXT,
+			num_devices: *mut Int
+		) -> Boolean;
+	}
+
+	ExtDeviceQuery: "EGL_EXT_device_query" (Client, "1_0") {
+		fn eglQueryDeviceAttribEXT(
+			device: EGLDeviceEXT,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglQueryDeviceStringEXT(device: EGLDeviceEXT, name: Int) -> *const c_char;
+		fn eglQueryDisplayAttribEXT(
+			display: EGLDisplay,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+	}
+
+	ExtImageDmaBufImportModifiers: "EGL_EXT_image_dma_buf_import_modifiers" (Display, "1_0") {
+		fn eglQueryDmaBufFormatsEXT(
+			display: EGLDisplay,
+			max_formats: Int,
+			formats: *mut Int,
+			num_formats: *mut Int
+		) -> Boolean;
+		fn eglQueryDmaBufModifiersEXT(
+			display: EGLDisplay,
+			format: Int,
+			max_modifiers: Int,
+			modifiers: *mut u64,
+			external_only: *mut Boolean,
+			num_modifiers: *mut Int
+		) -> Boolean;
+	}
+
+	ExtOutputBase: "EGL_EXT_output_base" (Display, "1_0") {
+		fn eglGetOutputLayersEXT(
+			display: EGLDisplay,
+			attrib_list: *const Attrib,
+			layers: *mut *mut c_void,
+			max_layers: Int,
+			num_layers: *mut Int
+		) -> Boolean;
+		fn eglGetOutputPortsEXT(
+			display: EGLDisplay,
+			attrib_list: *const Attrib,
+			ports: *mut *mut c_void,
+			max_ports: Int,
+			num_ports: *mut Int
+		) -> Boolean;
+		fn eglOutputLayerAttribEXT(
+			display: EGLDisplay,
+			layer: *mut c_void,
+			attribute: Int,
+			value: Attrib
+		) -> Boolean;
+		fn eglQueryOutputLayerAttribEXT(
+			display: EGLDisplay,
+			layer: *mut c_void,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglQueryOutputLayerStringEXT(
+			display: EGLDisplay,
+			layer: *mut c_void,
+			name: Int
+		) -> *const c_char;
+		fn eglOutputPortAttribEXT(
+			display: EGLDisplay,
+			port: *mut c_void,
+			attribute: Int,
+			value: Attrib
+		) -> Boolean;
+		fn eglQueryOutputPortAttribEXT(
+			display: EGLDisplay,
+			port: *mut c_void,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglQueryOutputPortStringEXT(
+			display: EGLDisplay,
+			port: *mut c_void,
+			name: Int
+		) -> *const c_char;
+	}
+
+	ExtPlatformBase: "EGL_EXT_platform_base" (Client, "1_2") {
+		fn eglGetPlatformDisplayEXT(
+			platform: Enum,
+			native_display: *mut c_void,
+			attrib_list: *const Int
+		) -> EGLDisplay;
+		fn eglCreatePlatformWindowSurfaceEXT(
+			display: EGLDisplay,
+			config: EGLConfig,
+			native_window: *mut c_void,
+			attrib_list: *const Int
+		) -> EGLSurface;
+		fn eglCreatePlatformPixmapSurfaceEXT(
+			display: EGLDisplay,
+			config: EGLConfig,
+			native_pixmap: *mut c_void,
+			attrib_list: *const Int
+		) -> EGLSurface;
+	}
+
+	ExtStreamConsumerEgloutput: "EGL_EXT_stream_consumer_egloutput" (Display, "1_0") {
+		fn eglStreamConsumerOutputEXT(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			layer: *mut c_void
+		) -> Boolean;
+	}
+
+	ExtSwapBuffersWithDamage: "EGL_EXT_swap_buffers_with_damage" (Display, "1_0") {
+		fn eglSwapBuffersWithDamageEXT(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			rects: *mut Int,
+			n_rects: Int
+		) -> Boolean;
+	}
+
+	ExtSyncReuse: "EGL_EXT_sync_reuse" (Display, "1_2") {
+		fn eglUnsignalSyncEXT(
+			display: EGLDisplay,
+			sync: EGLSync,
+			attrib_list: *const Attrib
+		) -> Boolean;
+	}
+
+	HiClientpixmap: "EGL_HI_clientpixmap" (Display, "1_0") {
+		fn eglCreatePixmapSurfaceHI(
+			display: EGLDisplay,
+			config: EGLConfig,
+			pixmap: *mut c_void
+		) -> EGLSurface;
+	}
+
+	KhrClEvent2: "EGL_KHR_cl_event2" (Display, "1_2") {
+		fn eglCreateSync64KHR(
+			display: EGLDisplay,
+			type_: Enum,
+			attrib_list: *const Attrib
+		) -> EGLSync;
+	}
+
+	KhrDebug: "EGL_KHR_debug" (Client, "1_2") {
+		fn eglDebugMessageControlKHR(callback: *mut c_void, attrib_list: *const Attrib) -> Int;
+		fn eglQueryDebugKHR(attribute: Int, value: *mut Attrib) -> Boolean;
+		fn eglLabelObjectKHR(
+			display: EGLDisplay,
+			objectType: Enum,
+			object: *mut c_void,
+			label: *mut c_void
+		) -> Int;
+	}
+
+	KhrDisplayReference: "EGL_KHR_display_reference" (Display, "1_0") {
+		fn eglQueryDisplayAttribKHR(display: EGLDisplay, name: Int, value: *mut Attrib) -> Boolean;
+	}
+
+	KhrFenceSync: "EGL_KHR_fence_sync" (Display, "1_2") {
+		fn eglCreateSyncKHR(display: EGLDisplay, type_: Enum, attrib_list: *const Int) -> EGLSync;
+		fn eglDestroySyncKHR(display: EGLDisplay, sync: EGLSync) -> Boolean;
+		fn eglClientWaitSyncKHR(
+			display: EGLDisplay,
+			sync: EGLSync,
+			flags: Int,
+			timeout: Time
+		) -> Int;
+		fn eglGetSyncAttribKHR(
+			display: EGLDisplay,
+			sync: EGLSync,
+			attribute: Int,
+			value: *mut Int
+		) -> Boolean;
+	}
+
+	KhrImage: "EGL_KHR_image" (Display, "1_2") {
+		fn eglCreateImageKHR(
+			display: EGLDisplay,
+			ctx: EGLContext,
+			target: Enum,
+			buffer: EGLClientBuffer,
+			attrib_list: *const Int
+		) -> EGLImage;
+		fn eglDestroyImageKHR(display: EGLDisplay, image: EGLImage) -> Boolean;
+	}
+
+	KhrImageBase: "EGL_KHR_image_base" (Display, "1_2") {
+		fn eglCreateImageKHR(
+			display: EGLDisplay,
+			ctx: EGLContext,
+			target: Enum,
+			buffer: EGLClientBuffer,
+			attrib_list: *const Int
+		) -> EGLImage;
+		fn eglDestroyImageKHR(display: EGLDisplay, image: EGLImage) -> Boolean;
+	}
+
+	KhrLockSurface: "EGL_KHR_lock_surface" (Display, "1_0") {
+		fn eglLockSurfaceKHR(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attrib_list: *const Int
+		) -> Boolean;
+		fn eglUnlockSurfaceKHR(display: EGLDisplay, surface: EGLSurface) -> Boolean;
+	}
+
+	KhrLockSurface3: "EGL_KHR_lock_surface3" (Display, "1_0") {
+		fn eglLockSurfaceKHR(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attrib_list: *const Int
+		) -> Boolean;
+		fn eglUnlockSurfaceKHR(display: EGLDisplay, surface: EGLSurface) -> Boolean;
+		fn eglQuerySurface64KHR(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+	}
+
+	KhrPartialUpdate: "EGL_KHR_partial_update" (Display, "1_0") {
+		fn eglSetDamageRegionKHR(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			rects: *mut Int,
+			n_rects: Int
+		) -> Boolean;
+	}
+
+	KhrReusableSync: "EGL_KHR_reusable_sync" (Display, "1_2") {
+		fn eglCreateSyncKHR(display: EGLDisplay, type_: Enum, attrib_list: *const Int) -> EGLSync;
+		fn eglDestroySyncKHR(display: EGLDisplay, sync: EGLSync) -> Boolean;
+		fn eglClientWaitSyncKHR(
+			display: EGLDisplay,
+			sync: EGLSync,
+			flags: Int,
+			timeout: Time
+		) -> Int;
+		fn eglSignalSyncKHR(display: EGLDisplay, sync: EGLSync, mode: Enum) -> Boolean;
+		fn eglGetSyncAttribKHR(
+			display: EGLDisplay,
+			sync: EGLSync,
+			attribute: Int,
+			value: *mut Int
+		) -> Boolean;
+	}
+
+	KhrStream: "EGL_KHR_stream" (Display, "1_2") {
+		fn eglCreateStreamKHR(display: EGLDisplay, attrib_list: *const Int) -> *mut c_void;
+		fn eglDestroyStreamKHR(display: EGLDisplay, stream: *mut c_void) -> Boolean;
+		fn eglStreamAttribKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: Int
+		) -> Boolean;
+		fn eglQueryStreamKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: *mut Int
+		) -> Boolean;
+		fn eglQueryStreamu64KHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: *mut u64
+		) -> Boolean;
+	}
+
+	KhrStreamAttrib: "EGL_KHR_stream_attrib" (Display, "1_2") {
+		fn eglCreateStreamAttribKHR(display: EGLDisplay, attrib_list: *const Attrib) -> *mut c_void;
+		fn eglSetStreamAttribKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: Attrib
+		) -> Boolean;
+		fn eglQueryStreamAttribKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglStreamConsumerAcquireAttribKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attrib_list: *const Attrib
+		) -> Boolean;
+		fn eglStreamConsumerReleaseAttribKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attrib_list: *const Attrib
+		) -> Boolean;
+	}
+
+	KhrStreamConsumerGltexture: "EGL_KHR_stream_consumer_gltexture" (Display, "1_0") {
+		fn eglStreamConsumerGLTextureExternalKHR(
+			display: EGLDisplay,
+			stream: *mut c_void
+		) -> Boolean;
+		fn eglStreamConsumerAcquireKHR(display: EGLDisplay, stream: *mut c_void) -> Boolean;
+		fn eglStreamConsumerReleaseKHR(display: EGLDisplay, stream: *mut c_void) -> Boolean;
+	}
+
+	KhrStreamCrossProcessFd: "EGL_KHR_stream_cross_process_fd" (Display, "1_0") {
+		fn eglGetStreamFileDescriptorKHR(display: EGLDisplay, stream: *mut c_void) -> Int;
+		fn eglCreateStreamFromFileDescriptorKHR(
+			display: EGLDisplay,
+			file_descriptor: Int
+		) -> *mut c_void;
+	}
+
+	KhrStreamFifo: "EGL_KHR_stream_fifo" (Display, "1_2") {
+		fn eglQueryStreamTimeKHR(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attribute: Enum,
+			value: *mut Time
+		) -> Boolean;
+	}
+
+	KhrStreamProducerEglsurface: "EGL_KHR_stream_producer_eglsurface" (Display, "1_0") {
+		fn eglCreateStreamProducerSurfaceKHR(
+			display: EGLDisplay,
+			config: EGLConfig,
+			stream: *mut c_void,
+			attrib_list: *const Int
+		) -> EGLSurface;
+	}
+
+	KhrSwapBuffersWithDamage: "EGL_KHR_swap_buffers_with_damage" (Display, "1_0") {
+		fn eglSwapBuffersWithDamageKHR(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			rects: *mut Int,
+			n_rects: Int
+		) -> Boolean;
+	}
+
+	KhrWaitSync: "EGL_KHR_wait_sync" (Display, "1_2") {
+		fn eglWaitSyncKHR(display: EGLDisplay, sync: EGLSync, flags: Int) -> Int;
+	}
+
+	MesaDrmImage: "EGL_MESA_drm_image" (Display, "1_2") {
+		fn eglCreateDRMImageMESA(display: EGLDisplay, attrib_list: *const Int) -> EGLImage;
+		fn eglExportDRMImageMESA(
+			display: EGLDisplay,
+			image: EGLImage,
+			name: *mut Int,
+			handle: *mut Int,
+			stride: *mut Int
+		) -> Boolean;
+	}
+
+	MesaImageDmaBufExport: "EGL_MESA_image_dma_buf_export" (Display, "1_2") {
+		fn eglExportDMABUFImageQueryMESA(
+			display: EGLDisplay,
+			image: EGLImage,
+			fourcc: *mut libc::c_int,
+			num_planes: *mut libc::c_int,
+			modifiers: *mut u64
+		) -> Boolean;
+		fn eglExportDMABUFImageMESA(
+			display: EGLDisplay,
+			image: EGLImage,
+			fds: *mut libc::c_int,
+			strides: *mut Int,
+			offsets: *mut Int
+		) -> Boolean;
+	}
+
+	NokSwapRegion: "EGL_NOK_swap_region" (Display, "1_0") {
+		fn eglSwapBuffersRegionNOK(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			numRects: Int,
+			rects: *const Int
+		) -> Boolean;
+	}
+
+	NokSwapRegion2: "EGL_NOK_swap_region2" (Display, "1_0") {
+		fn eglSwapBuffersRegion2NOK(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			numRects: Int,
+			rects: *const Int
+		) -> Boolean;
+	}
+
+	NvNativeQuery: "EGL_NV_native_query" (Display, "1_0") {
+		fn eglQueryNativeDisplayNV(
+			display: EGLDisplay,
+			display_id: *mut NativeDisplayType
+		) -> Boolean;
+		fn eglQueryNativeWindowNV(
+			display: EGLDisplay,
+			surf: EGLSurface,
+			window: *mut NativeWindowType
+		) -> Boolean;
+		fn eglQueryNativePixmapNV(
+			display: EGLDisplay,
+			surf: EGLSurface,
+			pixmap: *mut NativePixmapType
+		) -> Boolean;
+	}
+
+	NvPostSubBuffer: "EGL_NV_post_sub_buffer" (Display, "1_0") {
+		fn eglPostSubBufferNV(
+			display: EGLDisplay,
+			surface: EGLSurface,
+			x: Int,
+			y: Int,
+			width: Int,
+			height: Int
+		) -> Boolean;
+	}
+
+	NvStreamConsumerGltextureYuv: "EGL_NV_stream_consumer_gltexture_yuv" (Display, "1_0") {
+		fn eglStreamConsumerGLTextureExternalAttribsNV(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			attrib_list: *const Attrib
+		) -> Boolean;
+	}
+
+	NvStreamFlush: "EGL_NV_stream_flush" (Display, "1_0") {
+		fn eglStreamFlushNV(display: EGLDisplay, stream: *mut c_void) -> Boolean;
+	}
+
+	NvStreamMetadata: "EGL_NV_stream_metadata" (Display, "1_2") {
+		fn eglQueryDisplayAttribNV(
+			display: EGLDisplay,
+			attribute: Int,
+			value: *mut Attrib
+		) -> Boolean;
+		fn eglSetStreamMetadataNV(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			n: Int,
+			offset: Int,
+			size: Int,
+			data: *const c_void
+		) -> Boolean;
+		fn eglQueryStreamMetadataNV(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			name: Enum,
+			n: Int,
+			offset: Int,
+			size: Int,
+			data: *mut c_void
+		) -> Boolean;
+	}
+
+	NvStreamReset: "EGL_NV_stream_reset" (Display, "1_0") {
+		fn eglResetStreamNV(display: EGLDisplay, stream: *mut c_void) -> Boolean;
+	}
+
+	NvStreamSync: "EGL_NV_stream_sync" (Display, "1_2") {
+		fn eglCreateStreamSyncNV(
+			display: EGLDisplay,
+			stream: *mut c_void,
+			type_: Enum,
+			attrib_list: *const Int
+		) -> EGLSync;
+	}
+
+	NvSync: "EGL_NV_sync" (Display, "1_2") {
+		fn eglCreateFenceSyncNV(
+			display: EGLDisplay,
+			condition: Enum,
+			attrib_list: *const Int
+		) -> *mut c_void;
+		fn eglDestroySyncNV(sync: *mut c_void) -> Boolean;
+		fn eglFenceNV(sync: *mut c_void) -> Boolean;
+		fn eglClientWaitSyncNV(sync: *mut c_void, flags: Int, timeout: u64) -> Int;
+		fn eglSignalSyncNV(sync: *mut c_void, mode: Enum) -> Boolean;
+		fn eglGetSyncAttribNV(sync: *mut c_void, attribute: Int, value: *mut Int) -> Boolean;
+	}
+
+	NvSystemTime: "EGL_NV_system_time" (Display, "1_0") {
+		fn eglGetSystemTimeFrequencyNV() -> u64;
+		fn eglGetSystemTimeNV() -> u64;
+	}
+
+	ExtCompositor: "EGL_EXT_compositor" (Display, "1_0") {
+		fn eglCompositorSetContextListEXT(
+			external_ref_ids: *const Int,
+			num_entries: Int
+		) -> Boolean;
+		fn eglCompositorSetContextAttributesEXT(
+			external_ref_id: Int,
+			context_attributes: *const Int,
+			num_entries: Int
+		) -> Boolean;
+		fn eglCompositorSetWindowListEXT(
+			external_ref_id: Int,
+			external_win_ids: *const Int,
+			num_entries: Int
+		) -> Boolean;
+		fn eglCompositorSetWindowAttributesEXT(
+			external_win_id: Int,
+			window_attributes: *const Int,
+			num_entries: Int
+		) -> Boolean;
+		fn eglCompositorBindTexWindowEXT(external_win_id: Int) -> Boolean;
+		fn eglCompositorSetSizeEXT(external_win_id: Int, width: Int, height: Int) -> Boolean;
+		fn eglCompositorSwapPolicyEXT(external_win_id: Int, policy: Int) -> Boolean;
+	}
+}
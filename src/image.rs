@@ -0,0 +1,855 @@
+//! Images.
+use super::*;
+
+impl<T: api::EGL1_0> Instance<T> {
+	/// Create a new Image object (`EGL_KHR_image_base`).
+	///
+	/// This is equivalent to [`create_image`](Self::create_image), with an
+	/// `Int` attribute list, and is available on EGL 1.2 to 1.4 when the
+	/// display supports the extension.
+	///
+	/// This will return a `BadParameter` error if `attrib_list` is not a valid
+	/// attributes list (if it does not terminate with `NONE`).
+	pub fn create_image_khr(
+		&self,
+		display: Display,
+		ctx: Context,
+		target: Enum,
+		buffer: ClientBuffer,
+		attrib_list: &[Int],
+	) -> Result<Image, Error> {
+		check_int_list(attrib_list)?;
+		let ext = self.load_extension::<KhrImageBase>(Some(display))?;
+		unsafe {
+			let image = ext.eglCreateImageKHR(
+				display.as_ptr(),
+				ctx.as_ptr(),
+				target,
+				buffer.as_ptr(),
+				attrib_list.as_ptr(),
+			);
+			if image != NO_IMAGE_KHR {
+				Ok(Image(image))
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglCreateImageKHR")
+						.with_display(display)
+						.with_arg("ctx", &ctx)
+						.with_arg("target", &target)
+						.with_arg("buffer", &buffer)
+						.with_int_list(attrib_list),
+				))
+			}
+		}
+	}
+
+	/// Destroy an Image object created with
+	/// [`create_image_khr`](Self::create_image_khr) (`EGL_KHR_image_base`).
+	pub fn destroy_image_khr(&self, display: Display, image: Image) -> Result<(), Error> {
+		let ext = self.load_extension::<KhrImageBase>(Some(display))?;
+		unsafe {
+			if ext.eglDestroyImageKHR(display.as_ptr(), image.as_ptr()) == TRUE {
+				Ok(())
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglDestroyImageKHR")
+						.with_display(display)
+						.with_arg("image", &image),
+				))
+			}
+		}
+	}
+}
+
+/// Attributes of the dma-buf planes, by plane index: file descriptor,
+/// offset, pitch, and low and high bits of the modifier.
+#[cfg(unix)]
+pub(crate) const DMA_BUF_PLANE_ATTRIBS: [(Int, Int, Int, Int, Int); 4] = [
+	(
+		DMA_BUF_PLANE0_FD_EXT,
+		DMA_BUF_PLANE0_OFFSET_EXT,
+		DMA_BUF_PLANE0_PITCH_EXT,
+		DMA_BUF_PLANE0_MODIFIER_LO_EXT,
+		DMA_BUF_PLANE0_MODIFIER_HI_EXT,
+	),
+	(
+		DMA_BUF_PLANE1_FD_EXT,
+		DMA_BUF_PLANE1_OFFSET_EXT,
+		DMA_BUF_PLANE1_PITCH_EXT,
+		DMA_BUF_PLANE1_MODIFIER_LO_EXT,
+		DMA_BUF_PLANE1_MODIFIER_HI_EXT,
+	),
+	(
+		DMA_BUF_PLANE2_FD_EXT,
+		DMA_BUF_PLANE2_OFFSET_EXT,
+		DMA_BUF_PLANE2_PITCH_EXT,
+		DMA_BUF_PLANE2_MODIFIER_LO_EXT,
+		DMA_BUF_PLANE2_MODIFIER_HI_EXT,
+	),
+	(
+		DMA_BUF_PLANE3_FD_EXT,
+		DMA_BUF_PLANE3_OFFSET_EXT,
+		DMA_BUF_PLANE3_PITCH_EXT,
+		DMA_BUF_PLANE3_MODIFIER_LO_EXT,
+		DMA_BUF_PLANE3_MODIFIER_HI_EXT,
+	),
+];
+
+/// Returns the plane index of a dma-buf plane attribute, and its position in
+/// the [`DMA_BUF_PLANE_ATTRIBS`] entry of the plane.
+#[cfg(all(unix, feature = "mock"))]
+pub(crate) fn dma_buf_plane_attrib(attribute: Int) -> Option<(usize, usize)> {
+	DMA_BUF_PLANE_ATTRIBS.iter().enumerate().find_map(
+		|(plane, &(fd, offset, pitch, modifier_lo, modifier_hi))| {
+			[fd, offset, pitch, modifier_lo, modifier_hi]
+				.iter()
+				.position(|a| *a == attribute)
+				.map(|i| (plane, i))
+		},
+	)
+}
+
+/// Plane of a [`DmaBufImage`].
+#[cfg(unix)]
+#[derive(Clone, Copy, Debug)]
+pub struct DmaBufPlane<'fd> {
+	/// File descriptor of the dma-buf holding the plane.
+	pub fd: std::os::fd::BorrowedFd<'fd>,
+
+	/// Offset of the plane in the dma-buf, in bytes.
+	pub offset: Int,
+
+	/// Pitch (stride) of the plane, in bytes.
+	pub pitch: Int,
+}
+
+/// Linux dma-buf image descriptor (`EGL_EXT_image_dma_buf_import`).
+///
+/// This describes the planes of an image to import with
+/// [`Instance::import_dma_buf`]. The file descriptors are only borrowed:
+/// EGL does not take their ownership, and they can be closed once the image
+/// is created.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate khronos_egl as egl;
+/// # use std::os::fd::AsFd;
+/// # fn main() -> Result<(), egl::Error> {
+/// # let buffer = std::fs::File::open("/dev/null").unwrap();
+/// // DRM_FORMAT_NV12, with both planes in the same buffer.
+/// let fourcc = u32::from_le_bytes(*b"NV12");
+/// let image = egl::DmaBufImage::new(64, 64, fourcc)
+///   .plane(buffer.as_fd(), 0, 64)
+///   .plane(buffer.as_fd(), 64 * 64, 64);
+///
+/// assert_eq!(image.build()?.len(), 6 + 2 * 6 + 1);
+///
+/// // DRM_FORMAT_MOD_LINEAR, given for each plane.
+/// let image = image.with_modifier(0);
+/// assert_eq!(image.build()?.len(), 6 + 2 * 10 + 1);
+///
+/// // An image has at most four planes.
+/// let image = image
+///   .plane(buffer.as_fd(), 0, 64)
+///   .plane(buffer.as_fd(), 0, 64)
+///   .plane(buffer.as_fd(), 0, 64);
+///
+/// assert_eq!(image.build(), Err(egl::Error::BadParameter));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(unix)]
+#[derive(Clone, Debug)]
+pub struct DmaBufImage<'fd> {
+	width: Int,
+	height: Int,
+	fourcc: u32,
+	modifier: Option<u64>,
+	planes: Vec<DmaBufPlane<'fd>>,
+}
+
+#[cfg(unix)]
+impl<'fd> DmaBufImage<'fd> {
+	/// Creates an image descriptor with the given size and DRM format
+	/// (`LINUX_DRM_FOURCC_EXT`), without any plane.
+	pub fn new(width: Int, height: Int, fourcc: u32) -> Self {
+		DmaBufImage {
+			width,
+			height,
+			fourcc,
+			modifier: None,
+			planes: Vec::new(),
+		}
+	}
+
+	/// Returns the width of the image, in pixels.
+	#[inline]
+	pub fn width(&self) -> Int {
+		self.width
+	}
+
+	/// Returns the height of the image, in pixels.
+	#[inline]
+	pub fn height(&self) -> Int {
+		self.height
+	}
+
+	/// Returns the DRM format of the image.
+	#[inline]
+	pub fn fourcc(&self) -> u32 {
+		self.fourcc
+	}
+
+	/// Returns the DRM format modifier of the image, if any.
+	#[inline]
+	pub fn modifier(&self) -> Option<u64> {
+		self.modifier
+	}
+
+	/// Returns the planes of the image.
+	#[inline]
+	pub fn planes(&self) -> &[DmaBufPlane<'fd>] {
+		&self.planes
+	}
+
+	/// Adds a plane, given its dma-buf, offset and pitch in bytes.
+	pub fn plane(mut self, fd: std::os::fd::BorrowedFd<'fd>, offset: Int, pitch: Int) -> Self {
+		self.planes.push(DmaBufPlane { fd, offset, pitch });
+		self
+	}
+
+	/// Sets the DRM format modifier of the image
+	/// (`EGL_EXT_image_dma_buf_import_modifiers`).
+	///
+	/// The modifier is given for every plane, split into the
+	/// `DMA_BUF_PLANE*_MODIFIER_LO_EXT` and `DMA_BUF_PLANE*_MODIFIER_HI_EXT`
+	/// attributes.
+	pub fn with_modifier(mut self, modifier: u64) -> Self {
+		self.modifier = Some(modifier);
+		self
+	}
+
+	/// Builds the attributes list given to `eglCreateImageKHR` with the
+	/// `LINUX_DMA_BUF_EXT` target.
+	///
+	/// Returns a `BadParameter` error if the image does not have between
+	/// one and four planes.
+	pub fn build(&self) -> Result<Vec<Int>, Error> {
+		use std::os::fd::AsRawFd;
+
+		if self.planes.is_empty() || self.planes.len() > DMA_BUF_PLANE_ATTRIBS.len() {
+			return Err(Error::BadParameter.without_context());
+		}
+
+		let mut attrib_list = vec![
+			WIDTH,
+			self.width,
+			HEIGHT,
+			self.height,
+			LINUX_DRM_FOURCC_EXT,
+			self.fourcc as Int,
+		];
+
+		for (plane, (fd, offset, pitch, modifier_lo, modifier_hi)) in
+			self.planes.iter().zip(DMA_BUF_PLANE_ATTRIBS)
+		{
+			attrib_list.extend([
+				fd,
+				plane.fd.as_raw_fd(),
+				offset,
+				plane.offset,
+				pitch,
+				plane.pitch,
+			]);
+
+			if let Some(modifier) = self.modifier {
+				attrib_list.extend([
+					modifier_lo,
+					modifier as u32 as Int,
+					modifier_hi,
+					(modifier >> 32) as u32 as Int,
+				]);
+			}
+		}
+
+		attrib_list.push(NONE);
+		Ok(attrib_list)
+	}
+}
+
+/// Plane of a [`DmaBufExport`].
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct DmaBufExportPlane {
+	/// File descriptor of the dma-buf holding the plane.
+	///
+	/// This is `None` if the plane is stored in the dma-buf of a previous
+	/// plane.
+	pub fd: Option<std::os::fd::OwnedFd>,
+
+	/// Offset of the plane in the dma-buf, in bytes.
+	pub offset: Int,
+
+	/// Stride of the plane, in bytes.
+	pub stride: Int,
+}
+
+/// Image exported as Linux dma-bufs (`EGL_MESA_image_dma_buf_export`).
+///
+/// This is returned by [`Instance::export_dma_buf`]. The file descriptors
+/// are owned, and closed when dropped.
+#[cfg(unix)]
+#[derive(Debug)]
+pub struct DmaBufExport {
+	/// DRM format of the image.
+	pub fourcc: u32,
+
+	/// DRM format modifier of the image.
+	pub modifier: u64,
+
+	/// Planes of the image.
+	pub planes: Vec<DmaBufExportPlane>,
+}
+
+#[cfg(unix)]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Import a Linux dma-buf as an Image (`EGL_EXT_image_dma_buf_import`).
+	///
+	/// Importing a four planes image, or an image with a modifier, also
+	/// requires the `EGL_EXT_image_dma_buf_import_modifiers` extension.
+	///
+	/// Returns a `BadParameter` error if the image does not have between
+	/// one and four planes, and [`Error::ExtensionNotSupported`] if the display
+	/// does not support the required extensions.
+	pub fn import_dma_buf(&self, display: Display, image: &DmaBufImage) -> Result<Image, Error> {
+		let attrib_list = image.build()?;
+
+		let extensions = self.display_extensions(display)?;
+		if !extensions.has("EGL_EXT_image_dma_buf_import") {
+			return Err(
+				Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import").without_context()
+			);
+		}
+
+		if (image.planes.len() > 3 || image.modifier.is_some())
+			&& !extensions.has("EGL_EXT_image_dma_buf_import_modifiers")
+		{
+			return Err(
+				Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import_modifiers")
+					.without_context(),
+			);
+		}
+
+		unsafe {
+			self.create_image_khr(
+				display,
+				Context::from_ptr(NO_CONTEXT),
+				LINUX_DMA_BUF_EXT as Enum,
+				ClientBuffer::from_ptr(ptr::null_mut()),
+				&attrib_list,
+			)
+		}
+	}
+
+	/// Return the DRM formats supported for dma-buf import
+	/// (`EGL_EXT_image_dma_buf_import_modifiers`).
+	pub fn query_dma_buf_formats(&self, display: Display) -> Result<Vec<u32>, Error> {
+		let ext = self.load_extension::<ExtImageDmaBufImportModifiers>(Some(display))?;
+		unsafe {
+			let mut count = 0;
+
+			if ext.eglQueryDmaBufFormatsEXT(display.as_ptr(), 0, ptr::null_mut(), &mut count)
+				!= TRUE
+			{
+				return Err(self.error_in(
+					ErrorContext::new("eglQueryDmaBufFormatsEXT").with_display(display),
+				));
+			}
+
+			let mut formats: Vec<u32> = Vec::with_capacity(count as usize);
+
+			if ext.eglQueryDmaBufFormatsEXT(
+				display.as_ptr(),
+				count,
+				formats.as_mut_ptr() as *mut Int,
+				&mut count,
+			) == TRUE
+			{
+				formats.set_len(count as usize);
+				Ok(formats)
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDmaBufFormatsEXT")
+						.with_display(display)
+						.with_arg("max_formats", &count),
+				))
+			}
+		}
+	}
+
+	/// Return the DRM format modifiers supported for dma-buf import with the
+	/// given format (`EGL_EXT_image_dma_buf_import_modifiers`).
+	///
+	/// Each modifier comes with a flag telling if images using it can only be
+	/// used with the `GL_TEXTURE_EXTERNAL_OES` target.
+	pub fn query_dma_buf_modifiers(
+		&self,
+		display: Display,
+		format: u32,
+	) -> Result<Vec<(u64, bool)>, Error> {
+		let ext = self.load_extension::<ExtImageDmaBufImportModifiers>(Some(display))?;
+		unsafe {
+			let mut count = 0;
+
+			if ext.eglQueryDmaBufModifiersEXT(
+				display.as_ptr(),
+				format as Int,
+				0,
+				ptr::null_mut(),
+				ptr::null_mut(),
+				&mut count,
+			) != TRUE
+			{
+				return Err(self.error_in(
+					ErrorContext::new("eglQueryDmaBufModifiersEXT")
+						.with_display(display)
+						.with_arg("format", &format),
+				));
+			}
+
+			let mut modifiers: Vec<u64> = Vec::with_capacity(count as usize);
+			let mut external_only: Vec<Boolean> = Vec::with_capacity(count as usize);
+
+			if ext.eglQueryDmaBufModifiersEXT(
+				display.as_ptr(),
+				format as Int,
+				count,
+				modifiers.as_mut_ptr(),
+				external_only.as_mut_ptr(),
+				&mut count,
+			) == TRUE
+			{
+				modifiers.set_len(count as usize);
+				external_only.set_len(count as usize);
+				Ok(modifiers
+					.into_iter()
+					.zip(external_only)
+					.map(|(modifier, external_only)| (modifier, external_only == TRUE))
+					.collect())
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglQueryDmaBufModifiersEXT")
+						.with_display(display)
+						.with_arg("format", &format)
+						.with_arg("max_modifiers", &count),
+				))
+			}
+		}
+	}
+
+	/// Export an Image as Linux dma-bufs (`EGL_MESA_image_dma_buf_export`).
+	///
+	/// This queries the format, modifier and number of planes of the image
+	/// with `eglExportDMABUFImageQueryMESA`, then exports the planes with
+	/// `eglExportDMABUFImageMESA`. The returned file descriptors are owned
+	/// by the caller.
+	pub fn export_dma_buf(&self, display: Display, image: Image) -> Result<DmaBufExport, Error> {
+		use std::os::fd::{FromRawFd, OwnedFd};
+
+		let ext = self.load_extension::<MesaImageDmaBufExport>(Some(display))?;
+		unsafe {
+			let mut fourcc = 0;
+			let mut num_planes = 0;
+
+			if ext.eglExportDMABUFImageQueryMESA(
+				display.as_ptr(),
+				image.as_ptr(),
+				&mut fourcc,
+				&mut num_planes,
+				ptr::null_mut(),
+			) != TRUE
+			{
+				return Err(self.error_in(
+					ErrorContext::new("eglExportDMABUFImageQueryMESA")
+						.with_display(display)
+						.with_arg("image", &image),
+				));
+			}
+
+			// Some implementations give the modifier of each plane.
+			let num_planes = num_planes.max(0) as usize;
+			let mut modifiers = vec![0u64; num_planes.max(1)];
+
+			if ext.eglExportDMABUFImageQueryMESA(
+				display.as_ptr(),
+				image.as_ptr(),
+				ptr::null_mut(),
+				ptr::null_mut(),
+				modifiers.as_mut_ptr(),
+			) != TRUE
+			{
+				return Err(self.error_in(
+					ErrorContext::new("eglExportDMABUFImageQueryMESA")
+						.with_display(display)
+						.with_arg("image", &image),
+				));
+			}
+
+			let mut fds = vec![-1; num_planes];
+			let mut strides = vec![0; num_planes];
+			let mut offsets = vec![0; num_planes];
+
+			if ext.eglExportDMABUFImageMESA(
+				display.as_ptr(),
+				image.as_ptr(),
+				fds.as_mut_ptr(),
+				strides.as_mut_ptr(),
+				offsets.as_mut_ptr(),
+			) != TRUE
+			{
+				return Err(self.error_in(
+					ErrorContext::new("eglExportDMABUFImageMESA")
+						.with_display(display)
+						.with_arg("image", &image),
+				));
+			}
+
+			let planes = fds
+				.into_iter()
+				.zip(strides)
+				.zip(offsets)
+				.map(|((fd, stride), offset)| DmaBufExportPlane {
+					fd: (fd >= 0).then(|| OwnedFd::from_raw_fd(fd)),
+					offset,
+					stride,
+				})
+				.collect();
+
+			Ok(DmaBufExport {
+				fourcc: fourcc as u32,
+				modifier: modifiers[0],
+				planes,
+			})
+		}
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	/// Returns an initialized display and a context created on it.
+	fn initialized(egl: &Instance<Mock>) -> (Display, Context) {
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let ctx = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		(display, ctx)
+	}
+
+	#[test]
+	fn khr_image() {
+		let egl = Instance::new(Mock::new());
+		let (display, ctx) = initialized(&egl);
+		let buffer = unsafe { ClientBuffer::from_ptr(1 as EGLClientBuffer) };
+		assert_eq!(
+			egl.create_image_khr(display, ctx, GL_TEXTURE_2D as Enum, buffer, &[NONE]),
+			Err(Error::ExtensionNotSupported("EGL_KHR_image_base"))
+		);
+
+		let egl = Instance::new(Mock::new().with_display_extensions("EGL_KHR_image_base"));
+		let (display, ctx) = initialized(&egl);
+		let image = egl
+			.create_image_khr(
+				display,
+				ctx,
+				GL_TEXTURE_2D as Enum,
+				buffer,
+				&[GL_TEXTURE_LEVEL, 0, NONE],
+			)
+			.unwrap();
+
+		assert_eq!(
+			egl.create_image_khr(display, ctx, GL_TEXTURE_2D as Enum, buffer, &[NONE, 0]),
+			Err(Error::BadParameter)
+		);
+		assert_eq!(
+			egl.create_image_khr(
+				display,
+				ctx,
+				GL_TEXTURE_2D as Enum,
+				buffer,
+				&[WIDTH, 1, NONE]
+			),
+			Err(Error::BadParameter)
+		);
+
+		let e = egl
+			.create_image_khr(
+				display,
+				unsafe { Context::from_ptr(NO_CONTEXT) },
+				GL_TEXTURE_2D as Enum,
+				buffer,
+				&[NONE],
+			)
+			.unwrap_err();
+		assert_eq!(e, Error::BadContext);
+		assert_eq!(e.context().unwrap().function(), "eglCreateImageKHR");
+
+		assert_eq!(egl.destroy_image_khr(display, image), Ok(()));
+		assert_eq!(
+			egl.destroy_image_khr(display, image),
+			Err(Error::BadParameter)
+		);
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn dma_buf_attrib_list() {
+		use std::os::fd::{AsFd, AsRawFd};
+
+		let (a, b) = (
+			std::fs::File::open("/dev/null").unwrap(),
+			std::fs::File::open("/dev/null").unwrap(),
+		);
+		let image = DmaBufImage::new(64, 32, 0x3231564e)
+			.plane(a.as_fd(), 0, 64)
+			.plane(b.as_fd(), 2048, 64)
+			.with_modifier(0x0100_0000_0000_0002);
+
+		assert_eq!(
+			image.build(),
+			Ok(vec![
+				WIDTH,
+				64,
+				HEIGHT,
+				32,
+				LINUX_DRM_FOURCC_EXT,
+				0x3231564e,
+				DMA_BUF_PLANE0_FD_EXT,
+				a.as_raw_fd(),
+				DMA_BUF_PLANE0_OFFSET_EXT,
+				0,
+				DMA_BUF_PLANE0_PITCH_EXT,
+				64,
+				DMA_BUF_PLANE0_MODIFIER_LO_EXT,
+				2,
+				DMA_BUF_PLANE0_MODIFIER_HI_EXT,
+				0x0100_0000,
+				DMA_BUF_PLANE1_FD_EXT,
+				b.as_raw_fd(),
+				DMA_BUF_PLANE1_OFFSET_EXT,
+				2048,
+				DMA_BUF_PLANE1_PITCH_EXT,
+				64,
+				DMA_BUF_PLANE1_MODIFIER_LO_EXT,
+				2,
+				DMA_BUF_PLANE1_MODIFIER_HI_EXT,
+				0x0100_0000,
+				NONE,
+			])
+		);
+
+		let image = DmaBufImage::new(64, 32, 0x3231564e);
+		assert_eq!(image.build(), Err(Error::BadParameter));
+
+		let image = (0..5).fold(image, |image, _| image.plane(a.as_fd(), 0, 64));
+		assert_eq!(image.build(), Err(Error::BadParameter));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn import_dma_buf() {
+		use std::os::fd::AsFd;
+
+		let buffer = std::fs::File::open("/dev/null").unwrap();
+		let linear = DmaBufImage::new(64, 64, 0x34325241).plane(buffer.as_fd(), 0, 256);
+		let planes = (0..4).fold(DmaBufImage::new(64, 64, 0x34325241), |image, _| {
+			image.plane(buffer.as_fd(), 0, 256)
+		});
+
+		let egl = Instance::new(Mock::new().with_display_extensions("EGL_KHR_image_base"));
+		let (display, _) = initialized(&egl);
+		assert_eq!(
+			egl.import_dma_buf(display, &linear),
+			Err(Error::ExtensionNotSupported("EGL_EXT_image_dma_buf_import"))
+		);
+
+		let egl = Instance::new(
+			Mock::new().with_display_extensions("EGL_KHR_image_base EGL_EXT_image_dma_buf_import"),
+		);
+		let (display, _) = initialized(&egl);
+		let image = egl.import_dma_buf(display, &linear).unwrap();
+		assert_eq!(egl.destroy_image_khr(display, image), Ok(()));
+
+		for image in [&linear.clone().with_modifier(0), &planes] {
+			assert_eq!(
+				egl.import_dma_buf(display, image),
+				Err(Error::ExtensionNotSupported(
+					"EGL_EXT_image_dma_buf_import_modifiers"
+				))
+			);
+		}
+
+		// Planes must have a pitch.
+		let mut attrib_list = linear.build().unwrap();
+		attrib_list.truncate(attrib_list.len() - 3);
+		attrib_list.push(NONE);
+		let e = unsafe {
+			egl.create_image_khr(
+				display,
+				Context::from_ptr(NO_CONTEXT),
+				LINUX_DMA_BUF_EXT as Enum,
+				ClientBuffer::from_ptr(ptr::null_mut()),
+				&attrib_list,
+			)
+		};
+		assert_eq!(e, Err(Error::BadParameter));
+
+		let egl = Instance::new(Mock::new().with_display_extensions(
+			"EGL_KHR_image_base EGL_EXT_image_dma_buf_import EGL_EXT_image_dma_buf_import_modifiers",
+		));
+		let (display, _) = initialized(&egl);
+		for image in [&linear.clone().with_modifier(0), &planes.with_modifier(0)] {
+			let image = egl.import_dma_buf(display, image).unwrap();
+			assert_eq!(egl.destroy_image_khr(display, image), Ok(()));
+		}
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn dma_buf_formats() {
+		const XR24: u32 = 0x34325258;
+		const NV12: u32 = 0x3231564e;
+		const YUYV: u32 = 0x56595559;
+
+		let egl = Instance::new(Mock::new());
+		let (display, _) = initialized(&egl);
+		assert_eq!(
+			egl.query_dma_buf_formats(display),
+			Err(Error::ExtensionNotSupported(
+				"EGL_EXT_image_dma_buf_import_modifiers"
+			))
+		);
+
+		let egl = Instance::new(
+			Mock::new()
+				.with_display_extensions("EGL_EXT_image_dma_buf_import_modifiers")
+				.with_dma_buf_formats(vec![
+					(XR24, vec![(0, false), (0x0100_0000_0000_0001, false)]),
+					(NV12, vec![(0, true)]),
+					(YUYV, vec![]),
+				]),
+		);
+		let (display, _) = initialized(&egl);
+		assert_eq!(
+			egl.query_dma_buf_formats(display),
+			Ok(vec![XR24, NV12, YUYV])
+		);
+		assert_eq!(
+			egl.query_dma_buf_modifiers(display, XR24),
+			Ok(vec![(0, false), (0x0100_0000_0000_0001, false)])
+		);
+		assert_eq!(
+			egl.query_dma_buf_modifiers(display, NV12),
+			Ok(vec![(0, true)])
+		);
+		assert_eq!(egl.query_dma_buf_modifiers(display, YUYV), Ok(vec![]));
+
+		let e = egl.query_dma_buf_modifiers(display, 0).unwrap_err();
+		assert_eq!(e, Error::BadParameter);
+		assert_eq!(
+			e.context().unwrap().function(),
+			"eglQueryDmaBufModifiersEXT"
+		);
+
+		let egl = Instance::new(
+			Mock::new().with_display_extensions("EGL_EXT_image_dma_buf_import_modifiers"),
+		);
+		let (display, _) = initialized(&egl);
+		assert_eq!(egl.query_dma_buf_formats(display), Ok(vec![]));
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn export_dma_buf() {
+		use std::io::{Read, Write};
+		use std::os::fd::AsFd;
+		use std::os::unix::net::UnixStream;
+
+		let (buffer, mut peer) = UnixStream::pair().unwrap();
+		peer.set_read_timeout(Some(std::time::Duration::from_secs(5)))
+			.unwrap();
+		let nv12 = DmaBufImage::new(64, 64, 0x3231564e)
+			.plane(buffer.as_fd(), 0, 64)
+			.plane(buffer.as_fd(), 64 * 64, 64);
+
+		let egl = Instance::new(Mock::new().with_display_extensions(
+			"EGL_KHR_image_base EGL_EXT_image_dma_buf_import EGL_EXT_image_dma_buf_import_modifiers",
+		));
+		let (display, _) = initialized(&egl);
+		let image = egl.import_dma_buf(display, &nv12).unwrap();
+		assert_eq!(
+			egl.export_dma_buf(display, image).unwrap_err(),
+			Error::ExtensionNotSupported("EGL_MESA_image_dma_buf_export")
+		);
+		egl.destroy_image_khr(display, image).unwrap();
+
+		let egl = Instance::new(Mock::new().with_display_extensions(
+			"EGL_KHR_image_base EGL_EXT_image_dma_buf_import EGL_EXT_image_dma_buf_import_modifiers EGL_MESA_image_dma_buf_export",
+		));
+		let (display, ctx) = initialized(&egl);
+		let image = egl
+			.import_dma_buf(display, &nv12.clone().with_modifier(2))
+			.unwrap();
+		let linear = egl.import_dma_buf(display, &nv12).unwrap();
+		drop(nv12);
+		drop(buffer);
+
+		// The imported buffer is kept by the image.
+		let export = egl.export_dma_buf(display, image).unwrap();
+		assert_eq!(export.fourcc, 0x3231564e);
+		assert_eq!(export.modifier, 2);
+		assert_eq!(export.planes.len(), 2);
+		assert_eq!(
+			(export.planes[1].offset, export.planes[1].stride),
+			(64 * 64, 64)
+		);
+
+		let mut fd = UnixStream::from(export.planes[1].fd.as_ref().unwrap().try_clone().unwrap());
+		fd.write_all(b"plane").unwrap();
+		let mut data = [0; 5];
+		peer.read_exact(&mut data).unwrap();
+		assert_eq!(&data, b"plane");
+		drop((fd, export));
+
+		// The modifier is invalid if the image was imported without any.
+		let export = egl.export_dma_buf(display, linear).unwrap();
+		assert_eq!(export.modifier, 0x00ff_ffff_ffff_ffff);
+
+		let buffer = unsafe { ClientBuffer::from_ptr(1 as EGLClientBuffer) };
+		let texture = egl
+			.create_image_khr(display, ctx, GL_TEXTURE_2D as Enum, buffer, &[NONE])
+			.unwrap();
+		assert_eq!(
+			egl.export_dma_buf(display, texture).unwrap_err(),
+			Error::BadMatch
+		);
+
+		egl.destroy_image_khr(display, image).unwrap();
+		let e = egl.export_dma_buf(display, image).unwrap_err();
+		assert_eq!(e, Error::BadParameter);
+		assert_eq!(
+			e.context().unwrap().function(),
+			"eglExportDMABUFImageQueryMESA"
+		);
+
+		// Destroying the last image closes the imported buffer.
+		drop(export);
+		egl.destroy_image_khr(display, linear).unwrap();
+		assert_eq!(peer.read(&mut data).unwrap(), 0);
+	}
+}
@@ -47,7 +47,7 @@
 //!     egl::NONE
 //!   ];
 //!
-//!   egl.create_context(display, config, None, &context_attributes);
+//!   egl.create_context(display, Some(config), None, &context_attributes);
 //!
 //!   Ok(())
 //! }
@@ -167,6 +167,7 @@ impl<T> Upcast<T> for T {
 /// rust-friendly access to it.
 pub struct Instance<T> {
 	api: T,
+	extensions: ExtensionCache,
 }
 
 impl<T> Instance<T> {
@@ -175,6 +176,7 @@ impl<T> Instance<T> {
 	pub fn cast_into<U: From<T>>(self) -> Instance<U> {
 		Instance {
 			api: self.api.into(),
+			extensions: self.extensions,
 		}
 	}
 
@@ -182,8 +184,11 @@ impl<T> Instance<T> {
 	#[inline(always)]
 	pub fn try_cast_into<U: TryFrom<T>>(self) -> Result<Instance<U>, Instance<U::Error>> {
 		match self.api.try_into() {
-			Ok(t) => Ok(Instance { api: t }),
-			Err(e) => Err(Instance { api: e }),
+			Ok(t) => Ok(Instance {
+				api: t,
+				extensions: self.extensions,
+			}),
+			Err(e) => Err(Instance::new(e)),
 		}
 	}
 
@@ -200,7 +205,10 @@ impl<T> Instance<T> {
 impl<T> Instance<T> {
 	#[inline(always)]
 	pub const fn new(api: T) -> Instance<T> {
-		Instance { api }
+		Instance {
+			api,
+			extensions: ExtensionCache::new(),
+		}
 	}
 }
 
@@ -218,6 +226,12 @@ impl<T> From<T> for Instance<T> {
 	}
 }
 
+// ------------------------------------------------------------------------------------------------
+// Constants
+// ------------------------------------------------------------------------------------------------
+
+include!("generated/enums.rs");
+
 // ------------------------------------------------------------------------------------------------
 // EGL 1.0
 // ------------------------------------------------------------------------------------------------
@@ -335,66 +349,8 @@ mod egl1_0 {
 	#[cfg(android)]
 	pub type NativeWindowType = *mut android_native_window_t;
 
-	pub const ALPHA_SIZE: Int = 0x3021;
-	pub const BAD_ACCESS: Int = 0x3002;
-	pub const BAD_ALLOC: Int = 0x3003;
-	pub const BAD_ATTRIBUTE: Int = 0x3004;
-	pub const BAD_CONFIG: Int = 0x3005;
-	pub const BAD_CONTEXT: Int = 0x3006;
-	pub const BAD_CURRENT_SURFACE: Int = 0x3007;
-	pub const BAD_DISPLAY: Int = 0x3008;
-	pub const BAD_MATCH: Int = 0x3009;
-	pub const BAD_NATIVE_PIXMAP: Int = 0x300A;
-	pub const BAD_NATIVE_WINDOW: Int = 0x300B;
-	pub const BAD_PARAMETER: Int = 0x300C;
-	pub const BAD_SURFACE: Int = 0x300D;
-	pub const BLUE_SIZE: Int = 0x3022;
-	pub const BUFFER_SIZE: Int = 0x3020;
-	pub const CONFIG_CAVEAT: Int = 0x3027;
-	pub const CONFIG_ID: Int = 0x3028;
-	pub const CORE_NATIVE_ENGINE: Int = 0x305B;
-	pub const DEPTH_SIZE: Int = 0x3025;
-	pub const DONT_CARE: Int = -1;
-	pub const DRAW: Int = 0x3059;
-	pub const EXTENSIONS: Int = 0x3055;
-	pub const FALSE: Boolean = 0;
-	pub const GREEN_SIZE: Int = 0x3023;
-	pub const HEIGHT: Int = 0x3056;
-	pub const LARGEST_PBUFFER: Int = 0x3058;
-	pub const LEVEL: Int = 0x3029;
-	pub const MAX_PBUFFER_HEIGHT: Int = 0x302A;
-	pub const MAX_PBUFFER_PIXELS: Int = 0x302B;
-	pub const MAX_PBUFFER_WIDTH: Int = 0x302C;
-	pub const NATIVE_RENDERABLE: Int = 0x302D;
-	pub const NATIVE_VISUAL_ID: Int = 0x302E;
-	pub const NATIVE_VISUAL_TYPE: Int = 0x302F;
-	pub const NONE: Int = 0x3038;
+	/// `NONE` terminator of `Attrib` lists.
 	pub const ATTRIB_NONE: Attrib = 0x3038;
-	pub const NON_CONFORMANT_CONFIG: Int = 0x3051;
-	pub const NOT_INITIALIZED: Int = 0x3001;
-	pub const NO_CONTEXT: EGLContext = 0 as EGLContext;
-	pub const NO_DISPLAY: EGLDisplay = 0 as EGLDisplay;
-	pub const NO_SURFACE: EGLSurface = 0 as EGLSurface;
-	pub const PBUFFER_BIT: Int = 0x0001;
-	pub const PIXMAP_BIT: Int = 0x0002;
-	pub const READ: Int = 0x305A;
-	pub const RED_SIZE: Int = 0x3024;
-	pub const SAMPLES: Int = 0x3031;
-	pub const SAMPLE_BUFFERS: Int = 0x3032;
-	pub const SLOW_CONFIG: Int = 0x3050;
-	pub const STENCIL_SIZE: Int = 0x3026;
-	pub const SUCCESS: Int = 0x3000;
-	pub const SURFACE_TYPE: Int = 0x3033;
-	pub const TRANSPARENT_BLUE_VALUE: Int = 0x3035;
-	pub const TRANSPARENT_GREEN_VALUE: Int = 0x3036;
-	pub const TRANSPARENT_RED_VALUE: Int = 0x3037;
-	pub const TRANSPARENT_RGB: Int = 0x3052;
-	pub const TRANSPARENT_TYPE: Int = 0x3034;
-	pub const TRUE: Boolean = 1;
-	pub const VENDOR: Int = 0x3053;
-	pub const VERSION: Int = 0x3054;
-	pub const WIDTH: Int = 0x3057;
-	pub const WINDOW_BIT: Int = 0x0004;
 
 	/// EGL errors.
 	#[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -448,6 +404,39 @@ mod egl1_0 {
 		/// contexts and reinitialise OpenGL ES state and objects to continue
 		/// rendering.
 		ContextLost,
+
+		/// An EGLStreamKHR argument does not name a valid stream
+		/// (`EGL_KHR_stream`).
+		BadStream,
+
+		/// A stream is not in a valid state for the requested operation
+		/// (`EGL_KHR_stream`).
+		BadState,
+
+		/// An EGLDeviceEXT argument does not name a valid device
+		/// (`EGL_EXT_device_base`).
+		BadDevice,
+
+		/// An EGLOutputLayerEXT argument does not name a valid output layer
+		/// (`EGL_EXT_output_base`).
+		BadOutputLayer,
+
+		/// An EGLOutputPortEXT argument does not name a valid output port
+		/// (`EGL_EXT_output_base`).
+		BadOutputPort,
+
+		/// The given EGL extension is not supported by the implementation or the
+		/// display connection.
+		///
+		/// This error is not reported by EGL itself, but by the extension
+		/// functions of this crate before calling into EGL.
+		ExtensionNotSupported(&'static str),
+
+		/// Unknown error code.
+		///
+		/// This is also used with the `SUCCESS` code when an EGL function
+		/// reports a failure without setting any error.
+		Other(Int),
 	}
 
 	impl std::error::Error for Error {
@@ -456,10 +445,17 @@ mod egl1_0 {
 		}
 	}
 
+	/// Value of `CONTEXT_LOST`, which is only defined by EGL 1.1 and later.
+	const CONTEXT_LOST_ERROR: Int = 0x300E;
+
 	impl Error {
-		pub fn native(&self) -> Int {
+		/// Returns the EGL error code of this error.
+		///
+		/// Returns `None` for errors detected by this crate that have no EGL
+		/// error code, such as [`Error::ExtensionNotSupported`].
+		pub fn native(&self) -> Option<Int> {
 			use Error::*;
-			match self {
+			Some(match self {
 				NotInitialized => NOT_INITIALIZED,
 				BadAccess => BAD_ACCESS,
 				BadAlloc => BAD_ALLOC,
@@ -473,8 +469,15 @@ mod egl1_0 {
 				BadParameter => BAD_PARAMETER,
 				BadNativePixmap => BAD_NATIVE_PIXMAP,
 				BadNativeWindow => BAD_NATIVE_WINDOW,
-				ContextLost => CONTEXT_LOST,
-			}
+				ContextLost => CONTEXT_LOST_ERROR,
+				BadStream => BAD_STREAM_KHR,
+				BadState => BAD_STATE_KHR,
+				BadDevice => BAD_DEVICE_EXT,
+				BadOutputLayer => BAD_OUTPUT_LAYER_EXT,
+				BadOutputPort => BAD_OUTPUT_PORT_EXT,
+				ExtensionNotSupported(_) => return None,
+				Other(e) => *e,
+			})
 		}
 
 		fn message(&self) -> &'static str {
@@ -493,14 +496,26 @@ mod egl1_0 {
 				BadParameter => "One or more argument values are invalid.",
 				BadNativePixmap => "A NativePixmapType argument does not refer to a valid native pixmap.",
 				BadNativeWindow => "A NativeWindowType argument does not refer to a valid native window.",
-				ContextLost => "A power management event has occurred. The application must destroy all contexts and reinitialise OpenGL ES state and objects to continue rendering."
+				ContextLost => "A power management event has occurred. The application must destroy all contexts and reinitialise OpenGL ES state and objects to continue rendering.",
+				BadStream => "An EGLStreamKHR argument does not name a valid stream.",
+				BadState => "A stream is not in a valid state for the requested operation.",
+				BadDevice => "An EGLDeviceEXT argument does not name a valid device.",
+				BadOutputLayer => "An EGLOutputLayerEXT argument does not name a valid output layer.",
+				BadOutputPort => "An EGLOutputPortEXT argument does not name a valid output port.",
+				ExtensionNotSupported(_) => "The EGL extension is not supported.",
+				Other(SUCCESS) => "An EGL function failed without reporting any error.",
+				Other(_) => "Unknown EGL error."
 			}
 		}
 	}
 
-	impl From<Error> for Int {
-		fn from(e: Error) -> Int {
-			e.native()
+	impl TryFrom<Error> for Int {
+		type Error = Error;
+
+		/// Returns the EGL error code of the given error, or the error itself
+		/// if it has none (see [`Error::native`]).
+		fn try_from(e: Error) -> Result<Int, Error> {
+			e.native().ok_or(e)
 		}
 	}
 
@@ -523,15 +538,27 @@ mod egl1_0 {
 				BAD_PARAMETER => Ok(BadParameter),
 				BAD_NATIVE_PIXMAP => Ok(BadNativePixmap),
 				BAD_NATIVE_WINDOW => Ok(BadNativeWindow),
-				CONTEXT_LOST => Ok(ContextLost),
-				_ => Err(e),
+				CONTEXT_LOST_ERROR => Ok(ContextLost),
+				BAD_STREAM_KHR => Ok(BadStream),
+				BAD_STATE_KHR => Ok(BadState),
+				BAD_DEVICE_EXT => Ok(BadDevice),
+				BAD_OUTPUT_LAYER_EXT => Ok(BadOutputLayer),
+				BAD_OUTPUT_PORT_EXT => Ok(BadOutputPort),
+				SUCCESS => Err(e),
+				_ => Ok(Other(e)),
 			}
 		}
 	}
 
 	impl fmt::Display for Error {
 		fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-			self.message().fmt(f)
+			match self {
+				Error::ExtensionNotSupported(name) => {
+					write!(f, "The EGL extension {} is not supported.", name)
+				}
+				Error::Other(e) if *e != SUCCESS => write!(f, "Unknown EGL error {:#x}.", e),
+				_ => self.message().fmt(f),
+			}
 		}
 	}
 
@@ -539,7 +566,7 @@ mod egl1_0 {
 		if attrib_list.last() == Some(&NONE) {
 			Ok(())
 		} else {
-			Err(Error::BadParameter)
+			Err(Error::BadParameter.without_context())
 		}
 	}
 
@@ -547,7 +574,7 @@ mod egl1_0 {
 		if attrib_list.last() == Some(&ATTRIB_NONE) {
 			Ok(())
 		} else {
-			Err(Error::BadParameter)
+			Err(Error::BadParameter.without_context())
 		}
 	}
 
@@ -579,7 +606,11 @@ mod egl1_0 {
 				{
 					Ok(count as usize)
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglChooseConfig")
+							.with_display(display)
+							.with_int_list(attrib_list),
+					))
 				}
 			}
 		}
@@ -643,7 +674,11 @@ mod egl1_0 {
 						configs.set_len(count as usize);
 						Ok(())
 					} else {
-						Err(self.get_error().unwrap())
+						Err(self.error_in(
+							ErrorContext::new("eglChooseConfig")
+								.with_display(display)
+								.with_int_list(attrib_list),
+						))
 					}
 				}
 			}
@@ -699,23 +734,49 @@ mod egl1_0 {
 				{
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglCopyBuffers")
+							.with_display(display)
+							.with_arg("surface", &surface)
+							.with_arg("target", &target),
+					))
 				}
 			}
 		}
 
 		/// Create a new EGL rendering context.
 		///
+		/// If no `config` is given, the context is created without frame buffer
+		/// configuration (`NO_CONFIG_KHR`), and can be bound to any compatible
+		/// surface. This requires the `EGL_KHR_no_config_context` (or
+		/// `EGL_MESA_configless_context`) display extension, and returns
+		/// [`Error::ExtensionNotSupported`] otherwise.
+		///
 		/// This will return a `BadParameter` error if `attrib_list` is not a valid
 		/// attributes list (if it does not terminate with `NONE`).
 		pub fn create_context(
 			&self,
 			display: Display,
-			config: Config,
+			config: Option<Config>,
 			share_context: Option<Context>,
 			attrib_list: &[Int],
 		) -> Result<Context, Error> {
 			check_int_list(attrib_list)?;
+			let config_ptr = match config {
+				Some(config) => config.as_ptr(),
+				None => {
+					let extensions = self.display_extensions(display)?;
+					if !extensions.has("EGL_KHR_no_config_context")
+						&& !extensions.has("EGL_MESA_configless_context")
+					{
+						return Err(Error::ExtensionNotSupported("EGL_KHR_no_config_context")
+							.without_context());
+					}
+
+					NO_CONFIG_KHR
+				}
+			};
+
 			unsafe {
 				let share_context = match share_context {
 					Some(share_context) => share_context.as_ptr(),
@@ -724,7 +785,7 @@ mod egl1_0 {
 
 				let context = self.api.eglCreateContext(
 					display.as_ptr(),
-					config.as_ptr(),
+					config_ptr,
 					share_context,
 					attrib_list.as_ptr(),
 				);
@@ -732,7 +793,13 @@ mod egl1_0 {
 				if context != NO_CONTEXT {
 					Ok(Context(context))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglCreateContext")
+							.with_display(display)
+							.with_arg("config", &config)
+							.with_arg("share_context", &share_context)
+							.with_int_list(attrib_list),
+					))
 				}
 			}
 		}
@@ -758,7 +825,12 @@ mod egl1_0 {
 				if surface != NO_SURFACE {
 					Ok(Surface(surface))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglCreatePbufferSurface")
+							.with_display(display)
+							.with_arg("config", &config)
+							.with_int_list(attrib_list),
+					))
 				}
 			}
 		}
@@ -790,7 +862,13 @@ mod egl1_0 {
 			if surface != NO_SURFACE {
 				Ok(Surface(surface))
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglCreatePixmapSurface")
+						.with_display(display)
+						.with_arg("config", &config)
+						.with_arg("pixmap", &pixmap)
+						.with_int_list(attrib_list),
+				))
 			}
 		}
 
@@ -810,7 +888,7 @@ mod egl1_0 {
 			window: NativeWindowType,
 			attrib_list: Option<&[Int]>,
 		) -> Result<Surface, Error> {
-			let attrib_list = match attrib_list {
+			let attrib_list_ptr = match attrib_list {
 				Some(attrib_list) => {
 					check_int_list(attrib_list)?;
 					attrib_list.as_ptr()
@@ -822,13 +900,21 @@ mod egl1_0 {
 				display.as_ptr(),
 				config.as_ptr(),
 				window,
-				attrib_list,
+				attrib_list_ptr,
 			);
 
 			if surface != NO_SURFACE {
 				Ok(Surface(surface))
 			} else {
-				Err(self.get_error().unwrap())
+				let mut context = ErrorContext::new("eglCreateWindowSurface")
+					.with_display(display)
+					.with_arg("config", &config)
+					.with_arg("window", &window);
+				if let Some(attrib_list) = attrib_list {
+					context = context.with_int_list(attrib_list);
+				}
+
+				Err(self.error_in(context))
 			}
 		}
 
@@ -838,7 +924,11 @@ mod egl1_0 {
 				if self.api.eglDestroyContext(display.as_ptr(), ctx.as_ptr()) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglDestroyContext")
+							.with_display(display)
+							.with_arg("ctx", &ctx),
+					))
 				}
 			}
 		}
@@ -853,7 +943,11 @@ mod egl1_0 {
 				{
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglDestroySurface")
+							.with_display(display)
+							.with_arg("surface", &surface),
+					))
 				}
 			}
 		}
@@ -876,7 +970,12 @@ mod egl1_0 {
 				{
 					Ok(value)
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglGetConfigAttrib")
+							.with_display(display)
+							.with_arg("config", &config)
+							.with_arg("attribute", &attribute),
+					))
 				}
 			}
 		}
@@ -911,7 +1010,7 @@ mod egl1_0 {
 				{
 					Ok(count as usize)
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglGetConfigs").with_display(display)))
 				}
 			}
 		}
@@ -961,7 +1060,7 @@ mod egl1_0 {
 						configs.set_len(count as usize);
 						Ok(())
 					} else {
-						Err(self.get_error().unwrap())
+						Err(self.error_in(ErrorContext::new("eglGetConfigs").with_display(display)))
 					}
 				}
 			}
@@ -1025,14 +1124,15 @@ mod egl1_0 {
 		/// returning a `Result` when necessary, this function may only return `None`
 		/// from the point of view of a user.
 		pub fn get_error(&self) -> Option<Error> {
-			unsafe {
-				let e = self.api.eglGetError();
-				if e == SUCCESS {
-					None
-				} else {
-					Some(e.try_into().unwrap())
-				}
-			}
+			unsafe { self.api.eglGetError().try_into().ok() }
+		}
+
+		/// Return the error of a failed EGL function call.
+		///
+		/// Some implementations may report a failure without setting any error,
+		/// in which case `Error::Other(SUCCESS)` is returned.
+		pub(crate) fn last_error(&self) -> Error {
+			self.get_error().unwrap_or(Error::Other(SUCCESS))
 		}
 
 		/// Return a GL or an EGL extension function.
@@ -1040,12 +1140,7 @@ mod egl1_0 {
 			unsafe {
 				let string = CString::new(procname).unwrap();
 
-				let addr = self.api.eglGetProcAddress(string.as_ptr());
-				if !(addr as *const ()).is_null() {
-					Some(addr)
-				} else {
-					None
-				}
+				self.api.eglGetProcAddress(string.as_ptr())
 			}
 		}
 
@@ -1062,7 +1157,7 @@ mod egl1_0 {
 				{
 					Ok((major, minor))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglInitialize").with_display(display)))
 				}
 			}
 		}
@@ -1092,7 +1187,13 @@ mod egl1_0 {
 				if self.api.eglMakeCurrent(display.as_ptr(), draw, read, ctx) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglMakeCurrent")
+							.with_display(display)
+							.with_arg("draw", &draw)
+							.with_arg("read", &read)
+							.with_arg("ctx", &ctx),
+					))
 				}
 			}
 		}
@@ -1113,7 +1214,12 @@ mod egl1_0 {
 				{
 					Ok(value)
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglQueryContext")
+							.with_display(display)
+							.with_arg("ctx", &ctx)
+							.with_arg("attribute", &attribute),
+					))
 				}
 			}
 		}
@@ -1136,7 +1242,11 @@ mod egl1_0 {
 				if !c_str.is_null() {
 					Ok(CStr::from_ptr(c_str))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglQueryString")
+							.with_arg("display", &display)
+							.with_arg("name", &name),
+					))
 				}
 			}
 		}
@@ -1159,7 +1269,12 @@ mod egl1_0 {
 				{
 					Ok(value)
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglQuerySurface")
+							.with_display(display)
+							.with_arg("surface", &surface)
+							.with_arg("attribute", &attribute),
+					))
 				}
 			}
 		}
@@ -1170,7 +1285,11 @@ mod egl1_0 {
 				if self.api.eglSwapBuffers(display.as_ptr(), surface.as_ptr()) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglSwapBuffers")
+							.with_display(display)
+							.with_arg("surface", &surface),
+					))
 				}
 			}
 		}
@@ -1179,9 +1298,10 @@ mod egl1_0 {
 		pub fn terminate(&self, display: Display) -> Result<(), Error> {
 			unsafe {
 				if self.api.eglTerminate(display.as_ptr()) == TRUE {
+					self.extensions.forget_display(display);
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglTerminate").with_display(display)))
 				}
 			}
 		}
@@ -1192,7 +1312,7 @@ mod egl1_0 {
 				if self.api.eglWaitGL() == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglWaitGL")))
 				}
 			}
 		}
@@ -1203,7 +1323,8 @@ mod egl1_0 {
 				if self.api.eglWaitNative(engine) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self
+						.error_in(ErrorContext::new("eglWaitNative").with_arg("engine", &engine)))
 				}
 			}
 		}
@@ -1221,21 +1342,6 @@ pub use egl1_0::*;
 mod egl1_1 {
 	use super::*;
 
-	pub const BACK_BUFFER: Int = 0x3084;
-	pub const BIND_TO_TEXTURE_RGB: Int = 0x3039;
-	pub const BIND_TO_TEXTURE_RGBA: Int = 0x303A;
-	pub const CONTEXT_LOST: Int = 0x300E;
-	pub const MIN_SWAP_INTERVAL: Int = 0x303B;
-	pub const MAX_SWAP_INTERVAL: Int = 0x303C;
-	pub const MIPMAP_TEXTURE: Int = 0x3082;
-	pub const MIPMAP_LEVEL: Int = 0x3083;
-	pub const NO_TEXTURE: Int = 0x305C;
-	pub const TEXTURE_2D: Int = 0x305F;
-	pub const TEXTURE_FORMAT: Int = 0x3080;
-	pub const TEXTURE_RGB: Int = 0x305D;
-	pub const TEXTURE_RGBA: Int = 0x305E;
-	pub const TEXTURE_TARGET: Int = 0x3081;
-
 	impl<T: api::EGL1_1> Instance<T> {
 		/// Defines a two-dimensional texture image.
 		pub fn bind_tex_image(
@@ -1252,7 +1358,12 @@ mod egl1_1 {
 				{
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglBindTexImage")
+							.with_display(display)
+							.with_arg("surface", &surface)
+							.with_arg("buffer", &buffer),
+					))
 				}
 			}
 		}
@@ -1272,7 +1383,12 @@ mod egl1_1 {
 				{
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglReleaseTexImage")
+							.with_display(display)
+							.with_arg("surface", &surface)
+							.with_arg("buffer", &buffer),
+					))
 				}
 			}
 		}
@@ -1293,7 +1409,13 @@ mod egl1_1 {
 				{
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglSurfaceAttrib")
+							.with_display(display)
+							.with_arg("surface", &surface)
+							.with_arg("attribute", &attribute)
+							.with_arg("value", &value),
+					))
 				}
 			}
 		}
@@ -1305,16 +1427,17 @@ mod egl1_1 {
 				if self.api.eglSwapInterval(display.as_ptr(), interval) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglSwapInterval")
+							.with_display(display)
+							.with_arg("interval", &interval),
+					))
 				}
 			}
 		}
 	}
 }
 
-#[cfg(feature = "1_1")]
-pub use egl1_1::*;
-
 // ------------------------------------------------------------------------------------------------
 // EGL 1.2
 // ------------------------------------------------------------------------------------------------
@@ -1346,35 +1469,51 @@ mod egl1_2 {
 		}
 	}
 
-	pub const ALPHA_FORMAT: Int = 0x3088;
-	pub const ALPHA_FORMAT_NONPRE: Int = 0x308B;
-	pub const ALPHA_FORMAT_PRE: Int = 0x308C;
-	pub const ALPHA_MASK_SIZE: Int = 0x303E;
-	pub const BUFFER_PRESERVED: Int = 0x3094;
-	pub const BUFFER_DESTROYED: Int = 0x3095;
-	pub const CLIENT_APIS: Int = 0x308D;
-	pub const COLORSPACE: Int = 0x3087;
-	pub const COLORSPACE_sRGB: Int = 0x3089;
-	pub const COLORSPACE_LINEAR: Int = 0x308A;
-	pub const COLOR_BUFFER_TYPE: Int = 0x303F;
-	pub const CONTEXT_CLIENT_TYPE: Int = 0x3097;
-	pub const DISPLAY_SCALING: Int = 10000;
-	pub const HORIZONTAL_RESOLUTION: Int = 0x3090;
-	pub const LUMINANCE_BUFFER: Int = 0x308F;
-	pub const LUMINANCE_SIZE: Int = 0x303D;
-	pub const OPENGL_ES_BIT: Int = 0x0001;
-	pub const OPENVG_BIT: Int = 0x0002;
-	pub const OPENGL_ES_API: Enum = 0x30A0;
-	pub const OPENVG_API: Enum = 0x30A1;
-	pub const OPENVG_IMAGE: Int = 0x3096;
-	pub const PIXEL_ASPECT_RATIO: Int = 0x3092;
-	pub const RENDERABLE_TYPE: Int = 0x3040;
-	pub const RENDER_BUFFER: Int = 0x3086;
-	pub const RGB_BUFFER: Int = 0x308E;
-	pub const SINGLE_BUFFER: Int = 0x3085;
-	pub const SWAP_BEHAVIOR: Int = 0x3093;
-	pub const UNKNOWN: Int = -1;
-	pub const VERTICAL_RESOLUTION: Int = 0x3091;
+	// Sync objects and images are core in EGL 1.5, but also provided by the
+	// `EGL_KHR_fence_sync` and `EGL_KHR_image_base` extensions since EGL 1.2.
+	pub type Time = u64;
+	pub type EGLSync = *mut c_void;
+	pub type EGLImage = *mut c_void;
+
+	#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+	pub struct Sync(pub(crate) EGLSync);
+
+	impl Sync {
+		/// Creates a new sync form its EGL pointer.
+		///
+		/// # Safety
+		///
+		/// `ptr` must be a valid `EGLSync` pointer.
+		#[inline]
+		pub unsafe fn from_ptr(ptr: EGLSync) -> Sync {
+			Sync(ptr)
+		}
+
+		#[inline]
+		pub fn as_ptr(&self) -> EGLSync {
+			self.0
+		}
+	}
+
+	#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+	pub struct Image(pub(crate) EGLImage);
+
+	impl Image {
+		/// Creates a new image form its EGL pointer.
+		///
+		/// # Safety
+		///
+		/// `ptr` must be a valid `EGLImage` pointer.
+		#[inline]
+		pub unsafe fn from_ptr(ptr: EGLImage) -> Image {
+			Image(ptr)
+		}
+
+		#[inline]
+		pub fn as_ptr(&self) -> EGLImage {
+			self.0
+		}
+	}
 
 	impl<T: api::EGL1_2> Instance<T> {
 		/// Set the current rendering API.
@@ -1383,7 +1522,7 @@ mod egl1_2 {
 				if self.api.eglBindAPI(api) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglBindAPI").with_arg("api", &api)))
 				}
 			}
 		}
@@ -1418,7 +1557,14 @@ mod egl1_2 {
 				if surface != NO_SURFACE {
 					Ok(Surface::from_ptr(surface))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglCreatePbufferFromClientBuffer")
+							.with_display(display)
+							.with_arg("buffer_type", &buffer_type)
+							.with_arg("buffer", &buffer)
+							.with_arg("config", &config)
+							.with_int_list(attrib_list),
+					))
 				}
 			}
 		}
@@ -1429,7 +1575,7 @@ mod egl1_2 {
 				if self.api.eglReleaseThread() == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglReleaseThread")))
 				}
 			}
 		}
@@ -1440,7 +1586,7 @@ mod egl1_2 {
 				if self.api.eglWaitClient() == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(ErrorContext::new("eglWaitClient")))
 				}
 			}
 		}
@@ -1454,26 +1600,7 @@ pub use egl1_2::*;
 // EGL 1.3
 // ------------------------------------------------------------------------------------------------
 
-#[cfg(feature = "1_3")]
-mod egl1_3 {
-	use super::*;
-
-	pub const CONFORMANT: Int = 0x3042;
-	pub const CONTEXT_CLIENT_VERSION: Int = 0x3098;
-	pub const MATCH_NATIVE_PIXMAP: Int = 0x3041;
-	pub const OPENGL_ES2_BIT: Int = 0x0004;
-	pub const VG_ALPHA_FORMAT: Int = 0x3088;
-	pub const VG_ALPHA_FORMAT_NONPRE: Int = 0x308B;
-	pub const VG_ALPHA_FORMAT_PRE: Int = 0x308C;
-	pub const VG_ALPHA_FORMAT_PRE_BIT: Int = 0x0040;
-	pub const VG_COLORSPACE: Int = 0x3087;
-	pub const VG_COLORSPACE_sRGB: Int = 0x3089;
-	pub const VG_COLORSPACE_LINEAR: Int = 0x308A;
-	pub const VG_COLORSPACE_LINEAR_BIT: Int = 0x0020;
-}
-
-#[cfg(feature = "1_3")]
-pub use egl1_3::*;
+// EGL 1.3 only defines new constants.
 
 // ------------------------------------------------------------------------------------------------
 // EGL 1.4
@@ -1483,15 +1610,6 @@ pub use egl1_3::*;
 mod egl1_4 {
 	use super::*;
 
-	pub const DEFAULT_DISPLAY: NativeDisplayType = 0 as NativeDisplayType;
-	pub const MULTISAMPLE_RESOLVE_BOX_BIT: Int = 0x0200;
-	pub const MULTISAMPLE_RESOLVE: Int = 0x3099;
-	pub const MULTISAMPLE_RESOLVE_DEFAULT: Int = 0x309A;
-	pub const MULTISAMPLE_RESOLVE_BOX: Int = 0x309B;
-	pub const OPENGL_API: Enum = 0x30A2;
-	pub const OPENGL_BIT: Int = 0x0008;
-	pub const SWAP_BEHAVIOR_PRESERVED_BIT: Int = 0x0400;
-
 	impl<T: api::EGL1_4> Instance<T> {
 		/// Return the current EGL rendering context.
 		pub fn get_current_context(&self) -> Option<Context> {
@@ -1508,9 +1626,6 @@ mod egl1_4 {
 	}
 }
 
-#[cfg(feature = "1_4")]
-pub use egl1_4::*;
-
 // ------------------------------------------------------------------------------------------------
 // EGL 1.5
 // ------------------------------------------------------------------------------------------------
@@ -1519,94 +1634,6 @@ pub use egl1_4::*;
 mod egl1_5 {
 	use super::*;
 
-	pub type Time = u64;
-	pub type EGLSync = *mut c_void;
-	pub type EGLImage = *mut c_void;
-
-	#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-	pub struct Sync(EGLSync);
-
-	impl Sync {
-		/// Creates a new sync form its EGL pointer.
-		///
-		/// # Safety
-		///
-		/// `ptr` must be a valid `EGLSync` pointer.
-		#[inline]
-		pub unsafe fn from_ptr(ptr: EGLSync) -> Sync {
-			Sync(ptr)
-		}
-
-		#[inline]
-		pub fn as_ptr(&self) -> EGLSync {
-			self.0
-		}
-	}
-
-	#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-	pub struct Image(EGLImage);
-
-	impl Image {
-		/// Creates a new image form its EGL pointer.
-		///
-		/// # Safety
-		///
-		/// `ptr` must be a valid `EGLImage` pointer.
-		#[inline]
-		pub unsafe fn from_ptr(ptr: EGLImage) -> Image {
-			Image(ptr)
-		}
-
-		#[inline]
-		pub fn as_ptr(&self) -> EGLImage {
-			self.0
-		}
-	}
-
-	pub const CONTEXT_MAJOR_VERSION: Int = 0x3098;
-	pub const CONTEXT_MINOR_VERSION: Int = 0x30FB;
-	pub const CONTEXT_OPENGL_PROFILE_MASK: Int = 0x30FD;
-	pub const CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: Int = 0x31BD;
-	pub const NO_RESET_NOTIFICATION: Int = 0x31BE;
-	pub const LOSE_CONTEXT_ON_RESET: Int = 0x31BF;
-	pub const CONTEXT_OPENGL_CORE_PROFILE_BIT: Int = 0x00000001;
-	pub const CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: Int = 0x00000002;
-	pub const CONTEXT_OPENGL_DEBUG: Int = 0x31B0;
-	pub const CONTEXT_OPENGL_FORWARD_COMPATIBLE: Int = 0x31B1;
-	pub const CONTEXT_OPENGL_ROBUST_ACCESS: Int = 0x31B2;
-	pub const OPENGL_ES3_BIT: Int = 0x00000040;
-	pub const CL_EVENT_HANDLE: Int = 0x309C;
-	pub const SYNC_CL_EVENT: Int = 0x30FE;
-	pub const SYNC_CL_EVENT_COMPLETE: Int = 0x30FF;
-	pub const SYNC_PRIOR_COMMANDS_COMPLETE: Int = 0x30F0;
-	pub const SYNC_TYPE: Int = 0x30F7;
-	pub const SYNC_STATUS: Int = 0x30F1;
-	pub const SYNC_CONDITION: Int = 0x30F8;
-	pub const SIGNALED: Int = 0x30F2;
-	pub const UNSIGNALED: Int = 0x30F3;
-	pub const SYNC_FLUSH_COMMANDS_BIT: Int = 0x0001;
-	pub const FOREVER: u64 = 0xFFFFFFFFFFFFFFFFu64;
-	pub const TIMEOUT_EXPIRED: Int = 0x30F5;
-	pub const CONDITION_SATISFIED: Int = 0x30F6;
-	pub const NO_SYNC: EGLSync = 0 as EGLSync;
-	pub const SYNC_FENCE: Int = 0x30F9;
-	pub const GL_COLORSPACE: Int = 0x309D;
-	pub const GL_COLORSPACE_SRGB: Int = 0x3089;
-	pub const GL_COLORSPACE_LINEAR: Int = 0x308A;
-	pub const GL_RENDERBUFFER: Int = 0x30B9;
-	pub const GL_TEXTURE_2D: Int = 0x30B1;
-	pub const GL_TEXTURE_LEVEL: Int = 0x30BC;
-	pub const GL_TEXTURE_3D: Int = 0x30B2;
-	pub const GL_TEXTURE_ZOFFSET: Int = 0x30BD;
-	pub const GL_TEXTURE_CUBE_MAP_POSITIVE_X: Int = 0x30B3;
-	pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_X: Int = 0x30B4;
-	pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Y: Int = 0x30B5;
-	pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Y: Int = 0x30B6;
-	pub const GL_TEXTURE_CUBE_MAP_POSITIVE_Z: Int = 0x30B7;
-	pub const GL_TEXTURE_CUBE_MAP_NEGATIVE_Z: Int = 0x30B8;
-	pub const IMAGE_PRESERVED: Int = 0x30D2;
-	pub const NO_IMAGE: EGLImage = 0 as EGLImage;
-
 	impl<T: api::EGL1_5> Instance<T> {
 		/// Create a new EGL sync object.
 		///
@@ -1634,7 +1661,12 @@ mod egl1_5 {
 			if sync != NO_SYNC {
 				Ok(Sync(sync))
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglCreateSync")
+						.with_display(display)
+						.with_arg("ty", &ty)
+						.with_attrib_list(attrib_list),
+				))
 			}
 		}
 
@@ -1648,7 +1680,11 @@ mod egl1_5 {
 			if self.api.eglDestroySync(display.as_ptr(), sync.as_ptr()) == TRUE {
 				Ok(())
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglDestroySync")
+						.with_display(display)
+						.with_arg("sync", &sync),
+				))
 			}
 		}
 
@@ -1671,7 +1707,13 @@ mod egl1_5 {
 			if status != FALSE as Int {
 				Ok(status)
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglClientWaitSync")
+						.with_display(display)
+						.with_arg("sync", &sync)
+						.with_arg("flags", &flags)
+						.with_arg("timeout", &timeout),
+				))
 			}
 		}
 
@@ -1697,7 +1739,12 @@ mod egl1_5 {
 			{
 				Ok(value)
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglGetSyncAttrib")
+						.with_display(display)
+						.with_arg("sync", &sync)
+						.with_arg("attribute", &attribute),
+				))
 			}
 		}
 
@@ -1728,7 +1775,14 @@ mod egl1_5 {
 				if image != NO_IMAGE {
 					Ok(Image(image))
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglCreateImage")
+							.with_display(display)
+							.with_arg("ctx", &ctx)
+							.with_arg("target", &target)
+							.with_arg("buffer", &buffer)
+							.with_attrib_list(attrib_list),
+					))
 				}
 			}
 		}
@@ -1739,7 +1793,11 @@ mod egl1_5 {
 				if self.api.eglDestroyImage(display.as_ptr(), image.as_ptr()) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglDestroyImage")
+							.with_display(display)
+							.with_arg("image", &image),
+					))
 				}
 			}
 		}
@@ -1776,7 +1834,12 @@ mod egl1_5 {
 			if display != NO_DISPLAY {
 				Ok(Display::from_ptr(display))
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglGetPlatformDisplay")
+						.with_arg("platform", &platform)
+						.with_arg("native_display", &native_display)
+						.with_attrib_list(attrib_list),
+				))
 			}
 		}
 
@@ -1813,7 +1876,13 @@ mod egl1_5 {
 			if surface != NO_SURFACE {
 				Ok(Surface::from_ptr(surface))
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglCreatePlatformWindowSurface")
+						.with_display(display)
+						.with_arg("config", &config)
+						.with_arg("native_window", &native_window)
+						.with_attrib_list(attrib_list),
+				))
 			}
 		}
 
@@ -1850,7 +1919,13 @@ mod egl1_5 {
 			if surface != NO_SURFACE {
 				Ok(Surface::from_ptr(surface))
 			} else {
-				Err(self.get_error().unwrap())
+				Err(self.error_in(
+					ErrorContext::new("eglCreatePlatformPixmapSurface")
+						.with_display(display)
+						.with_arg("config", &config)
+						.with_arg("native_pixmap", &native_pixmap)
+						.with_attrib_list(attrib_list),
+				))
 			}
 		}
 
@@ -1863,15 +1938,153 @@ mod egl1_5 {
 				if self.api.eglWaitSync(display.as_ptr(), sync.as_ptr(), flags) == TRUE {
 					Ok(())
 				} else {
-					Err(self.get_error().unwrap())
+					Err(self.error_in(
+						ErrorContext::new("eglWaitSync")
+							.with_display(display)
+							.with_arg("sync", &sync)
+							.with_arg("flags", &flags),
+					))
 				}
 			}
 		}
 	}
 }
 
-#[cfg(feature = "1_5")]
-pub use egl1_5::*;
+// ------------------------------------------------------------------------------------------------
+// Configuration attributes
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_0")]
+mod config;
+
+#[cfg(feature = "1_0")]
+pub use config::*;
+
+// ------------------------------------------------------------------------------------------------
+// Context attributes
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_2")]
+mod context;
+
+#[cfg(feature = "1_2")]
+pub use context::*;
+
+// ------------------------------------------------------------------------------------------------
+// Owned objects
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_0")]
+mod owned;
+
+#[cfg(feature = "1_0")]
+pub use owned::*;
+
+// ------------------------------------------------------------------------------------------------
+// Initialized displays
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_0")]
+mod display;
+
+#[cfg(feature = "1_0")]
+pub use display::*;
+
+// ------------------------------------------------------------------------------------------------
+// Scoped bindings
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_4")]
+mod current;
+
+#[cfg(feature = "1_4")]
+pub use current::*;
+
+// ------------------------------------------------------------------------------------------------
+// Mock implementation
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "mock")]
+mod mock;
+
+#[cfg(feature = "mock")]
+pub use mock::*;
+
+// ------------------------------------------------------------------------------------------------
+// Tracing
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "trace")]
+mod trace;
+
+#[cfg(feature = "trace")]
+pub use trace::*;
+
+// ------------------------------------------------------------------------------------------------
+// Validation
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "validation")]
+mod validation;
+
+#[cfg(feature = "validation")]
+pub use validation::*;
+
+// -------------------------------------------------------------------------------------------------
+// Error context
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_0")]
+mod error;
+
+#[cfg(feature = "1_0")]
+pub use error::*;
+
+// -------------------------------------------------------------------------------------------------
+// Extensions
+// -------------------------------------------------------------------------------------------------
+
+mod extensions;
+
+pub use extensions::*;
+
+// -------------------------------------------------------------------------------------------------
+// Extension functions
+// -------------------------------------------------------------------------------------------------
+
+mod ext;
+
+pub use ext::*;
+
+// -------------------------------------------------------------------------------------------------
+// Devices
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_0")]
+mod device;
+
+#[cfg(feature = "1_0")]
+pub use device::*;
+
+// -------------------------------------------------------------------------------------------------
+// Images
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_2")]
+mod image;
+
+#[cfg(feature = "1_2")]
+pub use image::*;
+
+// -------------------------------------------------------------------------------------------------
+// Sync objects
+// -------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "1_2")]
+mod sync;
+
+#[cfg(feature = "1_2")]
+pub use sync::*;
 
 // -------------------------------------------------------------------------------------------------
 // FFI
@@ -2496,157 +2709,4 @@ macro_rules! api {
 	}
 }
 
-api! {
-	EGL1_0 : "1_0" {
-		fn eglChooseConfig(
-			display: EGLDisplay,
-			attrib_list: *const Int,
-			configs: *mut EGLConfig,
-			config_size: Int,
-			num_config: *mut Int
-		) -> Boolean;
-		fn eglCopyBuffers(
-			display: EGLDisplay,
-			surface: EGLSurface,
-			target: NativePixmapType
-		) -> Boolean;
-		fn eglCreateContext(
-			display: EGLDisplay,
-			config: EGLConfig,
-			share_context: EGLContext,
-			attrib_list: *const Int
-		) -> EGLContext;
-		fn eglCreatePbufferSurface(
-			display: EGLDisplay,
-			config: EGLConfig,
-			attrib_list: *const Int
-		) -> EGLSurface;
-		fn eglCreatePixmapSurface(
-			display: EGLDisplay,
-			config: EGLConfig,
-			pixmap: NativePixmapType,
-			attrib_list: *const Int
-		) -> EGLSurface;
-		fn eglCreateWindowSurface(
-			display: EGLDisplay,
-			config: EGLConfig,
-			win: NativeWindowType,
-			attrib_list: *const Int
-		) -> EGLSurface;
-		fn eglDestroyContext(display: EGLDisplay, ctx: EGLContext) -> Boolean;
-		fn eglDestroySurface(display: EGLDisplay, surface: EGLSurface) -> Boolean;
-		fn eglGetConfigAttrib(
-			display: EGLDisplay,
-			config: EGLConfig,
-			attribute: Int,
-			value: *mut Int
-		) -> Boolean;
-		fn eglGetConfigs(
-			display: EGLDisplay,
-			configs: *mut EGLConfig,
-			config_size: Int,
-			num_config: *mut Int
-		) -> Boolean;
-		fn eglGetCurrentDisplay() -> EGLDisplay;
-		fn eglGetCurrentSurface(readdraw: Int) -> EGLSurface;
-		fn eglGetDisplay(display_id: NativeDisplayType) -> EGLDisplay;
-		fn eglGetError() -> Int;
-		fn eglGetProcAddress(procname: *const c_char) -> extern "system" fn();
-		fn eglInitialize(display: EGLDisplay, major: *mut Int, minor: *mut Int) -> Boolean;
-		fn eglMakeCurrent(
-			display: EGLDisplay,
-			draw: EGLSurface,
-			read: EGLSurface,
-			ctx: EGLContext
-		) -> Boolean;
-		fn eglQueryContext(
-			display: EGLDisplay,
-			ctx: EGLContext,
-			attribute: Int,
-			value: *mut Int
-		) -> Boolean;
-		fn eglQueryString(display: EGLDisplay, name: Int) -> *const c_char;
-		fn eglQuerySurface(
-			display: EGLDisplay,
-			surface: EGLSurface,
-			attribute: Int,
-			value: *mut Int
-		) -> Boolean;
-		fn eglSwapBuffers(display: EGLDisplay, surface: EGLSurface) -> Boolean;
-		fn eglTerminate(display: EGLDisplay) -> Boolean;
-		fn eglWaitGL() -> Boolean;
-		fn eglWaitNative(engine: Int) -> Boolean;
-	},
-
-	EGL1_1 : "1_1" {
-		fn eglBindTexImage(display: EGLDisplay, surface: EGLSurface, buffer: Int) -> Boolean;
-		fn eglReleaseTexImage(display: EGLDisplay, surface: EGLSurface, buffer: Int) -> Boolean;
-		fn eglSurfaceAttrib(
-			display: EGLDisplay,
-			surface: EGLSurface,
-			attribute: Int,
-			value: Int
-		) -> Boolean;
-		fn eglSwapInterval(display: EGLDisplay, interval: Int) -> Boolean;
-	},
-
-	EGL1_2 : "1_2" {
-		fn eglBindAPI(api: Enum) -> Boolean;
-		fn eglQueryAPI() -> Enum;
-		fn eglCreatePbufferFromClientBuffer(
-			display: EGLDisplay,
-			buftype: Enum,
-			buffer: EGLClientBuffer,
-			config: EGLConfig,
-			attrib_list: *const Int
-		) -> EGLSurface;
-		fn eglReleaseThread() -> Boolean;
-		fn eglWaitClient() -> Boolean;
-	},
-
-	EGL1_3 : "1_3" {
-		// nothing.
-	},
-
-	EGL1_4 : "1_4" {
-		fn eglGetCurrentContext() -> EGLContext;
-	},
-
-	EGL1_5 : "1_5" {
-		fn eglCreateSync(display: EGLDisplay, type_: Enum, attrib_list: *const Attrib) -> EGLSync;
-		fn eglDestroySync(display: EGLDisplay, sync: EGLSync) -> Boolean;
-		fn eglClientWaitSync(display: EGLDisplay, sync: EGLSync, flags: Int, timeout: Time) -> Int;
-		fn eglGetSyncAttrib(
-			display: EGLDisplay,
-			sync: EGLSync,
-			attribute: Int,
-			value: *mut Attrib
-		) -> Boolean;
-		fn eglCreateImage(
-			display: EGLDisplay,
-			ctx: EGLContext,
-			target: Enum,
-			buffer: EGLClientBuffer,
-			attrib_list: *const Attrib
-		) -> EGLImage;
-		fn eglDestroyImage(display: EGLDisplay, image: EGLImage) -> Boolean;
-		fn eglGetPlatformDisplay(
-			platform: Enum,
-			native_display: *mut c_void,
-			attrib_list: *const Attrib
-		) -> EGLDisplay;
-		fn eglCreatePlatformWindowSurface(
-			display: EGLDisplay,
-			config: EGLConfig,
-			native_window: *mut c_void,
-			attrib_list: *const Attrib
-		) -> EGLSurface;
-		fn eglCreatePlatformPixmapSurface(
-			display: EGLDisplay,
-			config: EGLConfig,
-			native_pixmap: *mut c_void,
-			attrib_list: *const Attrib
-		) -> EGLSurface;
-		fn eglWaitSync(display: EGLDisplay, sync: EGLSync, flags: Int) -> Boolean;
-	}
-}
+include!("generated/api.rs");
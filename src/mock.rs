@@ -0,0 +1,2109 @@
+//! In-memory mock EGL implementation.
+use super::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::ThreadId;
+
+/// Width of mock window and pixmap surfaces.
+const NATIVE_SURFACE_WIDTH: Int = 640;
+
+/// Height of mock window and pixmap surfaces.
+const NATIVE_SURFACE_HEIGHT: Int = 480;
+
+/// Maximum size of mock pixel buffer surfaces.
+const MAX_PBUFFER_SIZE: Int = 4096;
+
+/// Modifier exported for dma-buf images imported without modifier
+/// (`DRM_FORMAT_MOD_INVALID`).
+#[cfg(unix)]
+const DRM_FORMAT_MOD_INVALID: u64 = 0x00ff_ffff_ffff_ffff;
+
+/// Last handle given to a mock object.
+///
+/// Handles are unique across every [`Mock`], so that a display handle
+/// identifies the state of a single instance.
+static LAST_HANDLE: AtomicUsize = AtomicUsize::new(0);
+
+/// States owning each mock display.
+///
+/// Extension functions returned by `eglGetProcAddress` have no access to the
+/// [`Mock`] instance, and find its state from their display argument.
+static DISPLAY_STATES: Mutex<Vec<(usize, Weak<Mutex<State>>)>> = Mutex::new(Vec::new());
+
+/// Mock frame buffer configuration.
+///
+/// Each attribute has a default value describing an RGBA8888 configuration
+/// with a 24 bits depth buffer and an 8 bits stencil buffer, supporting every
+/// surface type and the OpenGL and OpenGL ES client APIs.
+/// Attributes can be changed using [`attrib`](Self::attrib).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MockConfig {
+	attribs: Vec<(Int, Int)>,
+}
+
+impl Default for MockConfig {
+	fn default() -> Self {
+		MockConfig::new()
+	}
+}
+
+impl MockConfig {
+	/// Creates a new configuration with default attributes.
+	pub fn new() -> Self {
+		MockConfig {
+			attribs: vec![
+				(BUFFER_SIZE, 32),
+				(RED_SIZE, 8),
+				(GREEN_SIZE, 8),
+				(BLUE_SIZE, 8),
+				(ALPHA_SIZE, 8),
+				(DEPTH_SIZE, 24),
+				(STENCIL_SIZE, 8),
+				(SAMPLES, 0),
+				(SAMPLE_BUFFERS, 0),
+				(CONFIG_CAVEAT, NONE),
+				(CONFIG_ID, 0),
+				(LEVEL, 0),
+				(MAX_PBUFFER_WIDTH, MAX_PBUFFER_SIZE),
+				(MAX_PBUFFER_HEIGHT, MAX_PBUFFER_SIZE),
+				(MAX_PBUFFER_PIXELS, MAX_PBUFFER_SIZE * MAX_PBUFFER_SIZE),
+				(NATIVE_RENDERABLE, FALSE as Int),
+				(NATIVE_VISUAL_ID, 0),
+				(NATIVE_VISUAL_TYPE, NONE),
+				(SURFACE_TYPE, WINDOW_BIT | PBUFFER_BIT | PIXMAP_BIT),
+				(TRANSPARENT_TYPE, NONE),
+				(TRANSPARENT_RED_VALUE, 0),
+				(TRANSPARENT_GREEN_VALUE, 0),
+				(TRANSPARENT_BLUE_VALUE, 0),
+				(BIND_TO_TEXTURE_RGB, FALSE as Int),
+				(BIND_TO_TEXTURE_RGBA, TRUE as Int),
+				(MIN_SWAP_INTERVAL, 0),
+				(MAX_SWAP_INTERVAL, 1),
+				(ALPHA_MASK_SIZE, 0),
+				(COLOR_BUFFER_TYPE, RGB_BUFFER),
+				(LUMINANCE_SIZE, 0),
+				(
+					RENDERABLE_TYPE,
+					OPENGL_ES_BIT | OPENGL_ES2_BIT | OPENGL_ES3_BIT | OPENGL_BIT,
+				),
+				(
+					CONFORMANT,
+					OPENGL_ES_BIT | OPENGL_ES2_BIT | OPENGL_ES3_BIT | OPENGL_BIT,
+				),
+			],
+		}
+	}
+
+	/// Sets the value of an attribute.
+	///
+	/// If `CONFIG_ID` is left to `0`, the configuration identifier is
+	/// set to the position of the configuration in the list given to
+	/// [`Mock::with_configs`], starting at 1.
+	pub fn attrib(mut self, attribute: Int, value: Int) -> Self {
+		match self.attribs.iter_mut().find(|(a, _)| *a == attribute) {
+			Some((_, v)) => *v = value,
+			None => self.attribs.push((attribute, value)),
+		}
+
+		self
+	}
+
+	/// Returns the value of an attribute.
+	pub fn get(&self, attribute: Int) -> Option<Int> {
+		self.attribs
+			.iter()
+			.find(|(a, _)| *a == attribute)
+			.map(|(_, v)| *v)
+	}
+}
+
+/// Mock EGL API interface.
+///
+/// This type is only available when the `mock` feature is enabled.
+/// It provides a pure Rust, in-memory implementation of the EGL 1.5 API
+/// without any actual rendering, useful to test code using EGL on machines
+/// without any GPU or EGL library.
+///
+/// Displays are created on demand for any native display and expose the
+/// configurations given to [`with_configs`](Self::with_configs).
+/// Contexts, surfaces, sync objects and images are only tracked, and EGL
+/// error semantics (`BadDisplay`, `NotInitialized`, `BadMatch`, etc.) are
+/// followed. Fence sync objects are always signaled.
+///
+/// Images can also be created with the `EGL_KHR_image_base` functions,
+/// returned by `eglGetProcAddress`, when the extension is advertised with
+/// [`with_display_extensions`](Self::with_display_extensions). Linux dma-buf
+/// images (`EGL_EXT_image_dma_buf_import`) are accepted on Unix when this
+/// extension is advertised as well, and the formats reported by
+/// `EGL_EXT_image_dma_buf_import_modifiers` are set with
+/// [`with_dma_buf_formats`](Self::with_dma_buf_formats). The mock keeps
+/// duplicates of the imported file descriptors, which are exported again
+/// by `EGL_MESA_image_dma_buf_export`.
+///
+/// Native fence sync objects (`EGL_ANDROID_native_fence_sync`) are supported
+/// on Unix when the extension is advertised with
+/// [`with_display_extensions`](Self::with_display_extensions). The mock
+/// takes ownership of their file descriptor, and closes it when the sync
+/// object is destroyed.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate khronos_egl as egl;
+/// # fn main() -> Result<(), egl::Error> {
+/// let egl = egl::Instance::new(egl::Mock::new());
+/// let display = unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }.unwrap();
+///
+/// assert_eq!(egl.create_pbuffer_surface(display, unsafe { egl::Config::from_ptr(std::ptr::null_mut()) }, &[egl::NONE]), Err(egl::Error::NotInitialized));
+///
+/// egl.initialize(display)?;
+/// let config = egl.choose_first_config(display, &[egl::SURFACE_TYPE, egl::PBUFFER_BIT, egl::NONE])?.unwrap();
+/// let surface = egl.create_pbuffer_surface(display, config, &[egl::WIDTH, 64, egl::HEIGHT, 64, egl::NONE])?;
+/// assert_eq!(egl.query_surface(display, surface, egl::WIDTH)?, 64);
+///
+/// egl.destroy_surface(display, surface)?;
+/// assert_eq!(egl.destroy_surface(display, surface), Err(egl::Error::BadSurface));
+/// # Ok(())
+/// # }
+/// ```
+pub struct Mock {
+	configs: Vec<MockConfig>,
+	vendor: CString,
+	version: CString,
+	client_apis: CString,
+	client_extensions: CString,
+	display_extensions: CString,
+	dma_buf_formats: Vec<(u32, Vec<(u64, bool)>)>,
+	state: Arc<Mutex<State>>,
+}
+
+impl Default for Mock {
+	fn default() -> Self {
+		Mock::new()
+	}
+}
+
+impl Mock {
+	/// Creates a new mock EGL implementation with a single default
+	/// configuration (see [`MockConfig::new`]).
+	pub fn new() -> Self {
+		Mock {
+			configs: vec![MockConfig::new().attrib(CONFIG_ID, 1)],
+			vendor: CString::new("khronos-egl").unwrap(),
+			version: CString::new("1.5 mock").unwrap(),
+			client_apis: CString::new("OpenGL OpenGL_ES").unwrap(),
+			client_extensions: CString::new("EGL_EXT_client_extensions").unwrap(),
+			display_extensions: CString::new("").unwrap(),
+			dma_buf_formats: Vec::new(),
+			state: Arc::new(Mutex::new(State::default())),
+		}
+	}
+
+	/// Sets the frame buffer configurations exposed by each display.
+	pub fn with_configs(mut self, configs: Vec<MockConfig>) -> Self {
+		self.configs = configs
+			.into_iter()
+			.enumerate()
+			.map(|(i, config)| match config.get(CONFIG_ID) {
+				Some(0) | None => config.attrib(CONFIG_ID, i as Int + 1),
+				Some(_) => config,
+			})
+			.collect();
+		self
+	}
+
+	/// Sets the space separated list of client extensions returned by
+	/// `eglQueryString(NO_DISPLAY, EXTENSIONS)`.
+	///
+	/// Passing `None` simulates an implementation that does not support
+	/// `EGL_EXT_client_extensions`: querying client extensions then fails with
+	/// a `BadDisplay` error.
+	///
+	/// # Panics
+	///
+	/// Panics if `extensions` contains a nul byte.
+	pub fn with_client_extensions(mut self, extensions: Option<&str>) -> Self {
+		self.client_extensions =
+			CString::new(extensions.unwrap_or("")).expect("extension list contains a nul byte");
+		self.state.lock().unwrap().no_client_extensions = extensions.is_none();
+		self
+	}
+
+	/// Sets the space separated list of display extensions returned by
+	/// `eglQueryString(display, EXTENSIONS)`.
+	///
+	/// # Panics
+	///
+	/// Panics if `extensions` contains a nul byte.
+	pub fn with_display_extensions(mut self, extensions: &str) -> Self {
+		self.display_extensions =
+			CString::new(extensions).expect("extension list contains a nul byte");
+		self
+	}
+
+	/// Sets the DRM formats returned by `eglQueryDmaBufFormatsEXT`, with
+	/// the modifiers returned by `eglQueryDmaBufModifiersEXT` for each of
+	/// them, and whether images using the modifier are external only.
+	///
+	/// These functions are available when the
+	/// `EGL_EXT_image_dma_buf_import_modifiers` extension is advertised with
+	/// [`with_display_extensions`](Self::with_display_extensions).
+	pub fn with_dma_buf_formats(mut self, formats: Vec<(u32, Vec<(u64, bool)>)>) -> Self {
+		self.dma_buf_formats = formats;
+		self
+	}
+
+	/// Runs the given function on the mock state, setting the error of the
+	/// calling thread and returning `fail` in case of error.
+	fn run<R>(&self, fail: R, f: impl FnOnce(&mut State) -> Result<R, Int>) -> R {
+		run_on(&self.state, fail, f)
+	}
+
+	/// Runs the given function on an initialized display.
+	fn run_display<R>(
+		&self,
+		display: EGLDisplay,
+		fail: R,
+		f: impl FnOnce(&Mock, &mut DisplayState) -> Result<R, Int>,
+	) -> R {
+		self.run(fail, |state| f(self, state.initialized_display(display)?))
+	}
+}
+
+/// Runs the given function on a mock state, setting the error of the calling
+/// thread and returning `fail` in case of error.
+fn run_on<R>(state: &Mutex<State>, fail: R, f: impl FnOnce(&mut State) -> Result<R, Int>) -> R {
+	let mut state = state.lock().unwrap();
+	let result = f(&mut state);
+	let thread = state.thread();
+	match result {
+		Ok(r) => {
+			thread.error = SUCCESS;
+			r
+		}
+		Err(e) => {
+			thread.error = e;
+			fail
+		}
+	}
+}
+
+/// Runs the given extension function on an initialized display.
+///
+/// Returns `fail` without setting any error if the display is unknown, as
+/// there is no state to store the error in.
+fn run_extension<R>(
+	display: EGLDisplay,
+	fail: R,
+	f: impl FnOnce(&mut DisplayState) -> Result<R, Int>,
+) -> R {
+	let state = DISPLAY_STATES
+		.lock()
+		.unwrap()
+		.iter()
+		.find(|(d, _)| *d == display as usize)
+		.and_then(|(_, state)| state.upgrade());
+
+	match state {
+		Some(state) => run_on(&state, fail, |state| f(state.initialized_display(display)?)),
+		None => fail,
+	}
+}
+
+extern "system" fn create_image_khr(
+	display: EGLDisplay,
+	ctx: EGLContext,
+	target: Enum,
+	buffer: EGLClientBuffer,
+	attrib_list: *const Int,
+) -> EGLImage {
+	run_extension(display, NO_IMAGE_KHR, |d| {
+		let handle = new_handle();
+		let image = d.create_image(ctx, target, buffer, unsafe { read_int_list(attrib_list) })?;
+		d.images.insert(handle, image);
+		Ok(handle as EGLImage)
+	})
+}
+
+extern "system" fn destroy_image_khr(display: EGLDisplay, image: EGLImage) -> Boolean {
+	run_extension(display, FALSE, |d| {
+		d.destroy_image(image)?;
+		Ok(TRUE)
+	})
+}
+
+extern "system" fn query_dma_buf_formats(
+	display: EGLDisplay,
+	max_formats: Int,
+	formats: *mut Int,
+	num_formats: *mut Int,
+) -> Boolean {
+	run_extension(display, FALSE, |d| {
+		let supported: Vec<Int> = d.dma_buf_formats.iter().map(|(f, _)| *f as Int).collect();
+		unsafe { write_list(&supported, max_formats, formats, num_formats)? };
+		Ok(TRUE)
+	})
+}
+
+extern "system" fn query_dma_buf_modifiers(
+	display: EGLDisplay,
+	format: Int,
+	max_modifiers: Int,
+	modifiers: *mut u64,
+	external_only: *mut Boolean,
+	num_modifiers: *mut Int,
+) -> Boolean {
+	run_extension(display, FALSE, |d| {
+		let (_, supported) = d
+			.dma_buf_formats
+			.iter()
+			.find(|(f, _)| *f as Int == format)
+			.ok_or(BAD_PARAMETER)?;
+
+		let list: Vec<u64> = supported.iter().map(|(m, _)| *m).collect();
+		unsafe { write_list(&list, max_modifiers, modifiers, num_modifiers)? };
+
+		if max_modifiers > 0 && !external_only.is_null() {
+			for (i, (_, external)) in supported.iter().take(max_modifiers as usize).enumerate() {
+				unsafe { *external_only.add(i) = *external as Boolean };
+			}
+		}
+
+		Ok(TRUE)
+	})
+}
+
+#[cfg(unix)]
+extern "system" fn export_dma_buf_image_query(
+	display: EGLDisplay,
+	image: EGLImage,
+	fourcc: *mut libc::c_int,
+	num_planes: *mut libc::c_int,
+	modifiers: *mut u64,
+) -> Boolean {
+	run_extension(display, FALSE, |d| {
+		let dma_buf = d.image_dma_buf(image)?;
+		unsafe {
+			if !fourcc.is_null() {
+				*fourcc = dma_buf.fourcc as libc::c_int;
+			}
+
+			if !num_planes.is_null() {
+				*num_planes = dma_buf.planes.len() as libc::c_int;
+			}
+
+			if !modifiers.is_null() {
+				let modifier = dma_buf.modifier.unwrap_or(DRM_FORMAT_MOD_INVALID);
+				for i in 0..dma_buf.planes.len() {
+					*modifiers.add(i) = modifier;
+				}
+			}
+		}
+
+		Ok(TRUE)
+	})
+}
+
+#[cfg(unix)]
+extern "system" fn export_dma_buf_image(
+	display: EGLDisplay,
+	image: EGLImage,
+	fds: *mut libc::c_int,
+	strides: *mut Int,
+	offsets: *mut Int,
+) -> Boolean {
+	use std::os::fd::IntoRawFd;
+
+	run_extension(display, FALSE, |d| {
+		let dma_buf = d.image_dma_buf(image)?;
+		for (i, (fd, offset, pitch)) in dma_buf.planes.iter().enumerate() {
+			unsafe {
+				if !fds.is_null() {
+					*fds.add(i) = fd.try_clone().map_err(|_| BAD_ALLOC)?.into_raw_fd();
+				}
+
+				if !strides.is_null() {
+					*strides.add(i) = *pitch;
+				}
+
+				if !offsets.is_null() {
+					*offsets.add(i) = *offset;
+				}
+			}
+		}
+
+		Ok(TRUE)
+	})
+}
+
+#[cfg(unix)]
+extern "system" fn dup_native_fence_fd(display: EGLDisplay, sync: EGLSync) -> Int {
+	use std::os::fd::IntoRawFd;
+
+	run_extension(display, NO_NATIVE_FENCE_FD_ANDROID, |d| {
+		let fd = d.sync(sync)?.fence_fd.as_ref().ok_or(BAD_PARAMETER)?;
+		Ok(fd.try_clone().map_err(|_| BAD_ALLOC)?.into_raw_fd())
+	})
+}
+
+impl Api for Mock {
+	#[inline(always)]
+	fn version(&self) -> Version {
+		LATEST
+	}
+}
+
+impl fmt::Debug for Mock {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Mock")
+	}
+}
+
+#[derive(Default)]
+struct State {
+	no_client_extensions: bool,
+	natives: HashMap<(Enum, usize), usize>,
+	displays: HashMap<usize, DisplayState>,
+	threads: HashMap<ThreadId, ThreadState>,
+}
+
+struct ThreadState {
+	error: Int,
+	api: Enum,
+	swap_interval: Int,
+	current: Option<Current>,
+}
+
+impl Default for ThreadState {
+	fn default() -> Self {
+		ThreadState {
+			error: SUCCESS,
+			api: OPENGL_ES_API,
+			swap_interval: 1,
+			current: None,
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Current {
+	display: usize,
+	draw: usize,
+	read: usize,
+	context: usize,
+}
+
+#[derive(Default)]
+struct DisplayState {
+	initialized: bool,
+	configs: Vec<(usize, MockConfig)>,
+	contexts: HashMap<usize, ContextState>,
+	surfaces: HashMap<usize, SurfaceState>,
+	syncs: HashMap<usize, SyncState>,
+	images: HashMap<usize, ImageState>,
+
+	/// Display extensions advertised by the mock.
+	extensions: Vec<String>,
+
+	/// DRM formats and modifiers supported for dma-buf import.
+	dma_buf_formats: Vec<(u32, Vec<(u64, bool)>)>,
+}
+
+struct ContextState {
+	config: Option<usize>,
+	api: Enum,
+	major_version: Int,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SurfaceKind {
+	Window,
+	Pbuffer,
+	Pixmap,
+}
+
+struct SurfaceState {
+	kind: SurfaceKind,
+	config: usize,
+	width: Int,
+	height: Int,
+	largest_pbuffer: Int,
+	texture_format: Int,
+	texture_target: Int,
+	mipmap_texture: Int,
+	mipmap_level: Int,
+	swap_behavior: Int,
+	multisample_resolve: Int,
+	bound_buffer: bool,
+}
+
+#[derive(Default)]
+struct ImageState {
+	/// Imported dma-buf (`EGL_EXT_image_dma_buf_import`).
+	#[cfg(unix)]
+	dma_buf: Option<DmaBufState>,
+}
+
+#[cfg(unix)]
+struct DmaBufState {
+	fourcc: u32,
+	modifier: Option<u64>,
+
+	/// File descriptor, offset and pitch of each plane.
+	///
+	/// The file descriptors are duplicated on import, and closed when the
+	/// image is destroyed.
+	planes: Vec<(std::os::fd::OwnedFd, Int, Int)>,
+}
+
+struct SyncState {
+	ty: Enum,
+
+	/// Native fence file descriptor (`EGL_ANDROID_native_fence_sync`).
+	#[cfg(unix)]
+	fence_fd: Option<std::os::fd::OwnedFd>,
+}
+
+/// Returns a new mock object handle.
+fn new_handle() -> usize {
+	LAST_HANDLE.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+impl State {
+	fn new_handle(&mut self) -> usize {
+		new_handle()
+	}
+
+	fn thread(&mut self) -> &mut ThreadState {
+		self.threads.entry(std::thread::current().id()).or_default()
+	}
+
+	fn get_display(&mut self, mock: &Mock, platform: Enum, native: usize) -> usize {
+		match self.natives.get(&(platform, native)) {
+			Some(display) => *display,
+			None => {
+				let display = self.new_handle();
+				let mut states = DISPLAY_STATES.lock().unwrap();
+				states.retain(|(_, state)| state.strong_count() > 0);
+				states.push((display, Arc::downgrade(&mock.state)));
+
+				let configs = mock
+					.configs
+					.iter()
+					.map(|config| (self.new_handle(), config.clone()))
+					.collect();
+				let extensions = mock
+					.display_extensions
+					.to_str()
+					.unwrap()
+					.split_whitespace()
+					.map(String::from)
+					.collect();
+				self.natives.insert((platform, native), display);
+				self.displays.insert(
+					display,
+					DisplayState {
+						configs,
+						extensions,
+						dma_buf_formats: mock.dma_buf_formats.clone(),
+						..Default::default()
+					},
+				);
+				display
+			}
+		}
+	}
+
+	fn display(&mut self, display: EGLDisplay) -> Result<&mut DisplayState, Int> {
+		self.displays
+			.get_mut(&(display as usize))
+			.ok_or(BAD_DISPLAY)
+	}
+
+	fn initialized_display(&mut self, display: EGLDisplay) -> Result<&mut DisplayState, Int> {
+		let d = self.display(display)?;
+		if d.initialized {
+			Ok(d)
+		} else {
+			Err(NOT_INITIALIZED)
+		}
+	}
+
+	/// Checks that the given context is not current to another thread.
+	fn check_not_current_elsewhere(&self, context: usize) -> Result<(), Int> {
+		let id = std::thread::current().id();
+		let elsewhere = self
+			.threads
+			.iter()
+			.any(|(t, s)| *t != id && s.current.map(|c| c.context) == Some(context));
+
+		if elsewhere {
+			Err(BAD_ACCESS)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn make_current(
+		&mut self,
+		display: EGLDisplay,
+		draw: EGLSurface,
+		read: EGLSurface,
+		ctx: EGLContext,
+	) -> Result<(), Int> {
+		let (draw, read, ctx) = (draw as usize, read as usize, ctx as usize);
+
+		if ctx == 0 {
+			if draw != 0 || read != 0 {
+				return Err(BAD_MATCH);
+			}
+
+			if display != NO_DISPLAY {
+				self.initialized_display(display)?;
+			}
+
+			self.thread().current = None;
+			return Ok(());
+		}
+
+		let d = self.initialized_display(display)?;
+		let context = d.contexts.get(&ctx).ok_or(BAD_CONTEXT)?;
+		if (draw == 0) != (read == 0) {
+			return Err(BAD_MATCH);
+		}
+
+		for surface in [draw, read] {
+			if surface != 0 {
+				let surface = d.surfaces.get(&surface).ok_or(BAD_SURFACE)?;
+				if context.config.is_some() && context.config != Some(surface.config) {
+					return Err(BAD_MATCH);
+				}
+			}
+		}
+
+		self.check_not_current_elsewhere(ctx)?;
+		self.thread().current = Some(Current {
+			display: display as usize,
+			draw,
+			read,
+			context: ctx,
+		});
+
+		Ok(())
+	}
+
+	/// Returns the current context of the calling thread on the given display.
+	fn current_context_on(&mut self, display: EGLDisplay) -> Option<usize> {
+		self.thread()
+			.current
+			.filter(|c| c.display == display as usize)
+			.map(|c| c.context)
+	}
+}
+
+impl DisplayState {
+	fn has_extension(&self, name: &str) -> bool {
+		self.extensions.iter().any(|e| e == name)
+	}
+
+	fn config(&self, config: EGLConfig) -> Result<&MockConfig, Int> {
+		self.configs
+			.iter()
+			.find(|(handle, _)| *handle == config as usize)
+			.map(|(_, config)| config)
+			.ok_or(BAD_CONFIG)
+	}
+
+	fn config_attrib(&self, config: usize, attribute: Int) -> Int {
+		self.config(config as EGLConfig)
+			.ok()
+			.and_then(|config| config.get(attribute))
+			.unwrap_or(0)
+	}
+
+	fn choose_config(&self, attrib_list: *const Int) -> Result<Vec<usize>, Int> {
+		let criteria = unsafe { read_int_list(attrib_list) };
+		for (attribute, _) in &criteria {
+			if match_rule(*attribute).is_none() {
+				return Err(BAD_ATTRIBUTE);
+			}
+		}
+
+		let criterion = |attribute: Int| {
+			criteria
+				.iter()
+				.rev()
+				.find(|(a, _)| *a == attribute)
+				.map(|(_, v)| *v)
+		};
+
+		let mut matching: Vec<&(usize, MockConfig)> = match criterion(CONFIG_ID) {
+			Some(id) if id != DONT_CARE => self
+				.configs
+				.iter()
+				.filter(|(_, config)| config.get(CONFIG_ID) == Some(id))
+				.collect(),
+			_ => self
+				.configs
+				.iter()
+				.filter(|(_, config)| {
+					config.attribs.iter().all(|(attribute, value)| {
+						let expected =
+							criterion(*attribute).unwrap_or(default_criterion(*attribute));
+						expected == DONT_CARE
+							|| match match_rule(*attribute) {
+								Some(MatchRule::AtLeast) => *value >= expected,
+								Some(MatchRule::Exact) => *value == expected,
+								Some(MatchRule::Mask) => *value & expected == expected,
+								_ => true,
+							}
+					})
+				})
+				.collect(),
+		};
+
+		matching.sort_by_key(|(_, config)| {
+			let caveat = match config.get(CONFIG_CAVEAT) {
+				Some(SLOW_CONFIG) => 1,
+				Some(NON_CONFORMANT_CONFIG) => 2,
+				_ => 0,
+			};
+
+			(caveat, config.get(CONFIG_ID))
+		});
+
+		Ok(matching.into_iter().map(|(handle, _)| *handle).collect())
+	}
+
+	fn create_context(
+		&mut self,
+		api: Enum,
+		config: EGLConfig,
+		share_context: EGLContext,
+		attrib_list: *const Int,
+		allow_no_config: bool,
+	) -> Result<ContextState, Int> {
+		let config = if config.is_null() && allow_no_config {
+			None
+		} else {
+			self.config(config)?;
+			Some(config as usize)
+		};
+
+		if !share_context.is_null() && !self.contexts.contains_key(&(share_context as usize)) {
+			return Err(BAD_CONTEXT);
+		}
+
+		let mut major_version = 1;
+		for (attribute, value) in unsafe { read_int_list(attrib_list) } {
+			match attribute {
+				CONTEXT_MAJOR_VERSION => major_version = value,
+				CONTEXT_MINOR_VERSION | CONTEXT_OPENGL_DEBUG | CONTEXT_OPENGL_ROBUST_ACCESS => (),
+				CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY
+					if value == NO_RESET_NOTIFICATION || value == LOSE_CONTEXT_ON_RESET => {}
+				CONTEXT_OPENGL_PROFILE_MASK | CONTEXT_OPENGL_FORWARD_COMPATIBLE
+					if api == OPENGL_API => {}
+				_ => return Err(BAD_ATTRIBUTE),
+			}
+		}
+
+		if api == OPENGL_API && attrib_list.is_null() {
+			major_version = 1;
+		}
+
+		if let Some(config) = config {
+			let required = match api {
+				OPENGL_API => OPENGL_BIT,
+				OPENVG_API => OPENVG_BIT,
+				_ => match major_version {
+					1 => OPENGL_ES_BIT,
+					2 => OPENGL_ES2_BIT,
+					3 => OPENGL_ES3_BIT,
+					_ => return Err(BAD_MATCH),
+				},
+			};
+
+			if self.config_attrib(config, RENDERABLE_TYPE) & required == 0 {
+				return Err(BAD_CONFIG);
+			}
+		}
+
+		Ok(ContextState {
+			config,
+			api,
+			major_version,
+		})
+	}
+
+	fn create_surface(
+		&mut self,
+		kind: SurfaceKind,
+		config: EGLConfig,
+		attrib_list: Vec<(Int, Int)>,
+	) -> Result<SurfaceState, Int> {
+		let required = match kind {
+			SurfaceKind::Window => WINDOW_BIT,
+			SurfaceKind::Pbuffer => PBUFFER_BIT,
+			SurfaceKind::Pixmap => PIXMAP_BIT,
+		};
+
+		if self.config(config)?.get(SURFACE_TYPE).unwrap_or(0) & required == 0 {
+			return Err(BAD_MATCH);
+		}
+
+		let (width, height) = match kind {
+			SurfaceKind::Pbuffer => (0, 0),
+			_ => (NATIVE_SURFACE_WIDTH, NATIVE_SURFACE_HEIGHT),
+		};
+
+		let mut surface = SurfaceState {
+			kind,
+			config: config as usize,
+			width,
+			height,
+			largest_pbuffer: FALSE as Int,
+			texture_format: NO_TEXTURE,
+			texture_target: NO_TEXTURE,
+			mipmap_texture: FALSE as Int,
+			mipmap_level: 0,
+			swap_behavior: BUFFER_DESTROYED,
+			multisample_resolve: MULTISAMPLE_RESOLVE_DEFAULT,
+			bound_buffer: false,
+		};
+
+		for (attribute, value) in attrib_list {
+			match (kind, attribute) {
+				(SurfaceKind::Pbuffer, WIDTH) => surface.width = value,
+				(SurfaceKind::Pbuffer, HEIGHT) => surface.height = value,
+				(SurfaceKind::Pbuffer, LARGEST_PBUFFER) => surface.largest_pbuffer = value,
+				(SurfaceKind::Pbuffer, TEXTURE_FORMAT) => surface.texture_format = value,
+				(SurfaceKind::Pbuffer, TEXTURE_TARGET) => surface.texture_target = value,
+				(SurfaceKind::Pbuffer, MIPMAP_TEXTURE) => surface.mipmap_texture = value,
+				(SurfaceKind::Window, RENDER_BUFFER) => (),
+				(_, GL_COLORSPACE) | (_, VG_ALPHA_FORMAT) | (_, VG_COLORSPACE) => (),
+				_ => return Err(BAD_ATTRIBUTE),
+			}
+		}
+
+		if surface.width < 0 || surface.height < 0 {
+			return Err(BAD_PARAMETER);
+		}
+
+		if surface.width > MAX_PBUFFER_SIZE || surface.height > MAX_PBUFFER_SIZE {
+			if surface.largest_pbuffer == FALSE as Int {
+				return Err(BAD_ALLOC);
+			}
+
+			surface.width = surface.width.min(MAX_PBUFFER_SIZE);
+			surface.height = surface.height.min(MAX_PBUFFER_SIZE);
+		}
+
+		if (surface.texture_format == NO_TEXTURE) != (surface.texture_target == NO_TEXTURE) {
+			return Err(BAD_MATCH);
+		}
+
+		Ok(surface)
+	}
+
+	fn surface(&mut self, surface: EGLSurface) -> Result<&mut SurfaceState, Int> {
+		self.surfaces
+			.get_mut(&(surface as usize))
+			.ok_or(BAD_SURFACE)
+	}
+
+	fn sync(&self, sync: EGLSync) -> Result<&SyncState, Int> {
+		self.syncs.get(&(sync as usize)).ok_or(BAD_PARAMETER)
+	}
+
+	/// Checks the parameters of a new image.
+	fn create_image(
+		&self,
+		ctx: EGLContext,
+		target: Enum,
+		buffer: EGLClientBuffer,
+		attrib_list: Vec<(Int, Int)>,
+	) -> Result<ImageState, Int> {
+		match target as Int {
+			GL_TEXTURE_2D
+			| GL_TEXTURE_3D
+			| GL_TEXTURE_CUBE_MAP_POSITIVE_X
+			| GL_TEXTURE_CUBE_MAP_NEGATIVE_X
+			| GL_TEXTURE_CUBE_MAP_POSITIVE_Y
+			| GL_TEXTURE_CUBE_MAP_NEGATIVE_Y
+			| GL_TEXTURE_CUBE_MAP_POSITIVE_Z
+			| GL_TEXTURE_CUBE_MAP_NEGATIVE_Z
+			| GL_RENDERBUFFER => {
+				if !self.contexts.contains_key(&(ctx as usize)) {
+					return Err(BAD_CONTEXT);
+				}
+			}
+			#[cfg(unix)]
+			LINUX_DMA_BUF_EXT if self.has_extension("EGL_EXT_image_dma_buf_import") => {
+				if ctx != NO_CONTEXT || !buffer.is_null() {
+					return Err(BAD_PARAMETER);
+				}
+
+				return Ok(ImageState {
+					dma_buf: Some(self.import_dma_buf(attrib_list)?),
+				});
+			}
+			_ => return Err(BAD_PARAMETER),
+		}
+
+		for (attribute, _) in attrib_list {
+			match attribute {
+				GL_TEXTURE_LEVEL | GL_TEXTURE_ZOFFSET | IMAGE_PRESERVED => (),
+				_ => return Err(BAD_PARAMETER),
+			}
+		}
+
+		if buffer.is_null() {
+			return Err(BAD_PARAMETER);
+		}
+
+		Ok(ImageState::default())
+	}
+
+	/// Imports the dma-buf described by the attributes of a
+	/// `LINUX_DMA_BUF_EXT` image.
+	#[cfg(unix)]
+	fn import_dma_buf(&self, attrib_list: Vec<(Int, Int)>) -> Result<DmaBufState, Int> {
+		use std::os::fd::BorrowedFd;
+
+		let modifiers = self.has_extension("EGL_EXT_image_dma_buf_import_modifiers");
+		let (mut width, mut height, mut fourcc) = (0, 0, None);
+		// File descriptor, offset, pitch, and low and high bits of the
+		// modifier of each plane.
+		let mut planes = [[None; 5]; 4];
+
+		for (attribute, value) in attrib_list {
+			match attribute {
+				WIDTH => width = value,
+				HEIGHT => height = value,
+				LINUX_DRM_FOURCC_EXT => fourcc = Some(value as u32),
+				IMAGE_PRESERVED => (),
+				_ => match image::dma_buf_plane_attrib(attribute) {
+					Some((plane, i)) if modifiers || (plane < 3 && i < 3) => {
+						planes[plane][i] = Some(value)
+					}
+					_ => return Err(BAD_ATTRIBUTE),
+				},
+			}
+		}
+
+		let count = planes.iter().take_while(|plane| plane[0].is_some()).count();
+		if width <= 0 || height <= 0 || count == 0 {
+			return Err(BAD_PARAMETER);
+		}
+
+		if planes[count..].iter().flatten().any(Option::is_some) {
+			return Err(BAD_ATTRIBUTE);
+		}
+
+		// The modifier must be the same for every plane.
+		let plane_modifier = |plane: &[Option<Int>; 5]| match (plane[3], plane[4]) {
+			(Some(lo), Some(hi)) => Ok(Some((hi as u32 as u64) << 32 | lo as u32 as u64)),
+			(None, None) => Ok(None),
+			_ => Err(BAD_PARAMETER),
+		};
+		let modifier = plane_modifier(&planes[0])?;
+
+		let planes = planes[..count]
+			.iter()
+			.map(|plane| match (plane[0], plane[1], plane[2]) {
+				(Some(fd), Some(offset), Some(pitch)) if fd >= 0 => {
+					if plane_modifier(plane)? != modifier {
+						return Err(BAD_PARAMETER);
+					}
+
+					let fd = unsafe { BorrowedFd::borrow_raw(fd) }
+						.try_clone_to_owned()
+						.map_err(|_| BAD_PARAMETER)?;
+					Ok((fd, offset, pitch))
+				}
+				_ => Err(BAD_PARAMETER),
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(DmaBufState {
+			fourcc: fourcc.ok_or(BAD_PARAMETER)?,
+			modifier,
+			planes,
+		})
+	}
+
+	/// Returns the dma-buf of an image, failing with `BadMatch` if the image
+	/// was not imported from a dma-buf.
+	#[cfg(unix)]
+	fn image_dma_buf(&self, image: EGLImage) -> Result<&DmaBufState, Int> {
+		self.images
+			.get(&(image as usize))
+			.ok_or(BAD_PARAMETER)?
+			.dma_buf
+			.as_ref()
+			.ok_or(BAD_MATCH)
+	}
+
+	fn destroy_image(&mut self, image: EGLImage) -> Result<(), Int> {
+		match self.images.remove(&(image as usize)) {
+			Some(_) => Ok(()),
+			None => Err(BAD_PARAMETER),
+		}
+	}
+}
+
+#[derive(Clone, Copy)]
+enum MatchRule {
+	AtLeast,
+	Exact,
+	Mask,
+	Ignore,
+}
+
+fn match_rule(attribute: Int) -> Option<MatchRule> {
+	match attribute {
+		BUFFER_SIZE | RED_SIZE | GREEN_SIZE | BLUE_SIZE | ALPHA_SIZE | DEPTH_SIZE
+		| STENCIL_SIZE | SAMPLES | SAMPLE_BUFFERS | ALPHA_MASK_SIZE | LUMINANCE_SIZE => {
+			Some(MatchRule::AtLeast)
+		}
+		CONFIG_CAVEAT
+		| CONFIG_ID
+		| LEVEL
+		| NATIVE_RENDERABLE
+		| NATIVE_VISUAL_TYPE
+		| TRANSPARENT_TYPE
+		| TRANSPARENT_RED_VALUE
+		| TRANSPARENT_GREEN_VALUE
+		| TRANSPARENT_BLUE_VALUE
+		| BIND_TO_TEXTURE_RGB
+		| BIND_TO_TEXTURE_RGBA
+		| MIN_SWAP_INTERVAL
+		| MAX_SWAP_INTERVAL
+		| COLOR_BUFFER_TYPE => Some(MatchRule::Exact),
+		SURFACE_TYPE | RENDERABLE_TYPE | CONFORMANT => Some(MatchRule::Mask),
+		MAX_PBUFFER_WIDTH | MAX_PBUFFER_HEIGHT | MAX_PBUFFER_PIXELS | NATIVE_VISUAL_ID
+		| MATCH_NATIVE_PIXMAP => Some(MatchRule::Ignore),
+		_ => None,
+	}
+}
+
+/// Default `eglChooseConfig` criterion of an attribute.
+fn default_criterion(attribute: Int) -> Int {
+	match attribute {
+		LEVEL => 0,
+		SURFACE_TYPE => WINDOW_BIT,
+		RENDERABLE_TYPE => OPENGL_ES_BIT,
+		COLOR_BUFFER_TYPE => RGB_BUFFER,
+		TRANSPARENT_TYPE => NONE,
+		CONFORMANT => 0,
+		_ => match match_rule(attribute) {
+			Some(MatchRule::AtLeast) => 0,
+			_ => DONT_CARE,
+		},
+	}
+}
+
+/// Reads a `NONE` terminated attribute list.
+///
+/// # Safety
+///
+/// `list` must be null or point to a valid `NONE` terminated list.
+unsafe fn read_int_list(mut list: *const Int) -> Vec<(Int, Int)> {
+	let mut result = Vec::new();
+	if !list.is_null() {
+		while *list != NONE {
+			result.push((*list, *list.add(1)));
+			list = list.add(2);
+		}
+	}
+
+	result
+}
+
+/// Reads an `ATTRIB_NONE` terminated attribute list.
+///
+/// # Safety
+///
+/// `list` must be null or point to a valid `ATTRIB_NONE` terminated list.
+unsafe fn read_attrib_list(mut list: *const Attrib) -> Vec<(Attrib, Attrib)> {
+	let mut result = Vec::new();
+	if !list.is_null() {
+		while *list != ATTRIB_NONE {
+			result.push((*list, *list.add(1)));
+			list = list.add(2);
+		}
+	}
+
+	result
+}
+
+/// Writes at most `max` items of a list to `items`, or only its length to
+/// `num` if `max` is `0`, following the pattern of the EGL query functions.
+unsafe fn write_list<T: Copy>(
+	list: &[T],
+	max: Int,
+	items: *mut T,
+	num: *mut Int,
+) -> Result<(), Int> {
+	if max < 0 || (max > 0 && items.is_null()) || num.is_null() {
+		return Err(BAD_PARAMETER);
+	}
+
+	let count = if max == 0 {
+		list.len()
+	} else {
+		let count = list.len().min(max as usize);
+		ptr::copy_nonoverlapping(list.as_ptr(), items, count);
+		count
+	};
+
+	*num = count as Int;
+	Ok(())
+}
+
+/// Converts an `Attrib` attribute list into an `Int` attribute list.
+fn attribs_to_ints(list: Vec<(Attrib, Attrib)>) -> Vec<(Int, Int)> {
+	list.into_iter()
+		.map(|(a, v)| (a as Int, v as Int))
+		.collect()
+}
+
+unsafe impl api::EGL1_0 for Mock {
+	unsafe fn eglChooseConfig(
+		&self,
+		display: EGLDisplay,
+		attrib_list: *const Int,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			if num_config.is_null() {
+				return Err(BAD_PARAMETER);
+			}
+
+			let matching = d.choose_config(attrib_list)?;
+			if configs.is_null() {
+				*num_config = matching.len() as Int;
+			} else {
+				let count = matching.len().min(config_size.max(0) as usize);
+				for (i, config) in matching.into_iter().take(count).enumerate() {
+					*configs.add(i) = config as EGLConfig;
+				}
+				*num_config = count as Int;
+			}
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglCopyBuffers(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		target: NativePixmapType,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.surface(surface)?;
+			if target.is_null() {
+				Err(BAD_NATIVE_PIXMAP)
+			} else {
+				Ok(TRUE)
+			}
+		})
+	}
+
+	unsafe fn eglCreateContext(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		share_context: EGLContext,
+		attrib_list: *const Int,
+	) -> EGLContext {
+		self.run(NO_CONTEXT, |state| {
+			let api = state.thread().api;
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let allow_no_config = d.has_extension("EGL_KHR_no_config_context")
+				|| d.has_extension("EGL_MESA_configless_context");
+			let context =
+				d.create_context(api, config, share_context, attrib_list, allow_no_config)?;
+			d.contexts.insert(handle, context);
+			Ok(handle as EGLContext)
+		})
+	}
+
+	unsafe fn eglCreatePbufferSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let surface =
+				d.create_surface(SurfaceKind::Pbuffer, config, read_int_list(attrib_list))?;
+			d.surfaces.insert(handle, surface);
+			Ok(handle as EGLSurface)
+		})
+	}
+
+	unsafe fn eglCreatePixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		pixmap: NativePixmapType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let surface =
+				d.create_surface(SurfaceKind::Pixmap, config, read_int_list(attrib_list))?;
+			if pixmap.is_null() {
+				return Err(BAD_NATIVE_PIXMAP);
+			}
+			d.surfaces.insert(handle, surface);
+			Ok(handle as EGLSurface)
+		})
+	}
+
+	unsafe fn eglCreateWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		win: NativeWindowType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let surface =
+				d.create_surface(SurfaceKind::Window, config, read_int_list(attrib_list))?;
+			if win.is_null() {
+				return Err(BAD_NATIVE_WINDOW);
+			}
+			d.surfaces.insert(handle, surface);
+			Ok(handle as EGLSurface)
+		})
+	}
+
+	unsafe fn eglDestroyContext(&self, display: EGLDisplay, ctx: EGLContext) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.contexts.remove(&(ctx as usize)).ok_or(BAD_CONTEXT)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglDestroySurface(&self, display: EGLDisplay, surface: EGLSurface) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.surfaces.remove(&(surface as usize)).ok_or(BAD_SURFACE)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglGetConfigAttrib(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			*value = d.config(config)?.get(attribute).ok_or(BAD_ATTRIBUTE)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglGetConfigs(
+		&self,
+		display: EGLDisplay,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			if num_config.is_null() {
+				return Err(BAD_PARAMETER);
+			}
+
+			if configs.is_null() {
+				*num_config = d.configs.len() as Int;
+			} else {
+				let count = d.configs.len().min(config_size.max(0) as usize);
+				for (i, (config, _)) in d.configs.iter().take(count).enumerate() {
+					*configs.add(i) = *config as EGLConfig;
+				}
+				*num_config = count as Int;
+			}
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglGetCurrentDisplay(&self) -> EGLDisplay {
+		self.run(NO_DISPLAY, |state| {
+			Ok(state
+				.thread()
+				.current
+				.map(|c| c.display as EGLDisplay)
+				.unwrap_or(NO_DISPLAY))
+		})
+	}
+
+	unsafe fn eglGetCurrentSurface(&self, readdraw: Int) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let current = state.thread().current;
+			match readdraw {
+				DRAW => Ok(current.map(|c| c.draw).unwrap_or(0) as EGLSurface),
+				READ => Ok(current.map(|c| c.read).unwrap_or(0) as EGLSurface),
+				_ => Err(BAD_PARAMETER),
+			}
+		})
+	}
+
+	unsafe fn eglGetDisplay(&self, display_id: NativeDisplayType) -> EGLDisplay {
+		self.run(NO_DISPLAY, |state| {
+			Ok(state.get_display(self, 0, display_id as usize) as EGLDisplay)
+		})
+	}
+
+	unsafe fn eglGetError(&self) -> Int {
+		let mut state = self.state.lock().unwrap();
+		std::mem::replace(&mut state.thread().error, SUCCESS)
+	}
+
+	unsafe fn eglGetProcAddress(&self, procname: *const c_char) -> Option<extern "system" fn()> {
+		match CStr::from_ptr(procname).to_bytes() {
+			b"eglCreateImageKHR" => Some(std::mem::transmute::<
+				extern "system" fn(
+					EGLDisplay,
+					EGLContext,
+					Enum,
+					EGLClientBuffer,
+					*const Int,
+				) -> EGLImage,
+				extern "system" fn(),
+			>(create_image_khr)),
+			b"eglDestroyImageKHR" => Some(std::mem::transmute::<
+				extern "system" fn(EGLDisplay, EGLImage) -> Boolean,
+				extern "system" fn(),
+			>(destroy_image_khr)),
+			b"eglQueryDmaBufFormatsEXT" => Some(std::mem::transmute::<
+				extern "system" fn(EGLDisplay, Int, *mut Int, *mut Int) -> Boolean,
+				extern "system" fn(),
+			>(query_dma_buf_formats)),
+			b"eglQueryDmaBufModifiersEXT" => Some(std::mem::transmute::<
+				extern "system" fn(
+					EGLDisplay,
+					Int,
+					Int,
+					*mut u64,
+					*mut Boolean,
+					*mut Int,
+				) -> Boolean,
+				extern "system" fn(),
+			>(query_dma_buf_modifiers)),
+			#[cfg(unix)]
+			b"eglExportDMABUFImageQueryMESA" => Some(std::mem::transmute::<
+				extern "system" fn(
+					EGLDisplay,
+					EGLImage,
+					*mut libc::c_int,
+					*mut libc::c_int,
+					*mut u64,
+				) -> Boolean,
+				extern "system" fn(),
+			>(export_dma_buf_image_query)),
+			#[cfg(unix)]
+			b"eglExportDMABUFImageMESA" => Some(std::mem::transmute::<
+				extern "system" fn(
+					EGLDisplay,
+					EGLImage,
+					*mut libc::c_int,
+					*mut Int,
+					*mut Int,
+				) -> Boolean,
+				extern "system" fn(),
+			>(export_dma_buf_image)),
+			#[cfg(unix)]
+			b"eglDupNativeFenceFDANDROID" => Some(std::mem::transmute::<
+				extern "system" fn(EGLDisplay, EGLSync) -> Int,
+				extern "system" fn(),
+			>(dup_native_fence_fd)),
+			_ => None,
+		}
+	}
+
+	unsafe fn eglInitialize(
+		&self,
+		display: EGLDisplay,
+		major: *mut Int,
+		minor: *mut Int,
+	) -> Boolean {
+		self.run(FALSE, |state| {
+			state.display(display)?.initialized = true;
+
+			if !major.is_null() {
+				*major = 1;
+			}
+
+			if !minor.is_null() {
+				*minor = 5;
+			}
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglMakeCurrent(
+		&self,
+		display: EGLDisplay,
+		draw: EGLSurface,
+		read: EGLSurface,
+		ctx: EGLContext,
+	) -> Boolean {
+		self.run(FALSE, |state| {
+			state.make_current(display, draw, read, ctx)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglQueryContext(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let context = d.contexts.get(&(ctx as usize)).ok_or(BAD_CONTEXT)?;
+			*value = match attribute {
+				CONFIG_ID => match context.config {
+					Some(config) => d.config_attrib(config, CONFIG_ID),
+					None => 0,
+				},
+				CONTEXT_CLIENT_TYPE => context.api as Int,
+				CONTEXT_CLIENT_VERSION => context.major_version,
+				RENDER_BUFFER => BACK_BUFFER,
+				_ => return Err(BAD_ATTRIBUTE),
+			};
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglQueryString(&self, display: EGLDisplay, name: Int) -> *const c_char {
+		self.run(ptr::null(), |state| {
+			if display == NO_DISPLAY {
+				return match name {
+					EXTENSIONS if !state.no_client_extensions => {
+						Ok(self.client_extensions.as_ptr())
+					}
+					VERSION => Ok(self.version.as_ptr()),
+					_ => Err(BAD_DISPLAY),
+				};
+			}
+
+			state.initialized_display(display)?;
+			match name {
+				VENDOR => Ok(self.vendor.as_ptr()),
+				VERSION => Ok(self.version.as_ptr()),
+				EXTENSIONS => Ok(self.display_extensions.as_ptr()),
+				CLIENT_APIS => Ok(self.client_apis.as_ptr()),
+				_ => Err(BAD_PARAMETER),
+			}
+		})
+	}
+
+	unsafe fn eglQuerySurface(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let surface = d.surface(surface)?;
+			let config = surface.config;
+			*value = match attribute {
+				WIDTH => surface.width,
+				HEIGHT => surface.height,
+				LARGEST_PBUFFER => surface.largest_pbuffer,
+				TEXTURE_FORMAT => surface.texture_format,
+				TEXTURE_TARGET => surface.texture_target,
+				MIPMAP_TEXTURE => surface.mipmap_texture,
+				MIPMAP_LEVEL => surface.mipmap_level,
+				SWAP_BEHAVIOR => surface.swap_behavior,
+				MULTISAMPLE_RESOLVE => surface.multisample_resolve,
+				RENDER_BUFFER => BACK_BUFFER,
+				HORIZONTAL_RESOLUTION | VERTICAL_RESOLUTION | PIXEL_ASPECT_RATIO => UNKNOWN,
+				VG_ALPHA_FORMAT => VG_ALPHA_FORMAT_NONPRE,
+				VG_COLORSPACE => VG_COLORSPACE_sRGB,
+				CONFIG_ID => d.config_attrib(config, CONFIG_ID),
+				_ => return Err(BAD_ATTRIBUTE),
+			};
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglSwapBuffers(&self, display: EGLDisplay, surface: EGLSurface) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.surface(surface)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglTerminate(&self, display: EGLDisplay) -> Boolean {
+		self.run(FALSE, |state| {
+			let d = state.display(display)?;
+			d.initialized = false;
+			d.contexts.clear();
+			d.surfaces.clear();
+			d.syncs.clear();
+			d.images.clear();
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglWaitGL(&self) -> Boolean {
+		self.run(FALSE, |_| Ok(TRUE))
+	}
+
+	unsafe fn eglWaitNative(&self, engine: Int) -> Boolean {
+		self.run(FALSE, |_| {
+			if engine == CORE_NATIVE_ENGINE {
+				Ok(TRUE)
+			} else {
+				Err(BAD_PARAMETER)
+			}
+		})
+	}
+}
+
+unsafe impl api::EGL1_1 for Mock {
+	unsafe fn eglBindTexImage(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let surface = d.surface(surface)?;
+			if buffer != BACK_BUFFER {
+				return Err(BAD_PARAMETER);
+			}
+
+			if surface.kind != SurfaceKind::Pbuffer || surface.texture_format == NO_TEXTURE {
+				return Err(BAD_MATCH);
+			}
+
+			if surface.bound_buffer {
+				return Err(BAD_ACCESS);
+			}
+
+			surface.bound_buffer = true;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglReleaseTexImage(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let surface = d.surface(surface)?;
+			if buffer != BACK_BUFFER {
+				return Err(BAD_PARAMETER);
+			}
+
+			if surface.kind != SurfaceKind::Pbuffer || surface.texture_format == NO_TEXTURE {
+				return Err(BAD_MATCH);
+			}
+
+			surface.bound_buffer = false;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglSurfaceAttrib(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		attribute: Int,
+		value: Int,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let surface = d.surface(surface)?;
+			match attribute {
+				MIPMAP_LEVEL => surface.mipmap_level = value,
+				SWAP_BEHAVIOR if value == BUFFER_DESTROYED || value == BUFFER_PRESERVED => {
+					surface.swap_behavior = value
+				}
+				MULTISAMPLE_RESOLVE
+					if value == MULTISAMPLE_RESOLVE_DEFAULT || value == MULTISAMPLE_RESOLVE_BOX =>
+				{
+					surface.multisample_resolve = value
+				}
+				_ => return Err(BAD_ATTRIBUTE),
+			}
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglSwapInterval(&self, display: EGLDisplay, interval: Int) -> Boolean {
+		self.run(FALSE, |state| {
+			state.initialized_display(display)?;
+			let thread = state.thread();
+			if thread.current.map(|c| c.display) != Some(display as usize) {
+				return Err(BAD_CONTEXT);
+			}
+
+			thread.swap_interval = interval.clamp(0, 1);
+			Ok(TRUE)
+		})
+	}
+}
+
+unsafe impl api::EGL1_2 for Mock {
+	unsafe fn eglBindAPI(&self, api: Enum) -> Boolean {
+		self.run(FALSE, |state| match api {
+			OPENGL_ES_API | OPENGL_API | OPENVG_API => {
+				state.thread().api = api;
+				Ok(TRUE)
+			}
+			_ => Err(BAD_PARAMETER),
+		})
+	}
+
+	unsafe fn eglQueryAPI(&self) -> Enum {
+		self.run(OPENGL_ES_API, |state| Ok(state.thread().api))
+	}
+
+	unsafe fn eglCreatePbufferFromClientBuffer(
+		&self,
+		display: EGLDisplay,
+		_buftype: Enum,
+		_buffer: EGLClientBuffer,
+		_config: EGLConfig,
+		_attrib_list: *const Int,
+	) -> EGLSurface {
+		// OpenVG is not supported.
+		self.run_display(display, NO_SURFACE, |_, _| Err(BAD_PARAMETER))
+	}
+
+	unsafe fn eglReleaseThread(&self) -> Boolean {
+		let mut state = self.state.lock().unwrap();
+		*state.thread() = ThreadState::default();
+		TRUE
+	}
+
+	unsafe fn eglWaitClient(&self) -> Boolean {
+		self.run(FALSE, |_| Ok(TRUE))
+	}
+}
+
+unsafe impl api::EGL1_3 for Mock {}
+
+unsafe impl api::EGL1_4 for Mock {
+	unsafe fn eglGetCurrentContext(&self) -> EGLContext {
+		self.run(NO_CONTEXT, |state| {
+			Ok(state.thread().current.map(|c| c.context).unwrap_or(0) as EGLContext)
+		})
+	}
+}
+
+unsafe impl api::EGL1_5 for Mock {
+	unsafe fn eglCreateSync(
+		&self,
+		display: EGLDisplay,
+		type_: Enum,
+		attrib_list: *const Attrib,
+	) -> EGLSync {
+		self.run(NO_SYNC, |state| {
+			let handle = state.new_handle();
+			let current = state.current_context_on(display);
+			let d = state.initialized_display(display)?;
+
+			let attribs = read_attrib_list(attrib_list);
+			#[cfg(unix)]
+			let mut fence_fd = NO_NATIVE_FENCE_FD_ANDROID;
+			match type_ as Int {
+				SYNC_FENCE if attribs.is_empty() => (),
+				#[cfg(unix)]
+				SYNC_NATIVE_FENCE_ANDROID if d.has_extension("EGL_ANDROID_native_fence_sync") => {
+					for (attribute, value) in attribs {
+						match attribute as Int {
+							SYNC_NATIVE_FENCE_FD_ANDROID => fence_fd = value as Int,
+							_ => return Err(BAD_ATTRIBUTE),
+						}
+					}
+				}
+				SYNC_FENCE => return Err(BAD_ATTRIBUTE),
+				_ => return Err(BAD_PARAMETER),
+			}
+
+			if current.is_none() {
+				return Err(BAD_MATCH);
+			}
+
+			// The file descriptor is only owned once the sync object is created.
+			d.syncs.insert(
+				handle,
+				SyncState {
+					ty: type_,
+					#[cfg(unix)]
+					fence_fd: (fence_fd != NO_NATIVE_FENCE_FD_ANDROID).then(|| {
+						use std::os::fd::FromRawFd;
+						std::os::fd::OwnedFd::from_raw_fd(fence_fd)
+					}),
+				},
+			);
+			Ok(handle as EGLSync)
+		})
+	}
+
+	unsafe fn eglDestroySync(&self, display: EGLDisplay, sync: EGLSync) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.syncs.remove(&(sync as usize)).ok_or(BAD_PARAMETER)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglClientWaitSync(
+		&self,
+		display: EGLDisplay,
+		sync: EGLSync,
+		_flags: Int,
+		_timeout: Time,
+	) -> Int {
+		self.run_display(display, FALSE as Int, |_, d| {
+			d.sync(sync)?;
+			Ok(CONDITION_SATISFIED)
+		})
+	}
+
+	unsafe fn eglGetSyncAttrib(
+		&self,
+		display: EGLDisplay,
+		sync: EGLSync,
+		attribute: Int,
+		value: *mut Attrib,
+	) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			let sync = d.sync(sync)?;
+			*value = match attribute {
+				SYNC_TYPE => sync.ty as Attrib,
+				SYNC_STATUS => SIGNALED as Attrib,
+				#[cfg(unix)]
+				SYNC_CONDITION if sync.ty == SYNC_NATIVE_FENCE_ANDROID as Enum => {
+					SYNC_NATIVE_FENCE_SIGNALED_ANDROID as Attrib
+				}
+				SYNC_CONDITION => SYNC_PRIOR_COMMANDS_COMPLETE as Attrib,
+				_ => return Err(BAD_ATTRIBUTE),
+			};
+
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglCreateImage(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		target: Enum,
+		buffer: EGLClientBuffer,
+		attrib_list: *const Attrib,
+	) -> EGLImage {
+		self.run(NO_IMAGE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let attrib_list = attribs_to_ints(read_attrib_list(attrib_list));
+			let image = d.create_image(ctx, target, buffer, attrib_list)?;
+			d.images.insert(handle, image);
+			Ok(handle as EGLImage)
+		})
+	}
+
+	unsafe fn eglDestroyImage(&self, display: EGLDisplay, image: EGLImage) -> Boolean {
+		self.run_display(display, FALSE, |_, d| {
+			d.destroy_image(image)?;
+			Ok(TRUE)
+		})
+	}
+
+	unsafe fn eglGetPlatformDisplay(
+		&self,
+		platform: Enum,
+		native_display: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLDisplay {
+		self.run(NO_DISPLAY, |state| {
+			if !read_attrib_list(attrib_list).is_empty() {
+				return Err(BAD_ATTRIBUTE);
+			}
+
+			Ok(state.get_display(self, platform, native_display as usize) as EGLDisplay)
+		})
+	}
+
+	unsafe fn eglCreatePlatformWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		native_window: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let attrib_list = attribs_to_ints(read_attrib_list(attrib_list));
+			let surface = d.create_surface(SurfaceKind::Window, config, attrib_list)?;
+			if native_window.is_null() {
+				return Err(BAD_NATIVE_WINDOW);
+			}
+			d.surfaces.insert(handle, surface);
+			Ok(handle as EGLSurface)
+		})
+	}
+
+	unsafe fn eglCreatePlatformPixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		native_pixmap: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		self.run(NO_SURFACE, |state| {
+			let handle = state.new_handle();
+			let d = state.initialized_display(display)?;
+			let attrib_list = attribs_to_ints(read_attrib_list(attrib_list));
+			let surface = d.create_surface(SurfaceKind::Pixmap, config, attrib_list)?;
+			if native_pixmap.is_null() {
+				return Err(BAD_NATIVE_PIXMAP);
+			}
+			d.surfaces.insert(handle, surface);
+			Ok(handle as EGLSurface)
+		})
+	}
+
+	unsafe fn eglWaitSync(&self, display: EGLDisplay, sync: EGLSync, flags: Int) -> Boolean {
+		self.run(FALSE, |state| {
+			let current = state.current_context_on(display);
+			let d = state.initialized_display(display)?;
+			d.sync(sync)?;
+
+			if flags != 0 {
+				return Err(BAD_PARAMETER);
+			}
+
+			if current.is_none() {
+				return Err(BAD_MATCH);
+			}
+
+			Ok(TRUE)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn initialized(egl: &Instance<Mock>) -> Display {
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		assert_eq!(egl.initialize(display), Ok((1, 5)));
+		display
+	}
+
+	#[test]
+	fn bad_display_and_not_initialized() {
+		let egl = Instance::new(Mock::new());
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		let unknown = unsafe { Display::from_ptr(0xdead as EGLDisplay) };
+
+		assert_eq!(egl.get_config_count(display), Err(Error::NotInitialized));
+		assert_eq!(egl.get_config_count(unknown), Err(Error::BadDisplay));
+		assert_eq!(egl.initialize(unknown), Err(Error::BadDisplay));
+
+		egl.initialize(display).unwrap();
+		assert_eq!(egl.get_config_count(display), Ok(1));
+
+		egl.terminate(display).unwrap();
+		assert_eq!(egl.get_config_count(display), Err(Error::NotInitialized));
+	}
+
+	#[test]
+	fn object_lifetimes() {
+		let egl = Instance::new(Mock::new());
+		let display = initialized(&egl);
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		let surface = egl
+			.create_pbuffer_surface(display, config, &[WIDTH, 16, HEIGHT, 8, NONE])
+			.unwrap();
+		assert_eq!(egl.query_surface(display, surface, HEIGHT), Ok(8));
+
+		egl.destroy_surface(display, surface).unwrap();
+		assert_eq!(
+			egl.destroy_surface(display, surface),
+			Err(Error::BadSurface)
+		);
+		assert_eq!(
+			egl.query_surface(display, surface, HEIGHT),
+			Err(Error::BadSurface)
+		);
+
+		// Terminating the display destroys the remaining objects.
+		egl.terminate(display).unwrap();
+		egl.initialize(display).unwrap();
+		assert_eq!(
+			egl.destroy_context(display, context),
+			Err(Error::BadContext)
+		);
+	}
+
+	#[test]
+	fn config_matching() {
+		let mock = Mock::new().with_configs(vec![
+			MockConfig::new()
+				.attrib(DEPTH_SIZE, 0)
+				.attrib(CONFIG_CAVEAT, SLOW_CONFIG),
+			MockConfig::new(),
+		]);
+		let egl = Instance::new(mock);
+		let display = initialized(&egl);
+
+		let mut configs = Vec::with_capacity(4);
+		egl.choose_config(display, &[NONE], &mut configs).unwrap();
+		let ids: Vec<Int> = configs
+			.iter()
+			.map(|config| egl.get_config_attrib(display, *config, CONFIG_ID).unwrap())
+			.collect();
+		assert_eq!(ids, [2, 1]);
+
+		egl.choose_config(display, &[DEPTH_SIZE, 16, NONE], &mut configs)
+			.unwrap();
+		assert_eq!(configs.len(), 1);
+		assert_eq!(egl.get_config_attrib(display, configs[0], CONFIG_ID), Ok(2));
+
+		egl.choose_config(display, &[CONFIG_ID, 1, NONE], &mut configs)
+			.unwrap();
+		assert_eq!(configs.len(), 1);
+		assert_eq!(
+			egl.get_config_attrib(display, configs[0], DEPTH_SIZE),
+			Ok(0)
+		);
+
+		assert_eq!(
+			egl.choose_config(display, &[RED_SIZE, 16, NONE], &mut configs),
+			Ok(())
+		);
+		assert!(configs.is_empty());
+		assert_eq!(
+			egl.choose_config(display, &[WIDTH, 16, NONE], &mut configs),
+			Err(Error::BadAttribute)
+		);
+	}
+
+	#[test]
+	fn make_current_state() {
+		let egl = Instance::new(Mock::new());
+		let display = initialized(&egl);
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		let surface = egl
+			.create_pbuffer_surface(display, config, &[NONE])
+			.unwrap();
+
+		assert_eq!(egl.get_current_context(), None);
+		assert_eq!(
+			egl.make_current(display, Some(surface), None, Some(context)),
+			Err(Error::BadMatch)
+		);
+
+		egl.make_current(display, Some(surface), Some(surface), Some(context))
+			.unwrap();
+		assert_eq!(egl.get_current_context(), Some(context));
+		assert_eq!(egl.get_current_display(), Some(display));
+		assert_eq!(egl.get_current_surface(DRAW), Some(surface));
+
+		// Bindings are per-thread.
+		let (raw_display, raw_context) = (display.as_ptr() as usize, context.as_ptr() as usize);
+		std::thread::scope(|scope| {
+			scope.spawn(|| {
+				let display = unsafe { Display::from_ptr(raw_display as EGLDisplay) };
+				let context = unsafe { Context::from_ptr(raw_context as EGLContext) };
+				assert_eq!(egl.get_current_context(), None);
+				assert_eq!(
+					egl.make_current(display, None, None, Some(context)),
+					Err(Error::BadAccess)
+				);
+			});
+		});
+
+		egl.make_current(display, None, None, None).unwrap();
+		assert_eq!(egl.get_current_context(), None);
+		assert_eq!(egl.get_current_surface(DRAW), None);
+	}
+
+	#[test]
+	fn config_info_and_owned_objects() {
+		let egl = Instance::new(Mock::new());
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		let display = egl.initialize_owned(display).unwrap();
+		let config = display.choose_first_config(&[NONE]).unwrap().unwrap();
+
+		let info = egl.config_info(display.as_raw(), config).unwrap();
+		assert_eq!(info.config, config);
+		assert_eq!(info.config_id, 1);
+		assert_eq!(info.depth_size, 24);
+		assert_eq!(info.config_caveat, ConfigCaveat::None);
+		assert_eq!(info.bind_to_texture_rgba, Some(true));
+		assert!(info
+			.conformant
+			.unwrap()
+			.contains(RenderableType::OPENGL_ES2));
+
+		let context = display.create_context(Some(config), None, &[NONE]).unwrap();
+		let surface = display.create_pbuffer_surface(config, &[NONE]).unwrap();
+		let raw_context = context.as_raw();
+		let raw_surface = surface.as_raw();
+
+		{
+			let guard = egl
+				.make_current_scoped(
+					display.as_raw(),
+					Some(raw_surface),
+					Some(raw_surface),
+					Some(raw_context),
+				)
+				.unwrap();
+			assert_eq!(guard.previous(), None);
+			assert_eq!(egl.get_current_context(), Some(raw_context));
+		}
+		assert_eq!(egl.get_current_context(), None);
+
+		drop(surface);
+		drop(context);
+		assert_eq!(
+			egl.destroy_surface(display.as_raw(), raw_surface),
+			Err(Error::BadSurface)
+		);
+		assert_eq!(
+			egl.destroy_context(display.as_raw(), raw_context),
+			Err(Error::BadContext)
+		);
+	}
+}
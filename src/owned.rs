@@ -0,0 +1,489 @@
+//! Owned EGL objects.
+//!
+//! The handles defined in the crate root ([`Display`], [`Context`], etc.)
+//! are simple copyable pointers that must be explicitly destroyed.
+//! The types defined here own the underlying EGL object
+//! and destroy it when dropped.
+//!
+//! Owned objects always borrow the [`Instance`] used to destroy them. Those
+//! created through an [`InitializedDisplay`] also borrow it, which ensures
+//! that they are destroyed before the display connection is terminated.
+use super::*;
+
+/// Owned EGL display connection.
+///
+/// The display connection is terminated when dropped.
+pub struct OwnedDisplay<'a, T: api::EGL1_0> {
+	instance: &'a Instance<T>,
+	display: Display,
+}
+
+impl<'a, T: api::EGL1_0> OwnedDisplay<'a, T> {
+	/// Takes ownership of the given display connection.
+	///
+	/// # Safety
+	///
+	/// `display` must be a valid display connection obtained from `instance`,
+	/// and must not be terminated by anyone else.
+	#[inline]
+	pub unsafe fn from_raw(instance: &'a Instance<T>, display: Display) -> Self {
+		OwnedDisplay { instance, display }
+	}
+
+	/// Releases ownership of the display connection without terminating it.
+	#[inline]
+	pub fn into_raw(self) -> Display {
+		let display = self.display;
+		std::mem::forget(self);
+		display
+	}
+
+	/// Returns the EGL instance used to terminate the display.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.instance
+	}
+
+	/// Returns the underlying display connection.
+	#[inline]
+	pub fn as_raw(&self) -> Display {
+		self.display
+	}
+}
+
+impl<'a, T: api::EGL1_0> Drop for OwnedDisplay<'a, T> {
+	fn drop(&mut self) {
+		self.instance.terminate(self.display).ok();
+	}
+}
+
+impl<'a, T: api::EGL1_0> fmt::Debug for OwnedDisplay<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "OwnedDisplay({:?})", self.display)
+	}
+}
+
+/// Owned EGL rendering context.
+///
+/// The context is destroyed when dropped.
+pub struct OwnedContext<'a, T: api::EGL1_0> {
+	instance: &'a Instance<T>,
+	display: Display,
+	context: Context,
+}
+
+impl<'a, T: api::EGL1_0> OwnedContext<'a, T> {
+	/// Takes ownership of the given rendering context.
+	///
+	/// # Safety
+	///
+	/// `context` must be a valid context created on `display` using `instance`,
+	/// and must not be destroyed by anyone else.
+	#[inline]
+	pub unsafe fn from_raw(instance: &'a Instance<T>, display: Display, context: Context) -> Self {
+		OwnedContext {
+			instance,
+			display,
+			context,
+		}
+	}
+
+	/// Releases ownership of the context without destroying it.
+	#[inline]
+	pub fn into_raw(self) -> Context {
+		let context = self.context;
+		std::mem::forget(self);
+		context
+	}
+
+	/// Returns the EGL instance used to destroy the context.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.instance
+	}
+
+	/// Returns the display on which the context has been created.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Returns the underlying context.
+	#[inline]
+	pub fn as_raw(&self) -> Context {
+		self.context
+	}
+}
+
+impl<'a, T: api::EGL1_0> Drop for OwnedContext<'a, T> {
+	fn drop(&mut self) {
+		self.instance
+			.destroy_context(self.display, self.context)
+			.ok();
+	}
+}
+
+impl<'a, T: api::EGL1_0> fmt::Debug for OwnedContext<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "OwnedContext({:?}, {:?})", self.display, self.context)
+	}
+}
+
+/// Owned EGL surface.
+///
+/// The surface is destroyed when dropped.
+pub struct OwnedSurface<'a, T: api::EGL1_0> {
+	instance: &'a Instance<T>,
+	display: Display,
+	surface: Surface,
+}
+
+impl<'a, T: api::EGL1_0> OwnedSurface<'a, T> {
+	/// Takes ownership of the given surface.
+	///
+	/// # Safety
+	///
+	/// `surface` must be a valid surface created on `display` using `instance`,
+	/// and must not be destroyed by anyone else.
+	#[inline]
+	pub unsafe fn from_raw(instance: &'a Instance<T>, display: Display, surface: Surface) -> Self {
+		OwnedSurface {
+			instance,
+			display,
+			surface,
+		}
+	}
+
+	/// Releases ownership of the surface without destroying it.
+	#[inline]
+	pub fn into_raw(self) -> Surface {
+		let surface = self.surface;
+		std::mem::forget(self);
+		surface
+	}
+
+	/// Returns the EGL instance used to destroy the surface.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.instance
+	}
+
+	/// Returns the display on which the surface has been created.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Returns the underlying surface.
+	#[inline]
+	pub fn as_raw(&self) -> Surface {
+		self.surface
+	}
+}
+
+impl<'a, T: api::EGL1_0> Drop for OwnedSurface<'a, T> {
+	fn drop(&mut self) {
+		self.instance
+			.destroy_surface(self.display, self.surface)
+			.ok();
+	}
+}
+
+impl<'a, T: api::EGL1_0> fmt::Debug for OwnedSurface<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "OwnedSurface({:?}, {:?})", self.display, self.surface)
+	}
+}
+
+/// Owned EGL sync object.
+///
+/// The sync object is destroyed when dropped.
+#[cfg(feature = "1_5")]
+pub struct OwnedSync<'a, T: api::EGL1_5> {
+	instance: &'a Instance<T>,
+	display: Display,
+	sync: Sync,
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> OwnedSync<'a, T> {
+	/// Takes ownership of the given sync object.
+	///
+	/// # Safety
+	///
+	/// `sync` must be a valid sync object created on `display` using
+	/// `instance`, and must not be destroyed by anyone else.
+	#[inline]
+	pub unsafe fn from_raw(instance: &'a Instance<T>, display: Display, sync: Sync) -> Self {
+		OwnedSync {
+			instance,
+			display,
+			sync,
+		}
+	}
+
+	/// Releases ownership of the sync object without destroying it.
+	#[inline]
+	pub fn into_raw(self) -> Sync {
+		let sync = self.sync;
+		std::mem::forget(self);
+		sync
+	}
+
+	/// Returns the EGL instance used to destroy the sync object.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.instance
+	}
+
+	/// Returns the display on which the sync object has been created.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Returns the underlying sync object.
+	#[inline]
+	pub fn as_raw(&self) -> Sync {
+		self.sync
+	}
+
+	/// Wait in the client for the sync object to be signalled.
+	///
+	/// See [`Instance::client_wait_sync`].
+	#[inline]
+	pub fn client_wait(&self, flags: Int, timeout: Time) -> Result<Int, Error> {
+		// This is safe since the sync object is known to belong to `display`.
+		unsafe {
+			self.instance
+				.client_wait_sync(self.display, self.sync, flags, timeout)
+		}
+	}
+
+	/// Return an attribute of the sync object.
+	///
+	/// See [`Instance::get_sync_attrib`].
+	#[inline]
+	pub fn get_attrib(&self, attribute: Int) -> Result<Attrib, Error> {
+		// This is safe since the sync object is known to belong to `display`.
+		unsafe {
+			self.instance
+				.get_sync_attrib(self.display, self.sync, attribute)
+		}
+	}
+
+	/// Wait in the server for the sync object to be signalled.
+	///
+	/// See [`Instance::wait_sync`].
+	#[inline]
+	pub fn wait(&self, flags: Int) -> Result<(), Error> {
+		self.instance.wait_sync(self.display, self.sync, flags)
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> Drop for OwnedSync<'a, T> {
+	fn drop(&mut self) {
+		// This is safe since the sync object is known to belong to `display`.
+		unsafe {
+			self.instance.destroy_sync(self.display, self.sync).ok();
+		}
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> fmt::Debug for OwnedSync<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "OwnedSync({:?}, {:?})", self.display, self.sync)
+	}
+}
+
+/// Owned EGL image.
+///
+/// The image is destroyed when dropped.
+#[cfg(feature = "1_5")]
+pub struct OwnedImage<'a, T: api::EGL1_5> {
+	instance: &'a Instance<T>,
+	display: Display,
+	image: Image,
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> OwnedImage<'a, T> {
+	/// Takes ownership of the given image.
+	///
+	/// # Safety
+	///
+	/// `image` must be a valid image created on `display` using `instance`,
+	/// and must not be destroyed by anyone else.
+	#[inline]
+	pub unsafe fn from_raw(instance: &'a Instance<T>, display: Display, image: Image) -> Self {
+		OwnedImage {
+			instance,
+			display,
+			image,
+		}
+	}
+
+	/// Releases ownership of the image without destroying it.
+	#[inline]
+	pub fn into_raw(self) -> Image {
+		let image = self.image;
+		std::mem::forget(self);
+		image
+	}
+
+	/// Returns the EGL instance used to destroy the image.
+	#[inline]
+	pub fn instance(&self) -> &'a Instance<T> {
+		self.instance
+	}
+
+	/// Returns the display on which the image has been created.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Returns the underlying image.
+	#[inline]
+	pub fn as_raw(&self) -> Image {
+		self.image
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> Drop for OwnedImage<'a, T> {
+	fn drop(&mut self) {
+		self.instance.destroy_image(self.display, self.image).ok();
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<'a, T: api::EGL1_5> fmt::Debug for OwnedImage<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "OwnedImage({:?}, {:?})", self.display, self.image)
+	}
+}
+
+impl<T: api::EGL1_0> Instance<T> {
+	/// Create a new EGL rendering context owned by the returned value.
+	///
+	/// See [`create_context`](Self::create_context).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	pub fn create_context_owned(
+		&self,
+		display: Display,
+		config: Option<Config>,
+		share_context: Option<Context>,
+		attrib_list: &[Int],
+	) -> Result<OwnedContext<'_, T>, Error> {
+		let context = self.create_context(display, config, share_context, attrib_list)?;
+		unsafe { Ok(OwnedContext::from_raw(self, display, context)) }
+	}
+
+	/// Create a new EGL pixel buffer surface owned by the returned value.
+	///
+	/// See [`create_pbuffer_surface`](Self::create_pbuffer_surface).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	pub fn create_pbuffer_surface_owned(
+		&self,
+		display: Display,
+		config: Config,
+		attrib_list: &[Int],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		let surface = self.create_pbuffer_surface(display, config, attrib_list)?;
+		unsafe { Ok(OwnedSurface::from_raw(self, display, surface)) }
+	}
+
+	/// Create a new EGL offscreen surface owned by the returned value.
+	///
+	/// See [`create_pixmap_surface`](Self::create_pixmap_surface).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	///
+	/// # Safety
+	///
+	/// This function may raise undefined behavior if the display and native
+	/// pixmap do not belong to the same platform.
+	pub unsafe fn create_pixmap_surface_owned(
+		&self,
+		display: Display,
+		config: Config,
+		pixmap: NativePixmapType,
+		attrib_list: &[Int],
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		let surface = self.create_pixmap_surface(display, config, pixmap, attrib_list)?;
+		Ok(OwnedSurface::from_raw(self, display, surface))
+	}
+
+	/// Create a new EGL window surface owned by the returned value.
+	///
+	/// See [`create_window_surface`](Self::create_window_surface).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	///
+	/// # Safety
+	///
+	/// This function may raise undefined behavior if the display and native
+	/// window do not belong to the same platform.
+	pub unsafe fn create_window_surface_owned(
+		&self,
+		display: Display,
+		config: Config,
+		window: NativeWindowType,
+		attrib_list: Option<&[Int]>,
+	) -> Result<OwnedSurface<'_, T>, Error> {
+		let surface = self.create_window_surface(display, config, window, attrib_list)?;
+		Ok(OwnedSurface::from_raw(self, display, surface))
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<T: api::EGL1_5> Instance<T> {
+	/// Create a new EGL sync object owned by the returned value.
+	///
+	/// See [`create_sync`](Self::create_sync).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	///
+	/// # Safety
+	///
+	/// When creating an OpenCL Event Sync Object, passing an invalid event
+	/// handle in `attrib_list` may result in undefined behavior up to and including program
+	/// termination.
+	pub unsafe fn create_sync_owned(
+		&self,
+		display: Display,
+		ty: Enum,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedSync<'_, T>, Error> {
+		let sync = self.create_sync(display, ty, attrib_list)?;
+		Ok(OwnedSync::from_raw(self, display, sync))
+	}
+
+	/// Create a new Image object owned by the returned value.
+	///
+	/// See [`create_image`](Self::create_image).
+	///
+	/// The returned value only borrows the instance: the display connection
+	/// must not be terminated while it is alive (see [`InitializedDisplay`]).
+	pub fn create_image_owned(
+		&self,
+		display: Display,
+		ctx: Context,
+		target: Enum,
+		buffer: ClientBuffer,
+		attrib_list: &[Attrib],
+	) -> Result<OwnedImage<'_, T>, Error> {
+		let image = self.create_image(display, ctx, target, buffer, attrib_list)?;
+		unsafe { Ok(OwnedImage::from_raw(self, display, image)) }
+	}
+}
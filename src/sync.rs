@@ -0,0 +1,608 @@
+//! Sync objects.
+use super::*;
+
+impl<T: api::EGL1_0> Instance<T> {
+	/// Loads `EGL_KHR_fence_sync`, falling back to `EGL_KHR_reusable_sync`
+	/// which includes its functions.
+	fn load_fence_sync(&self, display: Display) -> Result<KhrFenceSync, Error> {
+		match self.load_extension::<KhrFenceSync>(Some(display)) {
+			Err(Error::ExtensionNotSupported(name)) => self
+				.load_extension::<KhrReusableSync>(Some(display))
+				.map(KhrFenceSync::from)
+				.map_err(|_| Error::ExtensionNotSupported(name).without_context()),
+			result => result,
+		}
+	}
+
+	/// Create a new EGL sync object (`EGL_KHR_fence_sync`).
+	///
+	/// This is equivalent to [`create_sync`](Self::create_sync), with an
+	/// `Int` attribute list, and is available on EGL 1.2 to 1.4 when the
+	/// display supports the extension.
+	///
+	/// This will return a `BadParameter` error if `attrib_list` is not a valid
+	/// attributes list (if it does not terminate with `NONE`).
+	///
+	/// # Safety
+	///
+	/// Sync types defined by other extensions may require valid handles in
+	/// `attrib_list`.
+	pub unsafe fn create_sync_khr(
+		&self,
+		display: Display,
+		ty: Enum,
+		attrib_list: &[Int],
+	) -> Result<Sync, Error> {
+		check_int_list(attrib_list)?;
+		let ext = self.load_fence_sync(display)?;
+		let sync = ext.eglCreateSyncKHR(display.as_ptr(), ty, attrib_list.as_ptr());
+		if sync != NO_SYNC_KHR {
+			Ok(Sync(sync))
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglCreateSyncKHR")
+					.with_display(display)
+					.with_arg("ty", &ty)
+					.with_int_list(attrib_list),
+			))
+		}
+	}
+
+	/// Destroy a sync object (`EGL_KHR_fence_sync`).
+	///
+	/// # Safety
+	///
+	/// If display does not match the display passed to eglCreateSyncKHR when
+	/// sync was created, the behaviour is undefined.
+	pub unsafe fn destroy_sync_khr(&self, display: Display, sync: Sync) -> Result<(), Error> {
+		let ext = self.load_fence_sync(display)?;
+		if ext.eglDestroySyncKHR(display.as_ptr(), sync.as_ptr()) == TRUE {
+			Ok(())
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglDestroySyncKHR")
+					.with_display(display)
+					.with_arg("sync", &sync),
+			))
+		}
+	}
+
+	/// Wait in the client for a sync object to be signalled
+	/// (`EGL_KHR_fence_sync`).
+	///
+	/// # Safety
+	///
+	/// If `display` does not match the [`Display`] passed to
+	/// [`create_sync_khr`](Self::create_sync_khr) when `sync` was created, the
+	/// behaviour is undefined.
+	pub unsafe fn client_wait_sync_khr(
+		&self,
+		display: Display,
+		sync: Sync,
+		flags: Int,
+		timeout: Time,
+	) -> Result<Int, Error> {
+		let ext = self.load_fence_sync(display)?;
+		let status = ext.eglClientWaitSyncKHR(display.as_ptr(), sync.as_ptr(), flags, timeout);
+		if status != FALSE as Int {
+			Ok(status)
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglClientWaitSyncKHR")
+					.with_display(display)
+					.with_arg("sync", &sync)
+					.with_arg("flags", &flags)
+					.with_arg("timeout", &timeout),
+			))
+		}
+	}
+
+	/// Return an attribute of a sync object (`EGL_KHR_fence_sync`).
+	///
+	/// # Safety
+	///
+	/// If `display` does not match the [`Display`] passed to
+	/// [`create_sync_khr`](Self::create_sync_khr) when `sync` was created,
+	/// behavior is undefined.
+	pub unsafe fn get_sync_attrib_khr(
+		&self,
+		display: Display,
+		sync: Sync,
+		attribute: Int,
+	) -> Result<Int, Error> {
+		let ext = self.load_fence_sync(display)?;
+		let mut value = 0;
+		if ext.eglGetSyncAttribKHR(display.as_ptr(), sync.as_ptr(), attribute, &mut value) == TRUE {
+			Ok(value)
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglGetSyncAttribKHR")
+					.with_display(display)
+					.with_arg("sync", &sync)
+					.with_arg("attribute", &attribute),
+			))
+		}
+	}
+
+	/// Signal or unsignal a reusable sync object (`EGL_KHR_reusable_sync`).
+	///
+	/// `mode` is either `SIGNALED_KHR` or `UNSIGNALED_KHR`.
+	///
+	/// # Safety
+	///
+	/// If `display` does not match the [`Display`] passed to
+	/// [`create_sync_khr`](Self::create_sync_khr) when `sync` was created,
+	/// behavior is undefined.
+	pub unsafe fn signal_sync_khr(
+		&self,
+		display: Display,
+		sync: Sync,
+		mode: Enum,
+	) -> Result<(), Error> {
+		let ext = self.load_extension::<KhrReusableSync>(Some(display))?;
+		if ext.eglSignalSyncKHR(display.as_ptr(), sync.as_ptr(), mode) == TRUE {
+			Ok(())
+		} else {
+			Err(self.error_in(
+				ErrorContext::new("eglSignalSyncKHR")
+					.with_display(display)
+					.with_arg("sync", &sync)
+					.with_arg("mode", &mode),
+			))
+		}
+	}
+
+	/// Wait in the server for a sync object to be signalled
+	/// (`EGL_KHR_wait_sync`).
+	pub fn wait_sync_khr(&self, display: Display, sync: Sync, flags: Int) -> Result<(), Error> {
+		let ext = self.load_extension::<KhrWaitSync>(Some(display))?;
+		unsafe {
+			if ext.eglWaitSyncKHR(display.as_ptr(), sync.as_ptr(), flags) == TRUE as Int {
+				Ok(())
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglWaitSyncKHR")
+						.with_display(display)
+						.with_arg("sync", &sync)
+						.with_arg("flags", &flags),
+				))
+			}
+		}
+	}
+
+	/// Returns the sync object functions of the given display connection,
+	/// always using the `EGL_KHR_fence_sync`, `EGL_KHR_reusable_sync` and
+	/// `EGL_KHR_wait_sync` extensions.
+	///
+	/// This is available for any EGL version. Use
+	/// [`sync_dispatcher`](Self::sync_dispatcher) to use the EGL 1.5
+	/// functions when the display supports them. Returns
+	/// [`Error::ExtensionNotSupported`] if the display does not support
+	/// `EGL_KHR_fence_sync`.
+	pub fn sync_dispatcher_khr(&self, display: Display) -> Result<SyncDispatcher<'_, T>, Error> {
+		self.load_fence_sync(display)?;
+		Ok(SyncDispatcher {
+			instance: self,
+			display,
+			core: None,
+		})
+	}
+}
+
+#[cfg(feature = "1_5")]
+impl<T: api::EGL1_5> Instance<T> {
+	/// Returns the sync object functions of the given display connection,
+	/// using the EGL 1.5 functions if the display supports EGL 1.5, or the
+	/// KHR extensions otherwise.
+	///
+	/// The display must be initialized. Returns
+	/// [`Error::ExtensionNotSupported`] if the display supports neither EGL
+	/// 1.5 nor `EGL_KHR_fence_sync`.
+	///
+	/// ## Example
+	///
+	/// ```no_run
+	/// # extern crate khronos_egl as egl;
+	/// # let egl = egl::Instance::new(egl::Static);
+	/// # let display = unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }.unwrap();
+	/// let syncs = egl.sync_dispatcher(display)?;
+	/// unsafe {
+	///   let fence = syncs.create_sync(egl::SYNC_FENCE_KHR as egl::Enum, &[egl::NONE])?;
+	///   syncs.client_wait_sync(fence, egl::SYNC_FLUSH_COMMANDS_BIT_KHR, egl::FOREVER_KHR)?;
+	///   syncs.destroy_sync(fence)?;
+	/// }
+	/// # Ok::<(), egl::Error>(())
+	/// ```
+	pub fn sync_dispatcher(&self, display: Display) -> Result<SyncDispatcher<'_, T>, Error> {
+		let version = parse_version(self.query_string(Some(display), VERSION)?);
+		if version >= (1, 5) {
+			Ok(SyncDispatcher {
+				instance: self,
+				display,
+				core: Some(self),
+			})
+		} else {
+			self.sync_dispatcher_khr(display)
+		}
+	}
+}
+
+/// EGL 1.5 sync object functions, erasing the `api::EGL1_5` bound of the
+/// instance so that [`SyncDispatcher`] can be used for any EGL version.
+trait CoreSync {
+	unsafe fn create_sync(
+		&self,
+		display: Display,
+		ty: Enum,
+		attrib_list: &[Attrib],
+	) -> Result<Sync, Error>;
+
+	unsafe fn destroy_sync(&self, display: Display, sync: Sync) -> Result<(), Error>;
+
+	unsafe fn client_wait_sync(
+		&self,
+		display: Display,
+		sync: Sync,
+		flags: Int,
+		timeout: Time,
+	) -> Result<Int, Error>;
+
+	unsafe fn get_sync_attrib(
+		&self,
+		display: Display,
+		sync: Sync,
+		attribute: Int,
+	) -> Result<Attrib, Error>;
+
+	fn wait_sync(&self, display: Display, sync: Sync, flags: Int) -> Result<(), Error>;
+}
+
+#[cfg(feature = "1_5")]
+impl<T: api::EGL1_5> CoreSync for Instance<T> {
+	unsafe fn create_sync(
+		&self,
+		display: Display,
+		ty: Enum,
+		attrib_list: &[Attrib],
+	) -> Result<Sync, Error> {
+		Instance::create_sync(self, display, ty, attrib_list)
+	}
+
+	unsafe fn destroy_sync(&self, display: Display, sync: Sync) -> Result<(), Error> {
+		Instance::destroy_sync(self, display, sync)
+	}
+
+	unsafe fn client_wait_sync(
+		&self,
+		display: Display,
+		sync: Sync,
+		flags: Int,
+		timeout: Time,
+	) -> Result<Int, Error> {
+		Instance::client_wait_sync(self, display, sync, flags, timeout)
+	}
+
+	unsafe fn get_sync_attrib(
+		&self,
+		display: Display,
+		sync: Sync,
+		attribute: Int,
+	) -> Result<Attrib, Error> {
+		Instance::get_sync_attrib(self, display, sync, attribute)
+	}
+
+	fn wait_sync(&self, display: Display, sync: Sync, flags: Int) -> Result<(), Error> {
+		Instance::wait_sync(self, display, sync, flags)
+	}
+}
+
+/// Sync object functions of a display connection.
+///
+/// This is returned by [`Instance::sync_dispatcher`] and
+/// [`Instance::sync_dispatcher_khr`], and calls either the EGL 1.5 sync
+/// functions or their `EGL_KHR_fence_sync`, `EGL_KHR_reusable_sync` and
+/// `EGL_KHR_wait_sync` equivalents, depending on what the display supports.
+/// Attribute lists are always given as `Int` lists.
+pub struct SyncDispatcher<'a, T> {
+	instance: &'a Instance<T>,
+	display: Display,
+	core: Option<&'a dyn CoreSync>,
+}
+
+impl<'a, T: api::EGL1_0> SyncDispatcher<'a, T> {
+	/// Returns the display connection.
+	#[inline]
+	pub fn display(&self) -> Display {
+		self.display
+	}
+
+	/// Checks if the EGL 1.5 functions are used, instead of the KHR
+	/// extensions.
+	#[inline]
+	pub fn is_core(&self) -> bool {
+		self.core.is_some()
+	}
+
+	/// Create a new EGL sync object.
+	///
+	/// This will return a `BadParameter` error if `attrib_list` is not a valid
+	/// attributes list (if it does not terminate with `NONE`).
+	///
+	/// # Safety
+	///
+	/// See [`Instance::create_sync_khr`].
+	pub unsafe fn create_sync(&self, ty: Enum, attrib_list: &[Int]) -> Result<Sync, Error> {
+		match self.core {
+			Some(core) => {
+				check_int_list(attrib_list)?;
+				let attribs: Vec<Attrib> = attrib_list.iter().map(|a| *a as Attrib).collect();
+				core.create_sync(self.display, ty, &attribs)
+			}
+			None => self.instance.create_sync_khr(self.display, ty, attrib_list),
+		}
+	}
+
+	/// Destroy a sync object.
+	///
+	/// # Safety
+	///
+	/// `sync` must have been created on the same display connection.
+	pub unsafe fn destroy_sync(&self, sync: Sync) -> Result<(), Error> {
+		match self.core {
+			Some(core) => core.destroy_sync(self.display, sync),
+			None => self.instance.destroy_sync_khr(self.display, sync),
+		}
+	}
+
+	/// Wait in the client for a sync object to be signalled.
+	///
+	/// # Safety
+	///
+	/// `sync` must have been created on the same display connection.
+	pub unsafe fn client_wait_sync(
+		&self,
+		sync: Sync,
+		flags: Int,
+		timeout: Time,
+	) -> Result<Int, Error> {
+		match self.core {
+			Some(core) => core.client_wait_sync(self.display, sync, flags, timeout),
+			None => self
+				.instance
+				.client_wait_sync_khr(self.display, sync, flags, timeout),
+		}
+	}
+
+	/// Return an attribute of a sync object.
+	///
+	/// # Safety
+	///
+	/// `sync` must have been created on the same display connection.
+	pub unsafe fn get_sync_attrib(&self, sync: Sync, attribute: Int) -> Result<Attrib, Error> {
+		match self.core {
+			Some(core) => core.get_sync_attrib(self.display, sync, attribute),
+			None => self
+				.instance
+				.get_sync_attrib_khr(self.display, sync, attribute)
+				.map(|value| value as Attrib),
+		}
+	}
+
+	/// Signal or unsignal a reusable sync object.
+	///
+	/// EGL 1.5 has no reusable sync objects, so this always uses
+	/// `EGL_KHR_reusable_sync`.
+	///
+	/// # Safety
+	///
+	/// `sync` must have been created on the same display connection.
+	pub unsafe fn signal_sync(&self, sync: Sync, mode: Enum) -> Result<(), Error> {
+		self.instance.signal_sync_khr(self.display, sync, mode)
+	}
+
+	/// Wait in the server for a sync object to be signalled.
+	pub fn wait_sync(&self, sync: Sync, flags: Int) -> Result<(), Error> {
+		match self.core {
+			Some(core) => core.wait_sync(self.display, sync, flags),
+			None => self.instance.wait_sync_khr(self.display, sync, flags),
+		}
+	}
+}
+
+impl<'a, T> fmt::Debug for SyncDispatcher<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"SyncDispatcher({:?}, {})",
+			self.display,
+			if self.core.is_some() { "core" } else { "KHR" }
+		)
+	}
+}
+
+#[cfg(unix)]
+impl<'a, T: api::EGL1_0> SyncDispatcher<'a, T> {
+	/// Create a native fence sync object (`EGL_ANDROID_native_fence_sync`).
+	///
+	/// If a fence file descriptor is given, the sync object is created from
+	/// it and EGL takes its ownership. It is closed if the creation fails.
+	/// Otherwise, the sync object is signaled by a fence inserted in the
+	/// client API command stream when it is flushed, and its file descriptor
+	/// can then be retrieved with
+	/// [`Instance::dup_native_fence_fd`].
+	pub fn create_native_fence_sync(
+		&self,
+		fd: Option<std::os::fd::OwnedFd>,
+	) -> Result<Sync, Error> {
+		use std::os::fd::{FromRawFd, IntoRawFd, OwnedFd};
+
+		if !self
+			.instance
+			.display_extensions(self.display)?
+			.has("EGL_ANDROID_native_fence_sync")
+		{
+			return Err(
+				Error::ExtensionNotSupported("EGL_ANDROID_native_fence_sync").without_context(),
+			);
+		}
+
+		let fd = match fd {
+			Some(fd) => fd.into_raw_fd(),
+			None => NO_NATIVE_FENCE_FD_ANDROID,
+		};
+
+		let attrib_list = [SYNC_NATIVE_FENCE_FD_ANDROID, fd, NONE];
+
+		match unsafe { self.create_sync(SYNC_NATIVE_FENCE_ANDROID as Enum, &attrib_list) } {
+			Ok(sync) => Ok(sync),
+			Err(e) => {
+				if fd != NO_NATIVE_FENCE_FD_ANDROID {
+					drop(unsafe { OwnedFd::from_raw_fd(fd) })
+				}
+
+				Err(e)
+			}
+		}
+	}
+}
+
+#[cfg(unix)]
+impl<T: api::EGL1_0> Instance<T> {
+	/// Duplicate the file descriptor of a native fence sync object
+	/// (`EGL_ANDROID_native_fence_sync`).
+	///
+	/// The returned file descriptor is owned by the caller. This fails with a
+	/// `BadParameter` error if the sync object has no file descriptor yet
+	/// (before the fence is flushed).
+	pub fn dup_native_fence_fd(
+		&self,
+		display: Display,
+		sync: Sync,
+	) -> Result<std::os::fd::OwnedFd, Error> {
+		use std::os::fd::{FromRawFd, OwnedFd};
+
+		let ext = self.load_extension::<AndroidNativeFenceSync>(Some(display))?;
+		unsafe {
+			let fd = ext.eglDupNativeFenceFDANDROID(display.as_ptr(), sync.as_ptr());
+			if fd != NO_NATIVE_FENCE_FD_ANDROID {
+				Ok(OwnedFd::from_raw_fd(fd))
+			} else {
+				Err(self.error_in(
+					ErrorContext::new("eglDupNativeFenceFDANDROID")
+						.with_display(display)
+						.with_arg("sync", &sync),
+				))
+			}
+		}
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[cfg(feature = "validation")]
+	fn core_dispatcher_goes_through_the_api() {
+		let egl = Instance::new(Validated::new(Mock::new()));
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		egl.make_current(display, None, None, Some(context))
+			.unwrap();
+
+		let syncs = egl.sync_dispatcher(display).unwrap();
+		assert!(syncs.is_core());
+
+		unsafe {
+			let fence = syncs.create_sync(SYNC_FENCE as Enum, &[NONE]).unwrap();
+			assert_eq!(
+				syncs.get_sync_attrib(fence, SYNC_TYPE),
+				Ok(SYNC_FENCE as Attrib)
+			);
+			syncs.destroy_sync(fence).unwrap();
+
+			assert_eq!(syncs.destroy_sync(fence), Err(Error::BadParameter));
+			let violation = Violation::last().unwrap();
+			assert_eq!(violation.function(), "eglDestroySync");
+			assert_eq!(
+				violation.kind(),
+				ViolationKind::DoubleDestroy(Object::Sync(fence))
+			);
+		}
+	}
+
+	#[test]
+	fn khr_dispatcher_requires_the_extension() {
+		let egl = Instance::new(Mock::new());
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+
+		assert_eq!(
+			egl.sync_dispatcher_khr(display).unwrap_err(),
+			Error::ExtensionNotSupported("EGL_KHR_fence_sync")
+		);
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn native_fence_fd_ownership() {
+		use std::io::{Read, Write};
+		use std::os::unix::net::UnixStream;
+		use std::time::Duration;
+
+		/// Checks that the peer of `stream` has been closed.
+		fn assert_closed(stream: &mut UnixStream) {
+			stream
+				.set_read_timeout(Some(Duration::from_secs(5)))
+				.unwrap();
+			assert_eq!(stream.read(&mut [0]).unwrap(), 0);
+		}
+
+		let egl =
+			Instance::new(Mock::new().with_display_extensions("EGL_ANDROID_native_fence_sync"));
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+		egl.initialize(display).unwrap();
+		let syncs = egl.sync_dispatcher(display).unwrap();
+
+		// No current context: the creation fails and the fd is closed.
+		let (fence, mut peer) = UnixStream::pair().unwrap();
+		assert_eq!(
+			syncs.create_native_fence_sync(Some(fence.into())),
+			Err(Error::BadMatch)
+		);
+		assert_closed(&mut peer);
+
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		egl.make_current(display, None, None, Some(context))
+			.unwrap();
+
+		let (fence, mut peer) = UnixStream::pair().unwrap();
+		let sync = syncs.create_native_fence_sync(Some(fence.into())).unwrap();
+
+		// The duplicated fd is owned by the caller and refers to the fence.
+		let mut dup = UnixStream::from(egl.dup_native_fence_fd(display, sync).unwrap());
+		dup.write_all(b"x").unwrap();
+		let mut buf = [0];
+		peer.read_exact(&mut buf).unwrap();
+		assert_eq!(&buf, b"x");
+		drop(dup);
+
+		// The fence fd itself is closed with the sync object.
+		unsafe { syncs.destroy_sync(sync) }.unwrap();
+		assert_closed(&mut peer);
+
+		// A sync object created without fd has none to duplicate.
+		let sync = syncs.create_native_fence_sync(None).unwrap();
+		assert_eq!(
+			egl.dup_native_fence_fd(display, sync).unwrap_err(),
+			Error::BadParameter
+		);
+	}
+}
@@ -0,0 +1,1111 @@
+//! EGL calls tracing.
+use super::*;
+use std::cell::Cell;
+
+/// Target of the log records emitted by [`Traced`].
+pub const TRACE_TARGET: &str = "khronos_egl";
+
+thread_local! {
+	/// Error code of the last traced call on this thread.
+	static LAST_ERROR: Cell<Int> = const { Cell::new(SUCCESS) };
+}
+
+/// Tracing EGL API interface.
+///
+/// This type is only available when the `trace` feature is enabled.
+/// It wraps another API interface (such as [`Static`] or [`Dynamic`]) and
+/// emits a [`log`](https://crates.io/crates/log) record for every EGL call
+/// with its arguments, return value and resulting `eglGetError` code.
+/// Attribute lists and enumerants are decoded into their symbolic names.
+///
+/// Records are emitted with the [`TRACE_TARGET`] target, at the `Trace`
+/// level for successful calls and at the `Debug` level for failed calls.
+///
+/// Since `eglGetError` is called after every call to emit the record, the
+/// error code is saved in a thread local variable and returned by the next
+/// call to `eglGetError` through this interface.
+///
+/// ## Example
+///
+/// ```no_run
+/// # extern crate khronos_egl as egl;
+/// let egl = egl::Instance::new(egl::Traced::new(egl::Static));
+///
+/// // Or, from an existing instance:
+/// let egl = egl::Instance::new(egl::Static).cast_into::<egl::Traced<_>>();
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Traced<A> {
+	inner: A,
+}
+
+impl<A> Traced<A> {
+	/// Wraps the given API interface.
+	#[inline(always)]
+	pub const fn new(inner: A) -> Self {
+		Traced { inner }
+	}
+
+	/// Returns a reference to the wrapped API interface.
+	#[inline(always)]
+	pub fn inner(&self) -> &A {
+		&self.inner
+	}
+
+	/// Returns the wrapped API interface.
+	#[inline(always)]
+	pub fn into_inner(self) -> A {
+		self.inner
+	}
+}
+
+impl<A> From<A> for Traced<A> {
+	#[inline(always)]
+	fn from(inner: A) -> Self {
+		Traced::new(inner)
+	}
+}
+
+impl<A: Api> Api for Traced<A> {
+	#[inline(always)]
+	fn version(&self) -> Version {
+		self.inner.version()
+	}
+}
+
+impl<A: api::EGL1_0> Traced<A> {
+	/// Saves the error code of the last call and emits its record.
+	fn record(&self, call: fmt::Arguments, result: impl fmt::Display) {
+		let error = unsafe { self.inner.eglGetError() };
+		LAST_ERROR.with(|e| e.set(error));
+
+		if error == SUCCESS {
+			log::trace!(target: TRACE_TARGET, "{} = {}", call, result);
+		} else {
+			log::debug!(target: TRACE_TARGET, "{} = {} ({})", call, result, Name(error));
+		}
+	}
+}
+
+/// Kind of value taken by an attribute.
+#[derive(Clone, Copy)]
+enum ValueKind {
+	Int,
+	Bool,
+	Enum,
+	#[cfg(feature = "1_5")]
+	Handle,
+	Bits(&'static [(Int, &'static str)]),
+}
+
+const SURFACE_TYPE_BITS: &[(Int, &str)] = &[
+	(0x0001, "EGL_PBUFFER_BIT"),
+	(0x0002, "EGL_PIXMAP_BIT"),
+	(0x0004, "EGL_WINDOW_BIT"),
+	(0x0020, "EGL_VG_COLORSPACE_LINEAR_BIT"),
+	(0x0040, "EGL_VG_ALPHA_FORMAT_PRE_BIT"),
+	(0x0200, "EGL_MULTISAMPLE_RESOLVE_BOX_BIT"),
+	(0x0400, "EGL_SWAP_BEHAVIOR_PRESERVED_BIT"),
+];
+
+#[cfg(feature = "1_2")]
+const RENDERABLE_TYPE_BITS: &[(Int, &str)] = &[
+	(0x0001, "EGL_OPENGL_ES_BIT"),
+	(0x0002, "EGL_OPENVG_BIT"),
+	(0x0004, "EGL_OPENGL_ES2_BIT"),
+	(0x0008, "EGL_OPENGL_BIT"),
+	(0x0040, "EGL_OPENGL_ES3_BIT"),
+];
+
+#[cfg(feature = "1_5")]
+const PROFILE_BITS: &[(Int, &str)] = &[
+	(0x0001, "EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT"),
+	(0x0002, "EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT"),
+];
+
+/// Attributes with a non-integer value.
+const ATTRIBUTE_KINDS: &[(Int, ValueKind)] = &[
+	(CONFIG_CAVEAT, ValueKind::Enum),
+	(NATIVE_RENDERABLE, ValueKind::Bool),
+	(SURFACE_TYPE, ValueKind::Bits(SURFACE_TYPE_BITS)),
+	(TRANSPARENT_TYPE, ValueKind::Enum),
+	#[cfg(feature = "1_1")]
+	(BIND_TO_TEXTURE_RGB, ValueKind::Bool),
+	#[cfg(feature = "1_1")]
+	(BIND_TO_TEXTURE_RGBA, ValueKind::Bool),
+	#[cfg(feature = "1_2")]
+	(COLOR_BUFFER_TYPE, ValueKind::Enum),
+	#[cfg(feature = "1_2")]
+	(RENDERABLE_TYPE, ValueKind::Bits(RENDERABLE_TYPE_BITS)),
+	#[cfg(feature = "1_3")]
+	(CONFORMANT, ValueKind::Bits(RENDERABLE_TYPE_BITS)),
+	(LARGEST_PBUFFER, ValueKind::Bool),
+	#[cfg(feature = "1_1")]
+	(TEXTURE_FORMAT, ValueKind::Enum),
+	#[cfg(feature = "1_1")]
+	(TEXTURE_TARGET, ValueKind::Enum),
+	#[cfg(feature = "1_1")]
+	(MIPMAP_TEXTURE, ValueKind::Bool),
+	#[cfg(feature = "1_2")]
+	(RENDER_BUFFER, ValueKind::Enum),
+	#[cfg(feature = "1_3")]
+	(VG_COLORSPACE, ValueKind::Enum),
+	#[cfg(feature = "1_3")]
+	(VG_ALPHA_FORMAT, ValueKind::Enum),
+	#[cfg(feature = "1_2")]
+	(SWAP_BEHAVIOR, ValueKind::Enum),
+	#[cfg(feature = "1_2")]
+	(CONTEXT_CLIENT_TYPE, ValueKind::Enum),
+	#[cfg(feature = "1_4")]
+	(MULTISAMPLE_RESOLVE, ValueKind::Enum),
+	#[cfg(feature = "1_5")]
+	(CL_EVENT_HANDLE, ValueKind::Handle),
+	#[cfg(feature = "1_5")]
+	(GL_COLORSPACE, ValueKind::Enum),
+	#[cfg(feature = "1_5")]
+	(IMAGE_PRESERVED, ValueKind::Bool),
+	#[cfg(feature = "1_5")]
+	(SYNC_STATUS, ValueKind::Enum),
+	#[cfg(feature = "1_5")]
+	(SYNC_TYPE, ValueKind::Enum),
+	#[cfg(feature = "1_5")]
+	(SYNC_CONDITION, ValueKind::Enum),
+	#[cfg(feature = "1_5")]
+	(CONTEXT_OPENGL_PROFILE_MASK, ValueKind::Bits(PROFILE_BITS)),
+	#[cfg(feature = "1_5")]
+	(CONTEXT_OPENGL_DEBUG, ValueKind::Bool),
+	#[cfg(feature = "1_5")]
+	(CONTEXT_OPENGL_FORWARD_COMPATIBLE, ValueKind::Bool),
+	#[cfg(feature = "1_5")]
+	(CONTEXT_OPENGL_ROBUST_ACCESS, ValueKind::Bool),
+	#[cfg(feature = "1_5")]
+	(CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY, ValueKind::Enum),
+];
+
+include!("generated/enum_names.rs");
+
+/// Returns the symbolic name of the given EGL enumerant, if any.
+fn enum_name(value: Int) -> Option<&'static str> {
+	ENUM_NAMES
+		.binary_search_by_key(&value, |(v, _)| *v)
+		.ok()
+		.map(|i| ENUM_NAMES[i].1)
+}
+
+/// Enumerant, displayed with its symbolic name.
+struct Name(Int);
+
+impl fmt::Display for Name {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match enum_name(self.0) {
+			Some(name) => f.write_str(name),
+			None => write!(f, "{:#x}", self.0),
+		}
+	}
+}
+
+/// EGL boolean.
+struct Bool(Boolean);
+
+impl fmt::Display for Bool {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.0 {
+			0 => f.write_str("EGL_FALSE"),
+			1 => f.write_str("EGL_TRUE"),
+			b => write!(f, "{}", b),
+		}
+	}
+}
+
+/// EGL handle or pointer.
+struct Ptr<T>(*const T);
+
+impl<T> fmt::Display for Ptr<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.0.is_null() {
+			f.write_str("NULL")
+		} else {
+			write!(f, "{:p}", self.0)
+		}
+	}
+}
+
+/// Value pointed by an output parameter, read when displayed.
+struct Out<T>(*const T);
+
+impl<T: fmt::Display> fmt::Display for Out<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.0.is_null() {
+			f.write_str("NULL")
+		} else {
+			write!(f, "&{}", unsafe { &*self.0 })
+		}
+	}
+}
+
+/// Nul-terminated string.
+struct Str(*const c_char);
+
+impl fmt::Display for Str {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.0.is_null() {
+			f.write_str("NULL")
+		} else {
+			write!(f, "{:?}", unsafe { CStr::from_ptr(self.0) })
+		}
+	}
+}
+
+/// Array of configurations returned by `eglChooseConfig` and `eglGetConfigs`,
+/// read when displayed.
+struct Configs(*const EGLConfig, *const Int);
+
+impl fmt::Display for Configs {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.0.is_null() || self.1.is_null() {
+			return Ptr(self.0).fmt(f);
+		}
+
+		let count = unsafe { *self.1 }.max(0) as usize;
+		let configs = unsafe { std::slice::from_raw_parts(self.0, count) };
+		f.write_str("[")?;
+		for (i, config) in configs.iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+
+			Ptr(*config).fmt(f)?;
+		}
+		f.write_str("]")
+	}
+}
+
+/// Attribute value, displayed according to the attribute.
+struct Value(Int, Attrib);
+
+impl fmt::Display for Value {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let Value(attribute, value) = *self;
+		if value as isize == -1 {
+			return f.write_str("EGL_DONT_CARE");
+		}
+
+		let kind = ATTRIBUTE_KINDS
+			.iter()
+			.find(|(a, _)| *a == attribute)
+			.map(|(_, kind)| *kind)
+			.unwrap_or(ValueKind::Int);
+
+		match kind {
+			ValueKind::Int => write!(f, "{}", value as isize),
+			ValueKind::Bool => Bool(value as Boolean).fmt(f),
+			ValueKind::Enum => Name(value as Int).fmt(f),
+			#[cfg(feature = "1_5")]
+			ValueKind::Handle => write!(f, "{:#x}", value),
+			ValueKind::Bits(bits) => {
+				let mut remaining = value as Int;
+				if remaining == 0 {
+					return f.write_str("0");
+				}
+
+				let mut first = true;
+				for (bit, name) in bits {
+					if remaining & bit != 0 {
+						if !first {
+							f.write_str(" | ")?;
+						}
+						f.write_str(name)?;
+						remaining &= !bit;
+						first = false;
+					}
+				}
+
+				if remaining != 0 {
+					if !first {
+						f.write_str(" | ")?;
+					}
+					write!(f, "{:#x}", remaining)?;
+				}
+
+				Ok(())
+			}
+		}
+	}
+}
+
+/// Attribute list item type, either `Int` or `Attrib`.
+trait AttribValue: Copy {
+	fn to_attrib(self) -> Attrib;
+}
+
+impl AttribValue for Int {
+	fn to_attrib(self) -> Attrib {
+		self as Attrib
+	}
+}
+
+impl AttribValue for Attrib {
+	fn to_attrib(self) -> Attrib {
+		self
+	}
+}
+
+/// Output attribute value, read when displayed.
+struct OutValue<T>(Int, *const T);
+
+impl<T: AttribValue> fmt::Display for OutValue<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.1.is_null() {
+			f.write_str("NULL")
+		} else {
+			write!(f, "&{}", Value(self.0, unsafe { *self.1 }.to_attrib()))
+		}
+	}
+}
+
+/// `NONE` terminated attribute list.
+struct List<T>(*const T);
+
+impl<T: AttribValue> fmt::Display for List<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.0.is_null() {
+			return f.write_str("NULL");
+		}
+
+		f.write_str("[")?;
+		let mut list = self.0;
+		unsafe {
+			loop {
+				let attribute = (*list).to_attrib() as Int;
+				if attribute == NONE {
+					break;
+				}
+
+				write!(
+					f,
+					"{}, {}, ",
+					Name(attribute),
+					Value(attribute, (*list.add(1)).to_attrib())
+				)?;
+				list = list.add(2);
+			}
+		}
+		f.write_str("EGL_NONE]")
+	}
+}
+
+/// Value returned by `eglClientWaitSync`.
+#[cfg(feature = "1_5")]
+struct WaitResult(Int);
+
+#[cfg(feature = "1_5")]
+impl fmt::Display for WaitResult {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self.0 {
+			0 => f.write_str("EGL_FALSE"),
+			value => Name(value).fmt(f),
+		}
+	}
+}
+
+#[cfg(feature = "1_0")]
+unsafe impl<A: api::EGL1_0> api::EGL1_0 for Traced<A> {
+	unsafe fn eglChooseConfig(
+		&self,
+		display: EGLDisplay,
+		attrib_list: *const Int,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		let result =
+			self.inner
+				.eglChooseConfig(display, attrib_list, configs, config_size, num_config);
+		self.record(
+			format_args!(
+				"eglChooseConfig({}, {}, {}, {}, {})",
+				Ptr(display),
+				List(attrib_list),
+				Configs(configs, num_config),
+				config_size,
+				Out(num_config)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglCopyBuffers(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		target: NativePixmapType,
+	) -> Boolean {
+		let result = self.inner.eglCopyBuffers(display, surface, target);
+		self.record(
+			format_args!(
+				"eglCopyBuffers({}, {}, {})",
+				Ptr(display),
+				Ptr(surface),
+				Ptr(target)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreateContext(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		share_context: EGLContext,
+		attrib_list: *const Int,
+	) -> EGLContext {
+		let result = self
+			.inner
+			.eglCreateContext(display, config, share_context, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreateContext({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Ptr(share_context),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreatePbufferSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		let result = self
+			.inner
+			.eglCreatePbufferSurface(display, config, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreatePbufferSurface({}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreatePixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		pixmap: NativePixmapType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		let result = self
+			.inner
+			.eglCreatePixmapSurface(display, config, pixmap, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreatePixmapSurface({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Ptr(pixmap),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreateWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		win: NativeWindowType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		let result = self
+			.inner
+			.eglCreateWindowSurface(display, config, win, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreateWindowSurface({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Ptr(win),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglDestroyContext(&self, display: EGLDisplay, ctx: EGLContext) -> Boolean {
+		let result = self.inner.eglDestroyContext(display, ctx);
+		self.record(
+			format_args!("eglDestroyContext({}, {})", Ptr(display), Ptr(ctx)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglDestroySurface(&self, display: EGLDisplay, surface: EGLSurface) -> Boolean {
+		let result = self.inner.eglDestroySurface(display, surface);
+		self.record(
+			format_args!("eglDestroySurface({}, {})", Ptr(display), Ptr(surface)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetConfigAttrib(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		let result = self
+			.inner
+			.eglGetConfigAttrib(display, config, attribute, value);
+		self.record(
+			format_args!(
+				"eglGetConfigAttrib({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Name(attribute),
+				OutValue(attribute, value)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetConfigs(
+		&self,
+		display: EGLDisplay,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		let result = self
+			.inner
+			.eglGetConfigs(display, configs, config_size, num_config);
+		self.record(
+			format_args!(
+				"eglGetConfigs({}, {}, {}, {})",
+				Ptr(display),
+				Configs(configs, num_config),
+				config_size,
+				Out(num_config)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetCurrentDisplay(&self) -> EGLDisplay {
+		let result = self.inner.eglGetCurrentDisplay();
+		self.record(format_args!("eglGetCurrentDisplay()"), Ptr(result));
+		result
+	}
+
+	unsafe fn eglGetCurrentSurface(&self, readdraw: Int) -> EGLSurface {
+		let result = self.inner.eglGetCurrentSurface(readdraw);
+		self.record(
+			format_args!("eglGetCurrentSurface({})", Name(readdraw)),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetDisplay(&self, display_id: NativeDisplayType) -> EGLDisplay {
+		let result = self.inner.eglGetDisplay(display_id);
+		self.record(
+			format_args!("eglGetDisplay({})", Ptr(display_id)),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetError(&self) -> Int {
+		LAST_ERROR.with(|e| e.replace(SUCCESS))
+	}
+
+	unsafe fn eglGetProcAddress(&self, procname: *const c_char) -> Option<extern "system" fn()> {
+		let result = self.inner.eglGetProcAddress(procname);
+		self.record(
+			format_args!("eglGetProcAddress({})", Str(procname)),
+			Ptr(result.map_or(ptr::null(), |f| f as *const ())),
+		);
+		result
+	}
+
+	unsafe fn eglInitialize(
+		&self,
+		display: EGLDisplay,
+		major: *mut Int,
+		minor: *mut Int,
+	) -> Boolean {
+		let result = self.inner.eglInitialize(display, major, minor);
+		self.record(
+			format_args!(
+				"eglInitialize({}, {}, {})",
+				Ptr(display),
+				Out(major),
+				Out(minor)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglMakeCurrent(
+		&self,
+		display: EGLDisplay,
+		draw: EGLSurface,
+		read: EGLSurface,
+		ctx: EGLContext,
+	) -> Boolean {
+		let result = self.inner.eglMakeCurrent(display, draw, read, ctx);
+		self.record(
+			format_args!(
+				"eglMakeCurrent({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(draw),
+				Ptr(read),
+				Ptr(ctx)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglQueryContext(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		let result = self.inner.eglQueryContext(display, ctx, attribute, value);
+		self.record(
+			format_args!(
+				"eglQueryContext({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(ctx),
+				Name(attribute),
+				OutValue(attribute, value)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglQueryString(&self, display: EGLDisplay, name: Int) -> *const c_char {
+		let result = self.inner.eglQueryString(display, name);
+		self.record(
+			format_args!("eglQueryString({}, {})", Ptr(display), Name(name)),
+			Str(result),
+		);
+		result
+	}
+
+	unsafe fn eglQuerySurface(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		let result = self
+			.inner
+			.eglQuerySurface(display, surface, attribute, value);
+		self.record(
+			format_args!(
+				"eglQuerySurface({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(surface),
+				Name(attribute),
+				OutValue(attribute, value)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglSwapBuffers(&self, display: EGLDisplay, surface: EGLSurface) -> Boolean {
+		let result = self.inner.eglSwapBuffers(display, surface);
+		self.record(
+			format_args!("eglSwapBuffers({}, {})", Ptr(display), Ptr(surface)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglTerminate(&self, display: EGLDisplay) -> Boolean {
+		let result = self.inner.eglTerminate(display);
+		self.record(format_args!("eglTerminate({})", Ptr(display)), Bool(result));
+		result
+	}
+
+	unsafe fn eglWaitGL(&self) -> Boolean {
+		let result = self.inner.eglWaitGL();
+		self.record(format_args!("eglWaitGL()"), Bool(result));
+		result
+	}
+
+	unsafe fn eglWaitNative(&self, engine: Int) -> Boolean {
+		let result = self.inner.eglWaitNative(engine);
+		self.record(
+			format_args!("eglWaitNative({})", Name(engine)),
+			Bool(result),
+		);
+		result
+	}
+}
+
+#[cfg(feature = "1_1")]
+unsafe impl<A: api::EGL1_1> api::EGL1_1 for Traced<A> {
+	unsafe fn eglBindTexImage(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		let result = self.inner.eglBindTexImage(display, surface, buffer);
+		self.record(
+			format_args!(
+				"eglBindTexImage({}, {}, {})",
+				Ptr(display),
+				Ptr(surface),
+				Name(buffer)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglReleaseTexImage(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		let result = self.inner.eglReleaseTexImage(display, surface, buffer);
+		self.record(
+			format_args!(
+				"eglReleaseTexImage({}, {}, {})",
+				Ptr(display),
+				Ptr(surface),
+				Name(buffer)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglSurfaceAttrib(
+		&self,
+		display: EGLDisplay,
+		surface: EGLSurface,
+		attribute: Int,
+		value: Int,
+	) -> Boolean {
+		let result = self
+			.inner
+			.eglSurfaceAttrib(display, surface, attribute, value);
+		self.record(
+			format_args!(
+				"eglSurfaceAttrib({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(surface),
+				Name(attribute),
+				Value(attribute, value as Attrib)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglSwapInterval(&self, display: EGLDisplay, interval: Int) -> Boolean {
+		let result = self.inner.eglSwapInterval(display, interval);
+		self.record(
+			format_args!("eglSwapInterval({}, {})", Ptr(display), interval),
+			Bool(result),
+		);
+		result
+	}
+}
+
+#[cfg(feature = "1_2")]
+unsafe impl<A: api::EGL1_2> api::EGL1_2 for Traced<A> {
+	unsafe fn eglBindAPI(&self, api: Enum) -> Boolean {
+		let result = self.inner.eglBindAPI(api);
+		self.record(
+			format_args!("eglBindAPI({})", Name(api as Int)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglQueryAPI(&self) -> Enum {
+		let result = self.inner.eglQueryAPI();
+		self.record(format_args!("eglQueryAPI()"), Name(result as Int));
+		result
+	}
+
+	unsafe fn eglCreatePbufferFromClientBuffer(
+		&self,
+		display: EGLDisplay,
+		buftype: Enum,
+		buffer: EGLClientBuffer,
+		config: EGLConfig,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		let result = self.inner.eglCreatePbufferFromClientBuffer(
+			display,
+			buftype,
+			buffer,
+			config,
+			attrib_list,
+		);
+		self.record(
+			format_args!(
+				"eglCreatePbufferFromClientBuffer({}, {}, {}, {}, {})",
+				Ptr(display),
+				Name(buftype as Int),
+				Ptr(buffer),
+				Ptr(config),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglReleaseThread(&self) -> Boolean {
+		let result = self.inner.eglReleaseThread();
+		self.record(format_args!("eglReleaseThread()"), Bool(result));
+		result
+	}
+
+	unsafe fn eglWaitClient(&self) -> Boolean {
+		let result = self.inner.eglWaitClient();
+		self.record(format_args!("eglWaitClient()"), Bool(result));
+		result
+	}
+}
+
+#[cfg(feature = "1_3")]
+unsafe impl<A: api::EGL1_3> api::EGL1_3 for Traced<A> {}
+
+#[cfg(feature = "1_4")]
+unsafe impl<A: api::EGL1_4> api::EGL1_4 for Traced<A> {
+	unsafe fn eglGetCurrentContext(&self) -> EGLContext {
+		let result = self.inner.eglGetCurrentContext();
+		self.record(format_args!("eglGetCurrentContext()"), Ptr(result));
+		result
+	}
+}
+
+#[cfg(feature = "1_5")]
+unsafe impl<A: api::EGL1_5> api::EGL1_5 for Traced<A> {
+	unsafe fn eglCreateSync(
+		&self,
+		display: EGLDisplay,
+		type_: Enum,
+		attrib_list: *const Attrib,
+	) -> EGLSync {
+		let result = self.inner.eglCreateSync(display, type_, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreateSync({}, {}, {})",
+				Ptr(display),
+				Name(type_ as Int),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglDestroySync(&self, display: EGLDisplay, sync: EGLSync) -> Boolean {
+		let result = self.inner.eglDestroySync(display, sync);
+		self.record(
+			format_args!("eglDestroySync({}, {})", Ptr(display), Ptr(sync)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglClientWaitSync(
+		&self,
+		display: EGLDisplay,
+		sync: EGLSync,
+		flags: Int,
+		timeout: Time,
+	) -> Int {
+		let result = self.inner.eglClientWaitSync(display, sync, flags, timeout);
+		self.record(
+			format_args!(
+				"eglClientWaitSync({}, {}, {:#x}, {})",
+				Ptr(display),
+				Ptr(sync),
+				flags,
+				timeout
+			),
+			WaitResult(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetSyncAttrib(
+		&self,
+		display: EGLDisplay,
+		sync: EGLSync,
+		attribute: Int,
+		value: *mut Attrib,
+	) -> Boolean {
+		let result = self.inner.eglGetSyncAttrib(display, sync, attribute, value);
+		self.record(
+			format_args!(
+				"eglGetSyncAttrib({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(sync),
+				Name(attribute),
+				OutValue(attribute, value)
+			),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreateImage(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		target: Enum,
+		buffer: EGLClientBuffer,
+		attrib_list: *const Attrib,
+	) -> EGLImage {
+		let result = self
+			.inner
+			.eglCreateImage(display, ctx, target, buffer, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreateImage({}, {}, {}, {}, {})",
+				Ptr(display),
+				Ptr(ctx),
+				Name(target as Int),
+				Ptr(buffer),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglDestroyImage(&self, display: EGLDisplay, image: EGLImage) -> Boolean {
+		let result = self.inner.eglDestroyImage(display, image);
+		self.record(
+			format_args!("eglDestroyImage({}, {})", Ptr(display), Ptr(image)),
+			Bool(result),
+		);
+		result
+	}
+
+	unsafe fn eglGetPlatformDisplay(
+		&self,
+		platform: Enum,
+		native_display: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLDisplay {
+		let result = self
+			.inner
+			.eglGetPlatformDisplay(platform, native_display, attrib_list);
+		self.record(
+			format_args!(
+				"eglGetPlatformDisplay({}, {}, {})",
+				Name(platform as Int),
+				Ptr(native_display),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreatePlatformWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		native_window: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		let result =
+			self.inner
+				.eglCreatePlatformWindowSurface(display, config, native_window, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreatePlatformWindowSurface({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Ptr(native_window),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglCreatePlatformPixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config: EGLConfig,
+		native_pixmap: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		let result =
+			self.inner
+				.eglCreatePlatformPixmapSurface(display, config, native_pixmap, attrib_list);
+		self.record(
+			format_args!(
+				"eglCreatePlatformPixmapSurface({}, {}, {}, {})",
+				Ptr(display),
+				Ptr(config),
+				Ptr(native_pixmap),
+				List(attrib_list)
+			),
+			Ptr(result),
+		);
+		result
+	}
+
+	unsafe fn eglWaitSync(&self, display: EGLDisplay, sync: EGLSync, flags: Int) -> Boolean {
+		let result = self.inner.eglWaitSync(display, sync, flags);
+		self.record(
+			format_args!("eglWaitSync({}, {}, {:#x})", Ptr(display), Ptr(sync), flags),
+			Bool(result),
+		);
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn enum_names() {
+		assert!(ENUM_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+		assert_eq!(enum_name(SUCCESS), Some("EGL_SUCCESS"));
+		assert_eq!(enum_name(CONFIG_CAVEAT), Some("EGL_CONFIG_CAVEAT"));
+		assert_eq!(enum_name(0x3FFF), None);
+		assert_eq!(Name(0x3FFF).to_string(), "0x3fff");
+	}
+}
@@ -0,0 +1,1259 @@
+//! Objects lifetime validation.
+use super::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+thread_local! {
+	/// Error code of the last call rejected on this thread, if any.
+	static PENDING_ERROR: Cell<Option<Int>> = const { Cell::new(None) };
+
+	/// Last violation detected on this thread.
+	static LAST_VIOLATION: RefCell<Option<Violation>> = const { RefCell::new(None) };
+}
+
+/// EGL object tracked by [`Validated`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Object {
+	/// Frame buffer configuration.
+	Config(Config),
+
+	/// Rendering context.
+	Context(Context),
+
+	/// Rendering surface.
+	Surface(Surface),
+
+	/// Sync object.
+	#[cfg(feature = "1_5")]
+	Sync(Sync),
+
+	/// Image object.
+	#[cfg(feature = "1_5")]
+	Image(Image),
+}
+
+impl Object {
+	/// Returns the error returned by EGL when this object is not valid.
+	pub fn error(&self) -> Error {
+		match self {
+			Object::Config(_) => Error::BadConfig,
+			Object::Context(_) => Error::BadContext,
+			Object::Surface(_) => Error::BadSurface,
+			#[cfg(feature = "1_5")]
+			Object::Sync(_) | Object::Image(_) => Error::BadParameter,
+		}
+	}
+
+	fn key(&self) -> (u8, usize) {
+		match self {
+			Object::Config(c) => (0, c.as_ptr() as usize),
+			Object::Context(c) => (1, c.as_ptr() as usize),
+			Object::Surface(s) => (2, s.as_ptr() as usize),
+			#[cfg(feature = "1_5")]
+			Object::Sync(s) => (3, s.as_ptr() as usize),
+			#[cfg(feature = "1_5")]
+			Object::Image(i) => (4, i.as_ptr() as usize),
+		}
+	}
+}
+
+impl fmt::Display for Object {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Object::Config(c) => write!(f, "config {:?}", c.as_ptr()),
+			Object::Context(c) => write!(f, "context {:?}", c.as_ptr()),
+			Object::Surface(s) => write!(f, "surface {:?}", s.as_ptr()),
+			#[cfg(feature = "1_5")]
+			Object::Sync(s) => write!(f, "sync {:?}", s.as_ptr()),
+			#[cfg(feature = "1_5")]
+			Object::Image(i) => write!(f, "image {:?}", i.as_ptr()),
+		}
+	}
+}
+
+/// Kind of API usage violation detected by [`Validated`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ViolationKind {
+	/// The display connection was not obtained through the validated API.
+	UnknownDisplay(Display),
+
+	/// The display connection is not initialized.
+	NotInitialized(Display),
+
+	/// The object has been destroyed.
+	UseAfterDestroy(Object),
+
+	/// The object has already been destroyed.
+	DoubleDestroy(Object),
+
+	/// The object was created on another display connection.
+	WrongDisplay {
+		/// Object.
+		object: Object,
+
+		/// Display connection the object is used with.
+		display: Display,
+
+		/// Display connection the object was created on.
+		owner: Display,
+	},
+}
+
+/// API usage violation detected by [`Validated`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Violation {
+	function: &'static str,
+	kind: ViolationKind,
+}
+
+impl Violation {
+	/// Returns and clears the last violation detected on the calling thread.
+	pub fn last() -> Option<Violation> {
+		LAST_VIOLATION.with(|v| v.borrow_mut().take())
+	}
+
+	/// Returns the name of the rejected EGL function.
+	#[inline]
+	pub fn function(&self) -> &'static str {
+		self.function
+	}
+
+	/// Returns the kind of violation.
+	#[inline]
+	pub fn kind(&self) -> ViolationKind {
+		self.kind
+	}
+
+	/// Returns the EGL error reported for this violation.
+	pub fn error(&self) -> Error {
+		match self.kind {
+			ViolationKind::UnknownDisplay(_) => Error::BadDisplay,
+			ViolationKind::NotInitialized(_) => Error::NotInitialized,
+			ViolationKind::UseAfterDestroy(object)
+			| ViolationKind::DoubleDestroy(object)
+			| ViolationKind::WrongDisplay { object, .. } => object.error(),
+		}
+	}
+}
+
+impl fmt::Display for Violation {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}: ", self.function)?;
+		match self.kind {
+			ViolationKind::UnknownDisplay(display) => write!(
+				f,
+				"display {:?} was not obtained through this API",
+				display.as_ptr()
+			),
+			ViolationKind::NotInitialized(display) => {
+				write!(f, "display {:?} is not initialized", display.as_ptr())
+			}
+			ViolationKind::UseAfterDestroy(object) => write!(f, "{} has been destroyed", object),
+			ViolationKind::DoubleDestroy(object) => {
+				write!(f, "{} has already been destroyed", object)
+			}
+			ViolationKind::WrongDisplay {
+				object,
+				display,
+				owner,
+			} => write!(
+				f,
+				"{} belongs to display {:?}, not {:?}",
+				object,
+				owner.as_ptr(),
+				display.as_ptr()
+			),
+		}
+	}
+}
+
+impl std::error::Error for Violation {}
+
+/// Validating EGL API interface.
+///
+/// This type is only available when the `validation` feature is enabled.
+/// It wraps another API interface (such as [`Static`] or [`Dynamic`]) and
+/// records every display connection, configuration, context, surface, sync
+/// and image object created through it, with its owning display.
+/// Calls using a non-initialized display, a destroyed object or an object
+/// created on another display are rejected before reaching the driver.
+///
+/// A rejected call fails with the error EGL would return for an invalid
+/// argument (for instance `BadSurface` for a destroyed surface), and a
+/// [`Violation`] describing the problem can be retrieved using
+/// [`Violation::last`].
+///
+/// Objects that were not created through this interface (for instance
+/// through extension functions) are not tracked and are passed as is
+/// to the driver. Display connections obtained through extension functions
+/// (such as `get_surfaceless_display_ext`) are tracked once they
+/// are initialized through this interface.
+///
+/// ## Example
+///
+/// ```no_run
+/// # extern crate khronos_egl as egl;
+/// # fn main() -> Result<(), egl::Error> {
+/// let egl = egl::Instance::new(egl::Validated::new(egl::Static));
+/// let display = unsafe { egl.get_display(egl::DEFAULT_DISPLAY) }.unwrap();
+/// egl.initialize(display)?;
+///
+/// let config = egl.choose_first_config(display, &[egl::NONE])?.unwrap();
+/// let surface = egl.create_pbuffer_surface(display, config, &[egl::NONE])?;
+/// egl.destroy_surface(display, surface)?;
+///
+/// assert_eq!(egl.destroy_surface(display, surface), Err(egl::Error::BadSurface));
+/// println!("{}", egl::Violation::last().unwrap());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Validated<A> {
+	inner: A,
+	registry: Mutex<Registry>,
+}
+
+impl<A> Validated<A> {
+	/// Wraps the given API interface.
+	pub fn new(inner: A) -> Self {
+		Validated {
+			inner,
+			registry: Mutex::new(Registry::default()),
+		}
+	}
+
+	/// Returns a reference to the wrapped API interface.
+	#[inline(always)]
+	pub fn inner(&self) -> &A {
+		&self.inner
+	}
+
+	/// Returns the wrapped API interface.
+	#[inline(always)]
+	pub fn into_inner(self) -> A {
+		self.inner
+	}
+}
+
+impl<A> From<A> for Validated<A> {
+	#[inline(always)]
+	fn from(inner: A) -> Self {
+		Validated::new(inner)
+	}
+}
+
+impl<A: Api> Api for Validated<A> {
+	#[inline(always)]
+	fn version(&self) -> Version {
+		self.inner.version()
+	}
+}
+
+impl<A: fmt::Debug> fmt::Debug for Validated<A> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "Validated({:?})", self.inner)
+	}
+}
+
+impl<A> Validated<A> {
+	/// Validates a call using `check` before forwarding it to the inner
+	/// implementation, then updates the registry with the result.
+	fn call<R>(
+		&self,
+		function: &'static str,
+		fail: R,
+		check: impl FnOnce(&Registry) -> Result<(), ViolationKind>,
+		forward: impl FnOnce(&A) -> R,
+		update: impl FnOnce(&mut Registry, &R),
+	) -> R {
+		PENDING_ERROR.with(|e| e.set(None));
+
+		if let Err(kind) = check(&self.registry.lock().unwrap()) {
+			let violation = Violation { function, kind };
+			PENDING_ERROR.with(|e| e.set(violation.error().native()));
+			LAST_VIOLATION.with(|v| *v.borrow_mut() = Some(violation));
+			return fail;
+		}
+
+		let result = forward(&self.inner);
+		update(&mut self.registry.lock().unwrap(), &result);
+		result
+	}
+}
+
+struct ObjectRecord {
+	display: usize,
+	alive: bool,
+}
+
+/// Display connections and objects created through a [`Validated`] API.
+#[derive(Default)]
+struct Registry {
+	/// Known display connections, with their initialization status.
+	displays: HashMap<usize, bool>,
+	objects: HashMap<(u8, usize), ObjectRecord>,
+}
+
+impl Registry {
+	fn check_known(&self, display: EGLDisplay) -> Result<(), ViolationKind> {
+		if self.displays.contains_key(&(display as usize)) {
+			Ok(())
+		} else {
+			Err(ViolationKind::UnknownDisplay(unsafe {
+				Display::from_ptr(display)
+			}))
+		}
+	}
+
+	fn check_display(&self, display: EGLDisplay) -> Result<(), ViolationKind> {
+		match self.displays.get(&(display as usize)) {
+			Some(true) => Ok(()),
+			Some(false) => Err(ViolationKind::NotInitialized(unsafe {
+				Display::from_ptr(display)
+			})),
+			None => Err(ViolationKind::UnknownDisplay(unsafe {
+				Display::from_ptr(display)
+			})),
+		}
+	}
+
+	fn check_object(&self, display: EGLDisplay, object: Object) -> Result<(), ViolationKind> {
+		match self.objects.get(&object.key()) {
+			Some(record) if !record.alive => Err(ViolationKind::UseAfterDestroy(object)),
+			Some(record) if record.display != display as usize => {
+				Err(ViolationKind::WrongDisplay {
+					object,
+					display: unsafe { Display::from_ptr(display) },
+					owner: unsafe { Display::from_ptr(record.display as EGLDisplay) },
+				})
+			}
+			_ => Ok(()),
+		}
+	}
+
+	fn check_destroy(&self, display: EGLDisplay, object: Object) -> Result<(), ViolationKind> {
+		match self.objects.get(&object.key()) {
+			Some(record) if !record.alive => Err(ViolationKind::DoubleDestroy(object)),
+			_ => self.check_object(display, object),
+		}
+	}
+
+	fn register_display(&mut self, display: EGLDisplay) {
+		if !display.is_null() {
+			self.displays.entry(display as usize).or_insert(false);
+		}
+	}
+
+	fn create(&mut self, display: EGLDisplay, object: Object) {
+		self.objects.insert(
+			object.key(),
+			ObjectRecord {
+				display: display as usize,
+				alive: true,
+			},
+		);
+	}
+
+	fn destroy(&mut self, object: Object) {
+		if let Some(record) = self.objects.get_mut(&object.key()) {
+			record.alive = false;
+		}
+	}
+
+	fn terminate(&mut self, display: EGLDisplay) {
+		if let Some(initialized) = self.displays.get_mut(&(display as usize)) {
+			*initialized = false;
+		}
+
+		for (key, record) in &mut self.objects {
+			// Configurations are not destroyed by `eglTerminate`.
+			if key.0 != 0 && record.display == display as usize {
+				record.alive = false;
+			}
+		}
+	}
+
+	/// Registers the configurations returned by `eglChooseConfig` or
+	/// `eglGetConfigs`.
+	///
+	/// # Safety
+	///
+	/// `configs` must be null or point to `*num_config` valid configurations.
+	unsafe fn register_configs(
+		&mut self,
+		display: EGLDisplay,
+		configs: *const EGLConfig,
+		num_config: *const Int,
+	) {
+		if !configs.is_null() && !num_config.is_null() {
+			for i in 0..(*num_config).max(0) as usize {
+				self.create(display, Object::Config(Config::from_ptr(*configs.add(i))));
+			}
+		}
+	}
+}
+
+/// Checks that an optional object handle is valid.
+fn check_optional(
+	registry: &Registry,
+	display: EGLDisplay,
+	handle: *mut c_void,
+	object: impl FnOnce(*mut c_void) -> Object,
+) -> Result<(), ViolationKind> {
+	if handle.is_null() {
+		Ok(())
+	} else {
+		registry.check_object(display, object(handle))
+	}
+}
+
+fn config(config: EGLConfig) -> Object {
+	Object::Config(unsafe { Config::from_ptr(config) })
+}
+
+fn context(ctx: EGLContext) -> Object {
+	Object::Context(unsafe { Context::from_ptr(ctx) })
+}
+
+fn surface(surface: EGLSurface) -> Object {
+	Object::Surface(unsafe { Surface::from_ptr(surface) })
+}
+
+#[cfg(feature = "1_5")]
+fn sync(sync: EGLSync) -> Object {
+	Object::Sync(unsafe { Sync::from_ptr(sync) })
+}
+
+#[cfg(feature = "1_5")]
+fn image(image: EGLImage) -> Object {
+	Object::Image(unsafe { Image::from_ptr(image) })
+}
+
+#[cfg(feature = "1_0")]
+unsafe impl<A: api::EGL1_0> api::EGL1_0 for Validated<A> {
+	unsafe fn eglChooseConfig(
+		&self,
+		display: EGLDisplay,
+		attrib_list: *const Int,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglChooseConfig",
+			FALSE,
+			|r| r.check_display(display),
+			|a| a.eglChooseConfig(display, attrib_list, configs, config_size, num_config),
+			|r, result| {
+				if *result == TRUE {
+					r.register_configs(display, configs, num_config)
+				}
+			},
+		)
+	}
+
+	unsafe fn eglCopyBuffers(
+		&self,
+		display: EGLDisplay,
+		surface_: EGLSurface,
+		target: NativePixmapType,
+	) -> Boolean {
+		self.call(
+			"eglCopyBuffers",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglCopyBuffers(display, surface_, target),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglCreateContext(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		share_context: EGLContext,
+		attrib_list: *const Int,
+	) -> EGLContext {
+		self.call(
+			"eglCreateContext",
+			NO_CONTEXT,
+			|r| {
+				r.check_display(display)?;
+				check_optional(r, display, config_, config)?;
+				check_optional(r, display, share_context, context)
+			},
+			|a| a.eglCreateContext(display, config_, share_context, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, context(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglCreatePbufferSurface(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.call(
+			"eglCreatePbufferSurface",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreatePbufferSurface(display, config_, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglCreatePixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		pixmap: NativePixmapType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.call(
+			"eglCreatePixmapSurface",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreatePixmapSurface(display, config_, pixmap, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglCreateWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		win: NativeWindowType,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.call(
+			"eglCreateWindowSurface",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreateWindowSurface(display, config_, win, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglDestroyContext(&self, display: EGLDisplay, ctx: EGLContext) -> Boolean {
+		self.call(
+			"eglDestroyContext",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_destroy(display, context(ctx))
+			},
+			|a| a.eglDestroyContext(display, ctx),
+			|r, result| {
+				if *result == TRUE {
+					r.destroy(context(ctx))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglDestroySurface(&self, display: EGLDisplay, surface_: EGLSurface) -> Boolean {
+		self.call(
+			"eglDestroySurface",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_destroy(display, surface(surface_))
+			},
+			|a| a.eglDestroySurface(display, surface_),
+			|r, result| {
+				if *result == TRUE {
+					r.destroy(surface(surface_))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglGetConfigAttrib(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglGetConfigAttrib",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglGetConfigAttrib(display, config_, attribute, value),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglGetConfigs(
+		&self,
+		display: EGLDisplay,
+		configs: *mut EGLConfig,
+		config_size: Int,
+		num_config: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglGetConfigs",
+			FALSE,
+			|r| r.check_display(display),
+			|a| a.eglGetConfigs(display, configs, config_size, num_config),
+			|r, result| {
+				if *result == TRUE {
+					r.register_configs(display, configs, num_config)
+				}
+			},
+		)
+	}
+
+	unsafe fn eglGetCurrentDisplay(&self) -> EGLDisplay {
+		self.inner.eglGetCurrentDisplay()
+	}
+
+	unsafe fn eglGetCurrentSurface(&self, readdraw: Int) -> EGLSurface {
+		self.inner.eglGetCurrentSurface(readdraw)
+	}
+
+	unsafe fn eglGetDisplay(&self, display_id: NativeDisplayType) -> EGLDisplay {
+		self.call(
+			"eglGetDisplay",
+			NO_DISPLAY,
+			|_| Ok(()),
+			|a| a.eglGetDisplay(display_id),
+			|r, result| r.register_display(*result),
+		)
+	}
+
+	unsafe fn eglGetError(&self) -> Int {
+		match PENDING_ERROR.with(|e| e.take()) {
+			Some(error) => error,
+			None => self.inner.eglGetError(),
+		}
+	}
+
+	unsafe fn eglGetProcAddress(&self, procname: *const c_char) -> Option<extern "system" fn()> {
+		self.inner.eglGetProcAddress(procname)
+	}
+
+	unsafe fn eglInitialize(
+		&self,
+		display: EGLDisplay,
+		major: *mut Int,
+		minor: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglInitialize",
+			FALSE,
+			// Displays obtained through `eglGetPlatformDisplayEXT` are adopted
+			// here, and invalid ones are reported by the driver.
+			|_| Ok(()),
+			|a| a.eglInitialize(display, major, minor),
+			|r, result| {
+				if *result == TRUE {
+					r.displays.insert(display as usize, true);
+				}
+			},
+		)
+	}
+
+	unsafe fn eglMakeCurrent(
+		&self,
+		display: EGLDisplay,
+		draw: EGLSurface,
+		read: EGLSurface,
+		ctx: EGLContext,
+	) -> Boolean {
+		self.call(
+			"eglMakeCurrent",
+			FALSE,
+			|r| {
+				// Releasing the current context does not require a display.
+				if display.is_null() {
+					return Ok(());
+				}
+
+				r.check_display(display)?;
+				check_optional(r, display, draw, surface)?;
+				check_optional(r, display, read, surface)?;
+				check_optional(r, display, ctx, context)
+			},
+			|a| a.eglMakeCurrent(display, draw, read, ctx),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglQueryContext(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglQueryContext",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, context(ctx))
+			},
+			|a| a.eglQueryContext(display, ctx, attribute, value),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglQueryString(&self, display: EGLDisplay, name: Int) -> *const c_char {
+		self.call(
+			"eglQueryString",
+			ptr::null(),
+			|r| {
+				// Client extensions are queried without display.
+				if display.is_null() {
+					Ok(())
+				} else {
+					r.check_display(display)
+				}
+			},
+			|a| a.eglQueryString(display, name),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglQuerySurface(
+		&self,
+		display: EGLDisplay,
+		surface_: EGLSurface,
+		attribute: Int,
+		value: *mut Int,
+	) -> Boolean {
+		self.call(
+			"eglQuerySurface",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglQuerySurface(display, surface_, attribute, value),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglSwapBuffers(&self, display: EGLDisplay, surface_: EGLSurface) -> Boolean {
+		self.call(
+			"eglSwapBuffers",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglSwapBuffers(display, surface_),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglTerminate(&self, display: EGLDisplay) -> Boolean {
+		self.call(
+			"eglTerminate",
+			FALSE,
+			|r| r.check_known(display),
+			|a| a.eglTerminate(display),
+			|r, result| {
+				if *result == TRUE {
+					r.terminate(display)
+				}
+			},
+		)
+	}
+
+	unsafe fn eglWaitGL(&self) -> Boolean {
+		self.inner.eglWaitGL()
+	}
+
+	unsafe fn eglWaitNative(&self, engine: Int) -> Boolean {
+		self.inner.eglWaitNative(engine)
+	}
+}
+
+#[cfg(feature = "1_1")]
+unsafe impl<A: api::EGL1_1> api::EGL1_1 for Validated<A> {
+	unsafe fn eglBindTexImage(
+		&self,
+		display: EGLDisplay,
+		surface_: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		self.call(
+			"eglBindTexImage",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglBindTexImage(display, surface_, buffer),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglReleaseTexImage(
+		&self,
+		display: EGLDisplay,
+		surface_: EGLSurface,
+		buffer: Int,
+	) -> Boolean {
+		self.call(
+			"eglReleaseTexImage",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglReleaseTexImage(display, surface_, buffer),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglSurfaceAttrib(
+		&self,
+		display: EGLDisplay,
+		surface_: EGLSurface,
+		attribute: Int,
+		value: Int,
+	) -> Boolean {
+		self.call(
+			"eglSurfaceAttrib",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, surface(surface_))
+			},
+			|a| a.eglSurfaceAttrib(display, surface_, attribute, value),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglSwapInterval(&self, display: EGLDisplay, interval: Int) -> Boolean {
+		self.call(
+			"eglSwapInterval",
+			FALSE,
+			|r| r.check_display(display),
+			|a| a.eglSwapInterval(display, interval),
+			|_, _| (),
+		)
+	}
+}
+
+#[cfg(feature = "1_2")]
+unsafe impl<A: api::EGL1_2> api::EGL1_2 for Validated<A> {
+	unsafe fn eglBindAPI(&self, api: Enum) -> Boolean {
+		self.inner.eglBindAPI(api)
+	}
+
+	unsafe fn eglQueryAPI(&self) -> Enum {
+		self.inner.eglQueryAPI()
+	}
+
+	unsafe fn eglCreatePbufferFromClientBuffer(
+		&self,
+		display: EGLDisplay,
+		buftype: Enum,
+		buffer: EGLClientBuffer,
+		config_: EGLConfig,
+		attrib_list: *const Int,
+	) -> EGLSurface {
+		self.call(
+			"eglCreatePbufferFromClientBuffer",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreatePbufferFromClientBuffer(display, buftype, buffer, config_, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglReleaseThread(&self) -> Boolean {
+		self.inner.eglReleaseThread()
+	}
+
+	unsafe fn eglWaitClient(&self) -> Boolean {
+		self.inner.eglWaitClient()
+	}
+}
+
+#[cfg(feature = "1_3")]
+unsafe impl<A: api::EGL1_3> api::EGL1_3 for Validated<A> {}
+
+#[cfg(feature = "1_4")]
+unsafe impl<A: api::EGL1_4> api::EGL1_4 for Validated<A> {
+	unsafe fn eglGetCurrentContext(&self) -> EGLContext {
+		self.inner.eglGetCurrentContext()
+	}
+}
+
+#[cfg(feature = "1_5")]
+unsafe impl<A: api::EGL1_5> api::EGL1_5 for Validated<A> {
+	unsafe fn eglCreateSync(
+		&self,
+		display: EGLDisplay,
+		type_: Enum,
+		attrib_list: *const Attrib,
+	) -> EGLSync {
+		self.call(
+			"eglCreateSync",
+			NO_SYNC,
+			|r| r.check_display(display),
+			|a| a.eglCreateSync(display, type_, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, sync(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglDestroySync(&self, display: EGLDisplay, sync_: EGLSync) -> Boolean {
+		self.call(
+			"eglDestroySync",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_destroy(display, sync(sync_))
+			},
+			|a| a.eglDestroySync(display, sync_),
+			|r, result| {
+				if *result == TRUE {
+					r.destroy(sync(sync_))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglClientWaitSync(
+		&self,
+		display: EGLDisplay,
+		sync_: EGLSync,
+		flags: Int,
+		timeout: Time,
+	) -> Int {
+		self.call(
+			"eglClientWaitSync",
+			FALSE as Int,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, sync(sync_))
+			},
+			|a| a.eglClientWaitSync(display, sync_, flags, timeout),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglGetSyncAttrib(
+		&self,
+		display: EGLDisplay,
+		sync_: EGLSync,
+		attribute: Int,
+		value: *mut Attrib,
+	) -> Boolean {
+		self.call(
+			"eglGetSyncAttrib",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, sync(sync_))
+			},
+			|a| a.eglGetSyncAttrib(display, sync_, attribute, value),
+			|_, _| (),
+		)
+	}
+
+	unsafe fn eglCreateImage(
+		&self,
+		display: EGLDisplay,
+		ctx: EGLContext,
+		target: Enum,
+		buffer: EGLClientBuffer,
+		attrib_list: *const Attrib,
+	) -> EGLImage {
+		self.call(
+			"eglCreateImage",
+			NO_IMAGE,
+			|r| {
+				r.check_display(display)?;
+				check_optional(r, display, ctx, context)
+			},
+			|a| a.eglCreateImage(display, ctx, target, buffer, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, image(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglDestroyImage(&self, display: EGLDisplay, image_: EGLImage) -> Boolean {
+		self.call(
+			"eglDestroyImage",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_destroy(display, image(image_))
+			},
+			|a| a.eglDestroyImage(display, image_),
+			|r, result| {
+				if *result == TRUE {
+					r.destroy(image(image_))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglGetPlatformDisplay(
+		&self,
+		platform: Enum,
+		native_display: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLDisplay {
+		self.call(
+			"eglGetPlatformDisplay",
+			NO_DISPLAY,
+			|_| Ok(()),
+			|a| a.eglGetPlatformDisplay(platform, native_display, attrib_list),
+			|r, result| r.register_display(*result),
+		)
+	}
+
+	unsafe fn eglCreatePlatformWindowSurface(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		native_window: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		self.call(
+			"eglCreatePlatformWindowSurface",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreatePlatformWindowSurface(display, config_, native_window, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglCreatePlatformPixmapSurface(
+		&self,
+		display: EGLDisplay,
+		config_: EGLConfig,
+		native_pixmap: *mut c_void,
+		attrib_list: *const Attrib,
+	) -> EGLSurface {
+		self.call(
+			"eglCreatePlatformPixmapSurface",
+			NO_SURFACE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, config(config_))
+			},
+			|a| a.eglCreatePlatformPixmapSurface(display, config_, native_pixmap, attrib_list),
+			|r, result| {
+				if !result.is_null() {
+					r.create(display, surface(*result))
+				}
+			},
+		)
+	}
+
+	unsafe fn eglWaitSync(&self, display: EGLDisplay, sync_: EGLSync, flags: Int) -> Boolean {
+		self.call(
+			"eglWaitSync",
+			FALSE,
+			|r| {
+				r.check_display(display)?;
+				r.check_object(display, sync(sync_))
+			},
+			|a| a.eglWaitSync(display, sync_, flags),
+			|_, _| (),
+		)
+	}
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+	use super::*;
+
+	fn initialized(egl: &Instance<Validated<Mock>>, native: usize) -> (Display, Config) {
+		let display = unsafe { egl.get_display(native as NativeDisplayType) }.unwrap();
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		(display, config)
+	}
+
+	fn assert_violation(function: &str, kind: ViolationKind) {
+		let violation = Violation::last().unwrap();
+		assert_eq!(violation.function(), function);
+		assert_eq!(violation.kind(), kind);
+	}
+
+	#[test]
+	fn double_destroy() {
+		let egl = Instance::new(Validated::new(Mock::new()));
+		let (display, config) = initialized(&egl, 0);
+		let surface = egl
+			.create_pbuffer_surface(display, config, &[NONE])
+			.unwrap();
+
+		egl.destroy_surface(display, surface).unwrap();
+		assert!(Violation::last().is_none());
+		assert_eq!(
+			egl.destroy_surface(display, surface),
+			Err(Error::BadSurface)
+		);
+		assert_violation(
+			"eglDestroySurface",
+			ViolationKind::DoubleDestroy(Object::Surface(surface)),
+		);
+	}
+
+	#[test]
+	fn use_after_destroy() {
+		let egl = Instance::new(Validated::new(Mock::new()));
+		let (display, config) = initialized(&egl, 0);
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		egl.destroy_context(display, context).unwrap();
+
+		assert_eq!(
+			egl.query_context(display, context, CONFIG_ID),
+			Err(Error::BadContext)
+		);
+		assert_violation(
+			"eglQueryContext",
+			ViolationKind::UseAfterDestroy(Object::Context(context)),
+		);
+	}
+
+	#[test]
+	fn cross_display_use() {
+		let egl = Instance::new(Validated::new(Mock::new()));
+		let (first, config) = initialized(&egl, 0);
+		let (second, _) = initialized(&egl, 1);
+		let surface = egl.create_pbuffer_surface(first, config, &[NONE]).unwrap();
+
+		assert_eq!(
+			egl.query_surface(second, surface, WIDTH),
+			Err(Error::BadSurface)
+		);
+		assert_violation(
+			"eglQuerySurface",
+			ViolationKind::WrongDisplay {
+				object: Object::Surface(surface),
+				display: second,
+				owner: first,
+			},
+		);
+	}
+
+	#[test]
+	fn non_initialized_display() {
+		let egl = Instance::new(Validated::new(Mock::new()));
+		let display = unsafe { egl.get_display(DEFAULT_DISPLAY) }.unwrap();
+
+		assert_eq!(
+			egl.choose_first_config(display, &[NONE]),
+			Err(Error::NotInitialized)
+		);
+		assert_violation("eglChooseConfig", ViolationKind::NotInitialized(display));
+
+		let (display, config) = initialized(&egl, 0);
+		egl.terminate(display).unwrap();
+		assert_eq!(
+			egl.create_pbuffer_surface(display, config, &[NONE]),
+			Err(Error::NotInitialized)
+		);
+		assert_violation(
+			"eglCreatePbufferSurface",
+			ViolationKind::NotInitialized(display),
+		);
+	}
+
+	#[test]
+	fn displays_are_adopted_on_initialize() {
+		let validated = Validated::new(Mock::new());
+
+		// Obtained behind the back of the validation layer, as with
+		// `eglGetPlatformDisplayEXT`.
+		let display = unsafe {
+			Display::from_ptr(api::EGL1_0::eglGetDisplay(
+				validated.inner(),
+				DEFAULT_DISPLAY,
+			))
+		};
+		let egl = Instance::new(validated);
+
+		assert_eq!(
+			egl.create_context(display, None, None, &[NONE]),
+			Err(Error::BadDisplay)
+		);
+		assert_eq!(
+			Violation::last().unwrap().kind(),
+			ViolationKind::UnknownDisplay(display)
+		);
+
+		egl.initialize(display).unwrap();
+		let config = egl.choose_first_config(display, &[NONE]).unwrap().unwrap();
+		let context = egl
+			.create_context(display, Some(config), None, &[NONE])
+			.unwrap();
+		egl.destroy_context(display, context).unwrap();
+		assert!(Violation::last().is_none());
+
+		egl.terminate(display).unwrap();
+		assert_eq!(
+			egl.create_context(display, Some(config), None, &[NONE]),
+			Err(Error::NotInitialized)
+		);
+		assert_eq!(
+			Violation::last().unwrap().kind(),
+			ViolationKind::NotInitialized(display)
+		);
+	}
+}